@@ -1,2 +1,3 @@
 pub mod balance;
+pub mod nft;
 pub mod token;
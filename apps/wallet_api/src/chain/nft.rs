@@ -0,0 +1,50 @@
+use actix_web::{get, web};
+use std::str::FromStr;
+use tokio::sync::Mutex;
+
+use crate::response::{success_response, ApiResult};
+use primitives::Chain;
+use settings_chain::ChainProviders;
+
+#[get("/chain/nfts/{chain}/{address}")]
+pub async fn get_nfts(
+    path: web::Path<(String, String)>,
+    providers: web::Data<Mutex<ChainProviders>>,
+) -> ApiResult {
+    let (chain_str, address) = path.into_inner();
+    let chain = Chain::from_str(&chain_str).map_err(|e| anyhow::anyhow!("Invalid chain: {}", e))?;
+
+    let providers = providers.lock().await;
+    let provider = providers
+        .get_provider(chain)
+        .map_err(|e| anyhow::anyhow!("Provider not found: {}", e))?;
+
+    // 按合集分组返回地址持有的 NFT
+    let collections = provider
+        .get_nfts(address)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to get nfts: {}", e))?;
+
+    Ok(success_response(collections))
+}
+
+#[get("/chain/nft/{chain}/{contract_address}/{token_id}")]
+pub async fn get_nft(
+    path: web::Path<(String, String, String)>,
+    providers: web::Data<Mutex<ChainProviders>>,
+) -> ApiResult {
+    let (chain_str, contract_address, token_id) = path.into_inner();
+    let chain = Chain::from_str(&chain_str).map_err(|e| anyhow::anyhow!("Invalid chain: {}", e))?;
+
+    let providers = providers.lock().await;
+    let provider = providers
+        .get_provider(chain)
+        .map_err(|e| anyhow::anyhow!("Provider not found: {}", e))?;
+
+    let nft = provider
+        .get_nft(contract_address, token_id)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to get nft: {}", e))?;
+
+    Ok(success_response(nft))
+}
@@ -1,5 +1,6 @@
 mod chain;
 mod model;
+mod name;
 mod response;
 mod status;
 
@@ -7,7 +8,7 @@ use actix_web::{middleware::Logger, web, App, HttpServer};
 use model::APIService;
 use reef_tracing::SentryTracing;
 use settings::Settings;
-use settings_chain::ChainProviders;
+use settings_chain::{ChainProviders, NameResolvers};
 use std::str::FromStr;
 use tokio::sync::Mutex;
 
@@ -15,14 +16,19 @@ async fn create_app(settings: Settings) -> std::io::Result<()> {
     let bind_address = format!("{}:{}", settings.server.address, settings.server.port);
     let chain_providers =
         web::Data::new(Mutex::new(ChainProviders::from_settings(&settings).await));
+    let name_resolvers = web::Data::new(Mutex::new(NameResolvers::from_settings(&settings)));
 
     HttpServer::new(move || {
         App::new()
             .app_data(chain_providers.clone())
+            .app_data(name_resolvers.clone())
             .wrap(Logger::default())
             .service(status::get_status)
             .service(chain::balance::get_balances)
             .service(chain::token::get_token)
+            .service(chain::nft::get_nfts)
+            .service(chain::nft::get_nft)
+            .service(name::resolve::resolve_name)
     })
     .bind(&bind_address)?
     .run()
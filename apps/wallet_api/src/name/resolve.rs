@@ -0,0 +1,25 @@
+use actix_web::{get, web};
+use tokio::sync::Mutex;
+
+use crate::response::{success_response, ApiResult};
+use settings_chain::NameResolvers;
+
+#[get("/name/resolve/{name}")]
+pub async fn resolve_name(
+    path: web::Path<String>,
+    resolvers: web::Data<Mutex<NameResolvers>>,
+) -> ApiResult {
+    let name = path.into_inner();
+
+    let resolvers = resolvers.lock().await;
+    let resolver = resolvers
+        .get_resolver(&name)
+        .map_err(|e| anyhow::anyhow!("Resolver not found: {}", e))?;
+
+    let record = resolver
+        .resolve_name(name)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to resolve name: {}", e))?;
+
+    Ok(success_response(record))
+}
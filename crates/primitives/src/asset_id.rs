@@ -1,10 +1,15 @@
-use std::{collections::HashSet, fmt};
+use std::{collections::HashSet, fmt, str::FromStr};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{chain::Chain, AssetSubtype};
+use crate::{
+    caip::{decode_reference, encode_reference},
+    chain::Chain,
+    AssetSubtype,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// 按 CAIP-19 编码，例如 `eip155:1/slip44:60`、`eip155:1/erc20:0x...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AssetId {
     pub chain: Chain,
     pub token_address: Option<String>,
@@ -13,12 +18,57 @@ pub struct AssetId {
 impl fmt::Display for AssetId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.token_address {
-            Some(address) => write!(f, "{}:{}", self.chain, address),
-            None => write!(f, "{}", self.chain),
+            Some(address) => write!(
+                f,
+                "{}/{}:{}",
+                self.chain.caip2(),
+                self.chain.token_namespace(),
+                encode_reference(address)
+            ),
+            None => write!(f, "{}/slip44:{}", self.chain.caip2(), self.chain.slip44()),
         }
     }
 }
 
+/// 只接受 `Display` 的输出：原生资产的 `slip44` 编号与代币命名空间须与链一致
+impl FromStr for AssetId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid CAIP-19 asset id: {}", s);
+        let (chain, asset) = s.split_once('/').ok_or_else(invalid)?;
+        let chain = Chain::from_caip2(chain).ok_or_else(invalid)?;
+        let (namespace, reference) = asset.split_once(':').ok_or_else(invalid)?;
+        if reference.is_empty() {
+            return Err(invalid());
+        }
+        if namespace == "slip44" {
+            if reference.parse::<u32>().map_err(|_| invalid())? != chain.slip44() {
+                return Err(invalid());
+            }
+            return Ok(Self::native(chain));
+        }
+        if namespace != chain.token_namespace() {
+            return Err(invalid());
+        }
+        Ok(Self::new(chain, Some(decode_reference(reference)?)))
+    }
+}
+
+impl Serialize for AssetId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AssetId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl AssetId {
     /// 传入链和可选的代币地址
     pub fn new(chain: Chain, token_address: Option<String>) -> Self {
@@ -90,7 +140,7 @@ mod tests {
         assert_eq!(asset_id.token_address, None);
         assert!(asset_id.is_native());
         assert!(!asset_id.is_token());
-        assert_eq!(asset_id.to_string(), "Ethereum");
+        assert_eq!(asset_id.to_string(), "eip155:1/slip44:60");
     }
 
     #[test]
@@ -103,18 +153,83 @@ mod tests {
         );
         assert!(!asset_id.is_native());
         assert!(asset_id.is_token());
-        assert_eq!(asset_id.to_string(), "Ethereum:0x1234567890abcdef");
+        assert_eq!(asset_id.to_string(), "eip155:1/erc20:0x1234567890abcdef");
     }
 
     #[test]
     fn test_display_format() {
-        let eth = AssetId::native(Chain::Ethereum);
-        assert_eq!(format!("{}", eth), "Ethereum");
+        let sol = AssetId::native(Chain::Solana);
+        assert_eq!(
+            format!("{}", sol),
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp/slip44:501"
+        );
 
-        let usdc = AssetId::token(Chain::Ethereum, "0xa0b86a33e6409b3b94e55000000000000000000");
+        let usdc = AssetId::token(
+            Chain::Solana,
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+        );
         assert_eq!(
             format!("{}", usdc),
-            "Ethereum:0xa0b86a33e6409b3b94e55000000000000000000"
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp/token:EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        );
+
+        let sui = AssetId::token(Chain::Sui, "0x2::sui::SUI");
+        assert_eq!(sui.to_string(), "sui:mainnet/token:0x2%3A%3Asui%3A%3ASUI");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let ids = [
+            AssetId::native(Chain::Bitcoin),
+            AssetId::token(
+                Chain::SmartChain,
+                "0x55d398326f99059fF775485246999027B3197955",
+            ),
+            AssetId::token(Chain::Tron, "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+            AssetId::token(
+                Chain::Osmosis,
+                "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+            ),
+            AssetId::token(
+                Chain::Ton,
+                "EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs",
+            ),
+            AssetId::token(Chain::HyperCore, "perpetual::ETH"),
+        ];
+        for id in ids {
+            assert_eq!(id.to_string().parse::<AssetId>().unwrap(), id);
+        }
+
+        let bnb: AssetId = "eip155:56/slip44:60".parse().unwrap();
+        assert_eq!(bnb, AssetId::native(Chain::SmartChain));
+        assert!("eip155:56/slip44:714".parse::<AssetId>().is_err());
+        assert!("eip155:1/trc20:0x1234".parse::<AssetId>().is_err());
+        assert!("tron:0x2b6653dc/erc20:TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"
+            .parse::<AssetId>()
+            .is_err());
+
+        assert!("eip155:1".parse::<AssetId>().is_err());
+        assert!("eip155:12345/slip44:60".parse::<AssetId>().is_err());
+        assert!("eip155:1/slip44:eth".parse::<AssetId>().is_err());
+        assert!("eip155:1/erc20:".parse::<AssetId>().is_err());
+        // 非规范的转义
+        assert!("eip155:1/erc20:%30x12".parse::<AssetId>().is_err());
+        assert!("sui:mainnet/token:0x2%3a%3asui%3a%3aSUI"
+            .parse::<AssetId>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_serde() {
+        let id = AssetId::token(
+            Chain::Stellar,
+            "USDC-GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+        );
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(
+            json,
+            "\"stellar:pubnet/token:USDC-GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN\""
         );
+        assert_eq!(serde_json::from_str::<AssetId>(&json).unwrap(), id);
     }
 }
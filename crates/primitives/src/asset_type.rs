@@ -14,7 +14,7 @@ pub enum AssetType {
     SPL,     // Solana
     SPL2022, // Solana Token 2022
     TRC20,   // Tron
-    TOKEN,   // Sui, Aptos
+    TOKEN,   // Sui, Aptos, XRP, Stellar
     IBC,     // COSMOS
     JETTON,  // Ton
     SYNTH,   // Thorchain
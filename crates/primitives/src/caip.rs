@@ -0,0 +1,77 @@
+//! CAIP 标识符的公共部分，链与资产的编码分别见 `Chain::caip2`、`AssetId`
+
+/// CAIP-19 资产引用允许的字符为 `[-.%a-zA-Z0-9]`，其余字符按百分号编码
+pub(crate) fn encode_reference(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if is_unreserved(byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'.'
+}
+
+fn is_upper_hex(byte: u8) -> bool {
+    byte.is_ascii_digit() || (b'A'..=b'F').contains(&byte)
+}
+
+/// 只接受 `encode_reference` 的输出，小写转义和多余的转义都视为无效，保证同一资产只有一种写法
+pub(crate) fn decode_reference(value: &str) -> Result<String, String> {
+    let invalid = || format!("Invalid CAIP reference: {}", value);
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = bytes.get(i + 1..i + 3).ok_or_else(invalid)?;
+                if !hex.iter().all(|x| is_upper_hex(*x)) {
+                    return Err(invalid());
+                }
+                let byte = u8::from_str_radix(&value[i + 1..i + 3], 16).map_err(|_| invalid())?;
+                if is_unreserved(byte) {
+                    return Err(invalid());
+                }
+                decoded.push(byte);
+                i += 3;
+            }
+            x if is_unreserved(x) => {
+                decoded.push(x);
+                i += 1;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_round_trip() {
+        for value in ["0x2::sui::SUI", "ibc/27394FB0", "EQCxE6mU_Ot", "USDC-GA5Z"] {
+            let encoded = encode_reference(value);
+            assert!(!encoded.contains([':', '/', '_']));
+            assert_eq!(decode_reference(&encoded).unwrap(), value);
+        }
+        assert_eq!(encode_reference("0x2::sui::SUI"), "0x2%3A%3Asui%3A%3ASUI");
+        assert!(decode_reference("a%2").is_err());
+        assert!(decode_reference("a:b").is_err());
+    }
+
+    #[test]
+    fn test_reject_non_canonical_reference() {
+        for value in ["%30x12", "0x2%3a%3asui", "a%2d", "%+1", "%-1"] {
+            assert!(decode_reference(value).is_err(), "{}", value);
+        }
+        assert_eq!(decode_reference("0x2%3A%3Asui").unwrap(), "0x2::sui");
+    }
+}
@@ -1,24 +1,15 @@
 use std::fmt;
+use std::str::FromStr;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 use strum_macros::{AsRefStr, EnumString};
 use typeshare::typeshare;
 
+use crate::AssetType;
+
 #[derive(
-    Copy,
-    Clone,
-    Debug,
-    Serialize,
-    Deserialize,
-    EnumIter,
-    AsRefStr,
-    EnumString,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Eq,
-    Hash,
+    Copy, Clone, Debug, Serialize, EnumIter, AsRefStr, PartialEq, Ord, PartialOrd, Eq, Hash,
 )]
 #[typeshare(swift = "Equatable, CaseIterable, Sendable, Hashable")]
 #[serde(rename_all = "lowercase")]
@@ -28,11 +19,68 @@ pub enum Chain {
     Ethereum,
     SmartChain,
     Polygon,
+    Optimism,
+    Arbitrum,
+    Base,
+    OpBNB,
+    AvalancheC,
+    Fantom,
+    Gnosis,
+    ZkSync,
+    Linea,
+    Mantle,
+    Celo,
+    Blast,
+    Manta,
+    Sonic,
+    Berachain,
+    Ink,
+    Unichain,
+    Abstract,
+    Monad,
+    Hyperliquid,
+    Tron,
+    Bitcoin,
+    BitcoinCash,
+    Litecoin,
+    Doge,
+    Cosmos,
+    Osmosis,
+    Injective,
+    Celestia,
+    Sei,
+    Noble,
+    Sui,
+    Aptos,
+    Ton,
+    Xrp,
+    Stellar,
+    HyperCore,
 }
 
 impl fmt::Display for Chain {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_ref())
+    }
+}
+
+/// 接受小写名称（`ethereum`）或 CAIP-2 标识（`eip155:1`）
+impl FromStr for Chain {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|x| x.as_ref() == s)
+            .or_else(|| Self::from_caip2(s))
+            .ok_or(strum::ParseError::VariantNotFound)
+    }
+}
+
+impl<'de> Deserialize<'de> for Chain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::from_str(&value)
+            .map_err(|_| serde::de::Error::custom(format!("Unknown chain: {}", value)))
     }
 }
 
@@ -40,32 +88,203 @@ impl Chain {
     pub fn all() -> Vec<Self> {
         Self::iter().collect()
     }
+
+    /// CAIP-2 命名空间
+    pub fn caip2_namespace(&self) -> &'static str {
+        match self {
+            Self::Ethereum
+            | Self::SmartChain
+            | Self::Polygon
+            | Self::Optimism
+            | Self::Arbitrum
+            | Self::Base
+            | Self::OpBNB
+            | Self::AvalancheC
+            | Self::Fantom
+            | Self::Gnosis
+            | Self::ZkSync
+            | Self::Linea
+            | Self::Mantle
+            | Self::Celo
+            | Self::Blast
+            | Self::Manta
+            | Self::Sonic
+            | Self::Berachain
+            | Self::Ink
+            | Self::Unichain
+            | Self::Abstract
+            | Self::Monad
+            | Self::Hyperliquid => "eip155",
+            Self::Solana => "solana",
+            Self::Tron => "tron",
+            Self::Bitcoin | Self::BitcoinCash | Self::Litecoin | Self::Doge => "bip122",
+            Self::Cosmos
+            | Self::Osmosis
+            | Self::Injective
+            | Self::Celestia
+            | Self::Sei
+            | Self::Noble => "cosmos",
+            Self::Sui => "sui",
+            Self::Aptos => "aptos",
+            Self::Ton => "ton",
+            Self::Xrp => "xrpl",
+            Self::Stellar => "stellar",
+            Self::HyperCore => "hypercore",
+        }
+    }
+
+    /// CAIP-2 引用：EVM 为 chain id，bip122 为创世块哈希前 32 位，cosmos 为 chain-id
+    pub fn caip2_reference(&self) -> String {
+        match self {
+            Self::Ethereum => "1",
+            Self::SmartChain => "56",
+            Self::Polygon => "137",
+            Self::Optimism => "10",
+            Self::Arbitrum => "42161",
+            Self::Base => "8453",
+            Self::OpBNB => "204",
+            Self::AvalancheC => "43114",
+            Self::Fantom => "250",
+            Self::Gnosis => "100",
+            Self::ZkSync => "324",
+            Self::Linea => "59144",
+            Self::Mantle => "5000",
+            Self::Celo => "42220",
+            Self::Blast => "81457",
+            Self::Manta => "169",
+            Self::Sonic => "146",
+            Self::Berachain => "80094",
+            Self::Ink => "57073",
+            Self::Unichain => "130",
+            Self::Abstract => "2741",
+            Self::Monad => "143",
+            Self::Hyperliquid => "999",
+            Self::Solana => "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
+            Self::Tron => "0x2b6653dc",
+            Self::Bitcoin => "000000000019d6689c085ae165831e93",
+            Self::BitcoinCash => "000000000000000000651ef99cb9fcbe",
+            Self::Litecoin => "12a765e31ffd4059bada1e25190f6e98",
+            Self::Doge => "1a91e3dace36e2be3bf030a65679fe82",
+            Self::Cosmos => "cosmoshub-4",
+            Self::Osmosis => "osmosis-1",
+            Self::Injective => "injective-1",
+            Self::Celestia => "celestia",
+            Self::Sei => "pacific-1",
+            Self::Noble => "noble-1",
+            Self::Sui => "mainnet",
+            Self::Aptos => "1",
+            Self::Ton => "-239",
+            Self::Xrp => "0",
+            Self::Stellar => "pubnet",
+            Self::HyperCore => "mainnet",
+        }
+        .to_string()
+    }
+
+    /// 例如 `eip155:1`、`solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp`
+    pub fn caip2(&self) -> String {
+        format!("{}:{}", self.caip2_namespace(), self.caip2_reference())
+    }
+
+    pub fn from_caip2(value: &str) -> Option<Self> {
+        Self::iter().find(|x| x.caip2() == value)
+    }
+
+    /// SLIP-44 币种编号，用于 CAIP-19 原生资产；EVM 链统一为 60
+    pub fn slip44(&self) -> u32 {
+        match self {
+            Self::Ethereum
+            | Self::SmartChain
+            | Self::Polygon
+            | Self::Optimism
+            | Self::Arbitrum
+            | Self::Base
+            | Self::OpBNB
+            | Self::AvalancheC
+            | Self::Fantom
+            | Self::Gnosis
+            | Self::ZkSync
+            | Self::Linea
+            | Self::Mantle
+            | Self::Celo
+            | Self::Blast
+            | Self::Manta
+            | Self::Sonic
+            | Self::Berachain
+            | Self::Ink
+            | Self::Unichain
+            | Self::Abstract
+            | Self::Monad
+            | Self::Hyperliquid => 60,
+            Self::Solana => 501,
+            Self::Tron => 195,
+            Self::Bitcoin => 0,
+            Self::BitcoinCash => 145,
+            Self::Litecoin => 2,
+            Self::Doge => 3,
+            Self::Cosmos | Self::Osmosis | Self::Celestia | Self::Sei | Self::Noble => 118,
+            Self::Injective => 60,
+            Self::Sui => 784,
+            Self::Aptos => 637,
+            Self::Ton => 607,
+            Self::Xrp => 144,
+            Self::Stellar => 148,
+            Self::HyperCore => 60,
+        }
+    }
+
+    /// CAIP-19 代币命名空间
+    pub fn token_namespace(&self) -> &'static str {
+        if EVMChain::from_chain(*self).is_some() {
+            return "erc20";
+        }
+        match self {
+            Self::Tron => "trc20",
+            Self::Ton => "jetton",
+            _ => "token",
+        }
+    }
 }
 
 #[typeshare]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "lowercase")]
 pub enum EVMChain {
     Ethereum,
     SmartChain,
     Polygon,
+    Optimism,
+    Arbitrum,
+    Base,
+    OpBNB,
+    AvalancheC,
+    Fantom,
+    Gnosis,
+    ZkSync,
+    Linea,
+    Mantle,
+    Celo,
+    Blast,
+    Manta,
+    Sonic,
+    Berachain,
+    Ink,
+    Unichain,
+    Abstract,
+    Monad,
+    Hyperliquid,
 }
 
 impl EVMChain {
+    // EVMChain 与 Chain 的变体同名，按小写名称互相转换
     pub fn from_chain(chain: Chain) -> Option<Self> {
-        match chain {
-            Chain::Ethereum => Some(Self::Ethereum),
-            Chain::SmartChain => Some(Self::SmartChain),
-            Chain::Polygon => Some(Self::Polygon),
-            _ => None,
-        }
+        Self::from_str(chain.as_ref()).ok()
     }
 
     pub fn to_chain(self) -> Chain {
-        match self {
-            Self::Ethereum => Chain::Ethereum,
-            Self::SmartChain => Chain::SmartChain,
-            Self::Polygon => Chain::Polygon,
-        }
+        Chain::from_str(self.as_ref()).unwrap()
     }
 
     pub fn chain_id(&self) -> u64 {
@@ -73,14 +292,53 @@ impl EVMChain {
             Self::Ethereum => 1,
             Self::SmartChain => 56,
             Self::Polygon => 137,
+            Self::Optimism => 10,
+            Self::Arbitrum => 42161,
+            Self::Base => 8453,
+            Self::OpBNB => 204,
+            Self::AvalancheC => 43114,
+            Self::Fantom => 250,
+            Self::Gnosis => 100,
+            Self::ZkSync => 324,
+            Self::Linea => 59144,
+            Self::Mantle => 5000,
+            Self::Celo => 42220,
+            Self::Blast => 81457,
+            Self::Manta => 169,
+            Self::Sonic => 146,
+            Self::Berachain => 80094,
+            Self::Ink => 57073,
+            Self::Unichain => 130,
+            Self::Abstract => 2741,
+            Self::Monad => 143,
+            Self::Hyperliquid => 999,
         }
     }
 
     pub fn native_symbol(&self) -> &'static str {
         match self {
-            Self::Ethereum => "ETH",
-            Self::SmartChain => "BNB",
+            Self::Ethereum
+            | Self::Optimism
+            | Self::Arbitrum
+            | Self::Base
+            | Self::ZkSync
+            | Self::Linea
+            | Self::Blast
+            | Self::Manta
+            | Self::Ink
+            | Self::Unichain
+            | Self::Abstract => "ETH",
+            Self::SmartChain | Self::OpBNB => "BNB",
             Self::Polygon => "MATIC",
+            Self::AvalancheC => "AVAX",
+            Self::Fantom => "FTM",
+            Self::Gnosis => "xDAI",
+            Self::Mantle => "MNT",
+            Self::Celo => "CELO",
+            Self::Sonic => "S",
+            Self::Berachain => "BERA",
+            Self::Monad => "MON",
+            Self::Hyperliquid => "HYPE",
         }
     }
 
@@ -88,7 +346,140 @@ impl EVMChain {
         18
     }
 
+    /// 链上同质化代币的资产类型
+    pub fn asset_type(&self) -> AssetType {
+        match self {
+            Self::SmartChain => AssetType::BEP20,
+            _ => AssetType::ERC20,
+        }
+    }
+
+    /// OP Stack 系 L2，L1 数据费由 `GasPriceOracle` 预部署合约计算
+    pub fn is_opstack(&self) -> bool {
+        matches!(
+            self,
+            Self::Optimism
+                | Self::Base
+                | Self::OpBNB
+                | Self::Blast
+                | Self::Manta
+                | Self::Ink
+                | Self::Unichain
+        )
+    }
+
+    /// Arbitrum Nitro 系 L2，L1 数据费由 `NodeInterface` 估算
+    pub fn is_arbitrum_stack(&self) -> bool {
+        matches!(self, Self::Arbitrum)
+    }
+
+    pub fn explorer_url(&self) -> &'static str {
+        match self {
+            Self::Ethereum => "https://etherscan.io",
+            Self::SmartChain => "https://bscscan.com",
+            Self::Polygon => "https://polygonscan.com",
+            Self::Optimism => "https://optimistic.etherscan.io",
+            Self::Arbitrum => "https://arbiscan.io",
+            Self::Base => "https://basescan.org",
+            Self::OpBNB => "https://opbnb.bscscan.com",
+            Self::AvalancheC => "https://snowtrace.io",
+            Self::Fantom => "https://ftmscan.com",
+            Self::Gnosis => "https://gnosisscan.io",
+            Self::ZkSync => "https://era.zksync.network",
+            Self::Linea => "https://lineascan.build",
+            Self::Mantle => "https://mantlescan.xyz",
+            Self::Celo => "https://celoscan.io",
+            Self::Blast => "https://blastscan.io",
+            Self::Manta => "https://pacific-explorer.manta.network",
+            Self::Sonic => "https://sonicscan.org",
+            Self::Berachain => "https://berascan.com",
+            Self::Ink => "https://explorer.inkonchain.com",
+            Self::Unichain => "https://uniscan.xyz",
+            Self::Abstract => "https://abscan.org",
+            Self::Monad => "https://monadscan.com",
+            Self::Hyperliquid => "https://hyperevmscan.io",
+        }
+    }
+
+    pub fn transaction_url(&self, hash: &str) -> String {
+        format!("{}/tx/{}", self.explorer_url(), hash)
+    }
+
+    pub fn address_url(&self, address: &str) -> String {
+        format!("{}/address/{}", self.explorer_url(), address)
+    }
+
     pub fn all() -> Vec<Self> {
         Self::iter().collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evm_chain_from_chain() {
+        for chain in EVMChain::all() {
+            assert_eq!(EVMChain::from_chain(chain.to_chain()), Some(chain));
+        }
+        assert_eq!(EVMChain::from_chain(Chain::Solana), None);
+        assert_eq!(Chain::OpBNB.as_ref(), "opbnb");
+        assert_eq!(EVMChain::AvalancheC.to_chain(), Chain::AvalancheC);
+    }
+
+    #[test]
+    fn test_chain_caip2() {
+        assert_eq!(Chain::Ethereum.caip2(), "eip155:1");
+        assert_eq!(Chain::SmartChain.caip2(), "eip155:56");
+        assert_eq!(
+            Chain::Solana.caip2(),
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"
+        );
+        assert_eq!(
+            Chain::Bitcoin.caip2(),
+            "bip122:000000000019d6689c085ae165831e93"
+        );
+        assert_eq!(Chain::Osmosis.caip2(), "cosmos:osmosis-1");
+
+        for chain in EVMChain::all() {
+            assert_eq!(chain.to_chain().caip2_namespace(), "eip155");
+            assert_eq!(
+                chain.to_chain().caip2_reference(),
+                chain.chain_id().to_string()
+            );
+            assert_eq!(chain.to_chain().slip44(), 60);
+        }
+
+        let mut ids = Chain::all().iter().map(|x| x.caip2()).collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), Chain::all().len());
+    }
+
+    #[test]
+    fn test_chain_from_str() {
+        for chain in Chain::all() {
+            assert_eq!(chain.to_string().parse::<Chain>().unwrap(), chain);
+            assert_eq!(Chain::from_str(&chain.caip2()).unwrap(), chain);
+        }
+        assert_eq!(Chain::Ethereum.to_string(), "ethereum");
+        assert!(Chain::from_str("Ethereum").is_err());
+        assert!(Chain::from_str("eip155:12345").is_err());
+    }
+
+    #[test]
+    fn test_chain_serde() {
+        assert_eq!(
+            serde_json::to_string(&Chain::SmartChain).unwrap(),
+            "\"smartchain\""
+        );
+        for chain in Chain::all() {
+            let json = serde_json::to_string(&chain).unwrap();
+            assert_eq!(serde_json::from_str::<Chain>(&json).unwrap(), chain);
+        }
+        let chain: Chain = serde_json::from_str("\"eip155:137\"").unwrap();
+        assert_eq!(chain, Chain::Polygon);
+        assert!(serde_json::from_str::<Chain>("\"unknown\"").is_err());
+    }
+}
@@ -1,10 +1,11 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::Chain;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+/// 按 CAIP-10 编码，例如 `eip155:1:0xab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ChainAddress {
     pub chain: Chain,
     pub address: String,
@@ -18,6 +19,82 @@ impl ChainAddress {
 
 impl fmt::Display for ChainAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}", self.chain, self.address)
+        write!(f, "{}:{}", self.chain.caip2(), self.address)
+    }
+}
+
+impl FromStr for ChainAddress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid CAIP-10 account id: {}", s);
+        let mut parts = s.splitn(3, ':');
+        let (Some(namespace), Some(reference), Some(address)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        let chain =
+            Chain::from_caip2(&format!("{}:{}", namespace, reference)).ok_or_else(invalid)?;
+        if address.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self::new(chain, address.to_string()))
+    }
+}
+
+impl Serialize for ChainAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_address() {
+        let address = ChainAddress::new(
+            Chain::Ethereum,
+            "0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb".to_string(),
+        );
+        assert_eq!(
+            address.to_string(),
+            "eip155:1:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb"
+        );
+        assert_eq!(
+            address.to_string().parse::<ChainAddress>().unwrap(),
+            address
+        );
+
+        let address: ChainAddress =
+            "cosmos:cosmoshub-4:cosmos1t2uflqwqe0fsj0shcfkrvpukewcw40yjj6hdc0"
+                .parse()
+                .unwrap();
+        assert_eq!(address.chain, Chain::Cosmos);
+
+        assert!("eip155:1".parse::<ChainAddress>().is_err());
+        assert!("eip155:1:".parse::<ChainAddress>().is_err());
+        assert!("ethereum:0xab16".parse::<ChainAddress>().is_err());
+    }
+
+    #[test]
+    fn test_serde() {
+        let address = ChainAddress::new(Chain::Ton, "-1:3333".to_string());
+        let json = serde_json::to_string(&address).unwrap();
+        assert_eq!(json, "\"ton:-239:-1:3333\"");
+        assert_eq!(
+            serde_json::from_str::<ChainAddress>(&json).unwrap(),
+            address
+        );
     }
 }
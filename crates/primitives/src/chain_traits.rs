@@ -1,13 +1,18 @@
 use std::error::Error;
 
-use crate::{
-    Asset, AssetBalance, BroadcastOptions, Chain, Transaction, TransactionStateRequest,
-    TransactionUpdate,
-};
+use crate::{Asset, AssetBalance, Chain, NFTAsset, NFTCollection, UTXO};
 use async_trait::async_trait;
 
 pub trait ChainTraits:
-    ChainProvider + ChainBalances + ChainTransactions + ChainState + ChainToken + Send + Sync
+    ChainProvider
+    + ChainBalances
+    + ChainTransactions
+    + ChainState
+    + ChainToken
+    + ChainNft
+    + ChainUtxo
+    + Send
+    + Sync
 {
 }
 
@@ -37,7 +42,7 @@ pub trait ChainBalances: Send + Sync {
 
 #[async_trait]
 pub trait ChainTransactions: Send + Sync {
-   /*  async fn transaction_broadcast(
+    /*  async fn transaction_broadcast(
         &self,
         data: String,
         options: BroadcastOptions,
@@ -82,3 +87,30 @@ pub trait ChainToken: Send + Sync {
         Err("Chain does not support tokens".into())
     }
 }
+
+#[async_trait]
+pub trait ChainNft: Send + Sync {
+    //获取地址持有的NFT，按合约/集合分组
+    async fn get_nfts(
+        &self,
+        _address: String,
+    ) -> Result<Vec<NFTCollection>, Box<dyn Error + Sync + Send>> {
+        Err("Chain does not support NFTs".into())
+    }
+
+    async fn get_nft(
+        &self,
+        _contract_address: String,
+        _token_id: String,
+    ) -> Result<NFTAsset, Box<dyn Error + Sync + Send>> {
+        Err("Chain does not support NFTs".into())
+    }
+}
+
+#[async_trait]
+pub trait ChainUtxo: Send + Sync {
+    //获取地址的未花费输出，包括内存池中的
+    async fn get_utxos(&self, _address: String) -> Result<Vec<UTXO>, Box<dyn Error + Sync + Send>> {
+        Err("Chain does not support UTXOs".into())
+    }
+}
@@ -7,8 +7,10 @@ pub use self::chain_address::ChainAddress;
 pub mod asset_balance;
 pub use self::asset_balance::{AssetBalance, Balance};
 
-// pub mod asset_id;
-// pub use self::asset_id::{AssetId, AssetIdVecExt};
+pub mod asset_id;
+pub use self::asset_id::{AssetId, AssetIdVecExt};
+
+mod caip;
 
 pub mod asset_type;
 pub use self::asset_type::{AssetSubtype, AssetType};
@@ -21,7 +23,17 @@ pub use self::transaction::{
     BroadcastOptions, Transaction, TransactionStateRequest, TransactionStatus, TransactionUpdate,
 };
 
+pub mod nft;
+pub use self::nft::{NFTAsset, NFTCollection, NFTType};
+
+pub mod utxo;
+pub use self::utxo::UTXO;
+
+pub mod name;
+pub use self::name::{NameProvider, NameRecord, NameResolver};
+
 pub mod chain_traits;
 pub use self::chain_traits::{
-    ChainBalances, ChainProvider, ChainState, ChainToken, ChainTraits, ChainTransactions,
+    ChainBalances, ChainNft, ChainProvider, ChainState, ChainToken, ChainTraits, ChainTransactions,
+    ChainUtxo,
 };
@@ -0,0 +1,51 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumString};
+use typeshare::typeshare;
+
+use crate::Chain;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, AsRefStr, EnumString, PartialEq, Eq, Hash)]
+#[typeshare(swift = "Equatable, Sendable")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum NameProvider {
+    Ens,
+    Sns,
+    AllDomains,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NameRecord {
+    pub name: String,
+    pub chain: Chain,
+    pub address: String,
+    pub provider: NameProvider,
+    pub avatar: Option<String>,
+    pub url: Option<String>,
+}
+
+#[async_trait]
+pub trait NameResolver: Send + Sync {
+    fn provider(&self) -> NameProvider;
+
+    //反向解析地址所在的链
+    fn get_chain(&self) -> Chain;
+
+    //是否支持该域名，通常按顶级域名判断
+    fn is_supported_name(&self, name: &str) -> bool;
+
+    async fn resolve_name(&self, name: String) -> Result<NameRecord, Box<dyn Error + Sync + Send>>;
+
+    //地址反向解析为域名
+    async fn lookup_address(
+        &self,
+        _address: String,
+    ) -> Result<NameRecord, Box<dyn Error + Sync + Send>> {
+        Err("Name provider does not support reverse lookup".into())
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumString};
+use typeshare::typeshare;
+
+use crate::Chain;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, AsRefStr, EnumString, PartialEq, Eq, Hash)]
+#[typeshare(swift = "Equatable, Sendable")]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum NFTType {
+    ERC721,
+    ERC1155,
+    SPL,
+    CNFT,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NFTAsset {
+    pub chain: Chain,
+    pub contract_address: String,
+    pub token_id: String,
+    pub token_type: NFTType,
+    pub name: Option<String>,
+    pub token_uri: Option<String>,
+    pub owner: Option<String>,
+    pub balance: Option<String>,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NFTCollection {
+    pub chain: Chain,
+    pub contract_address: String,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub nfts: Vec<NFTAsset>,
+}
+
+impl NFTCollection {
+    pub fn new(chain: Chain, contract_address: String) -> Self {
+        Self {
+            chain,
+            contract_address,
+            name: None,
+            symbol: None,
+            nfts: vec![],
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// 未花费输出，`value` 为最小单位（satoshi）
+#[typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UTXO {
+    pub transaction_id: String,
+    pub vout: u32,
+    pub value: String,
+    pub address: String,
+    /// 内存池中的输出为 0
+    pub confirmations: u32,
+}
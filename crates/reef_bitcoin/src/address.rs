@@ -0,0 +1,142 @@
+use sha2::{Digest, Sha256};
+
+use crate::cashaddr;
+use crate::chain::BitcoinChain;
+
+const CHECKSUM_LEN: usize = 4;
+const HASH_LEN: usize = 20;
+
+/// 地址对应的输出脚本类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressType {
+    P2PKH,
+    P2SH,
+    /// bech32 v0，P2WPKH 或 P2WSH
+    WitnessV0,
+    /// bech32m v1 及以上，例如 Taproot
+    WitnessV1Plus,
+}
+
+/// base58check 解码为 `(版本, hash)`
+pub fn decode_base58check(address: &str) -> Option<(u8, Vec<u8>)> {
+    let data = bs58::decode(address).into_vec().ok()?;
+    if data.len() != 1 + HASH_LEN + CHECKSUM_LEN {
+        return None;
+    }
+    let (payload, checksum) = data.split_at(data.len() - CHECKSUM_LEN);
+    if &Sha256::digest(Sha256::digest(payload))[..CHECKSUM_LEN] != checksum {
+        return None;
+    }
+    Some((payload[0], payload[1..].to_vec()))
+}
+
+fn decode_segwit(chain: BitcoinChain, address: &str) -> Option<AddressType> {
+    let hrp = chain.segwit_hrp()?;
+    let (decoded_hrp, version, _) = bech32::segwit::decode(address).ok()?;
+    if decoded_hrp.to_lowercase() != hrp {
+        return None;
+    }
+    match version.to_u8() {
+        0 => Some(AddressType::WitnessV0),
+        _ => Some(AddressType::WitnessV1Plus),
+    }
+}
+
+fn decode_cashaddr(chain: BitcoinChain, address: &str) -> Option<AddressType> {
+    let (address_type, _) = cashaddr::decode(address, chain.cashaddr_prefix()?)?;
+    match address_type {
+        cashaddr::TYPE_P2PKH => Some(AddressType::P2PKH),
+        cashaddr::TYPE_P2SH => Some(AddressType::P2SH),
+        _ => None,
+    }
+}
+
+fn decode_legacy(chain: BitcoinChain, address: &str) -> Option<AddressType> {
+    let (version, _) = decode_base58check(address)?;
+    if version == chain.p2pkh_version() {
+        Some(AddressType::P2PKH)
+    } else if chain.p2sh_versions().contains(&version) {
+        Some(AddressType::P2SH)
+    } else {
+        None
+    }
+}
+
+/// 校验地址并返回类型：base58 P2PKH/P2SH、bech32/bech32m 或 CashAddr
+pub fn validate_address(chain: BitcoinChain, address: &str) -> Result<AddressType, String> {
+    decode_legacy(chain, address)
+        .or_else(|| decode_segwit(chain, address))
+        .or_else(|| decode_cashaddr(chain, address))
+        .ok_or_else(|| format!("Invalid {:?} address: {}", chain, address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_bitcoin() {
+        let chain = BitcoinChain::Bitcoin;
+        assert_eq!(
+            validate_address(chain, "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu"),
+            Ok(AddressType::P2PKH)
+        );
+        assert_eq!(
+            validate_address(chain, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"),
+            Ok(AddressType::P2SH)
+        );
+        assert_eq!(
+            validate_address(chain, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            Ok(AddressType::WitnessV0)
+        );
+        assert_eq!(
+            validate_address(
+                chain,
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"
+            ),
+            Ok(AddressType::WitnessV1Plus)
+        );
+
+        // 校验和错误、其他链与测试网地址
+        assert!(validate_address(chain, "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggv").is_err());
+        assert!(validate_address(chain, "ltc1qg82tnsaaxs6q3dzmf5v8ypvmcfr0dnm6nxm5sl").is_err());
+        assert!(validate_address(chain, "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx").is_err());
+        assert!(validate_address(chain, "0x000000000000000000000000000000000000dead").is_err());
+        // v0 程序使用 bech32m 校验和
+        assert!(validate_address(chain, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh").is_err());
+    }
+
+    #[test]
+    fn test_validate_other_chains() {
+        assert_eq!(
+            validate_address(BitcoinChain::Litecoin, "LVg2kJoFNg45Nbpy53h7Fe1wKyeXVRhMH9"),
+            Ok(AddressType::P2PKH)
+        );
+        assert_eq!(
+            validate_address(BitcoinChain::Doge, "DH5yaieqoZN36fDVciNyRueRGvGLR3mr7L"),
+            Ok(AddressType::P2PKH)
+        );
+        assert_eq!(
+            validate_address(
+                BitcoinChain::BitcoinCash,
+                "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"
+            ),
+            Ok(AddressType::P2PKH)
+        );
+        assert_eq!(
+            validate_address(
+                BitcoinChain::BitcoinCash,
+                "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu"
+            ),
+            Ok(AddressType::P2PKH)
+        );
+        assert!(
+            validate_address(BitcoinChain::Doge, "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu").is_err()
+        );
+        assert!(validate_address(
+            BitcoinChain::Bitcoin,
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"
+        )
+        .is_err());
+    }
+}
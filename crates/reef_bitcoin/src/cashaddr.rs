@@ -0,0 +1,161 @@
+/// CashAddr 编码，见 https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/cashaddr.md
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CHECKSUM_LEN: usize = 8;
+
+pub const TYPE_P2PKH: u8 = 0;
+pub const TYPE_P2SH: u8 = 8;
+
+fn polymod(values: &[u8]) -> u64 {
+    let mut c: u64 = 1;
+    for &d in values {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ d as u64;
+        if c0 & 0x01 != 0 {
+            c ^= 0x98_f2bc_8e61;
+        }
+        if c0 & 0x02 != 0 {
+            c ^= 0x79_b76d_99e2;
+        }
+        if c0 & 0x04 != 0 {
+            c ^= 0xf3_3e5f_b3c4;
+        }
+        if c0 & 0x08 != 0 {
+            c ^= 0xae_2eab_e2a8;
+        }
+        if c0 & 0x10 != 0 {
+            c ^= 0x1e_4f43_e470;
+        }
+    }
+    c ^ 1
+}
+
+fn prefix_values(prefix: &str) -> Vec<u8> {
+    prefix.bytes().map(|x| x & 0x1f).chain([0]).collect()
+}
+
+/// 5 位分组转回 8 位字节，多余的填充位必须为 0
+fn from_5bit(data: &[u8]) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut out = Vec::new();
+    for &value in data {
+        acc = (acc << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    if bits >= 5 || (acc & ((1 << bits) - 1)) != 0 {
+        return None;
+    }
+    Some(out)
+}
+
+fn to_5bit(data: &[u8]) -> Vec<u8> {
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut out = Vec::new();
+    for &value in data {
+        acc = (acc << 8) | value as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(((acc >> bits) & 0x1f) as u8);
+        }
+    }
+    if bits > 0 {
+        out.push(((acc << (5 - bits)) & 0x1f) as u8);
+    }
+    out
+}
+
+/// 解码为 `(类型, hash)`，省略前缀时使用 `default_prefix`
+pub fn decode(address: &str, default_prefix: &str) -> Option<(u8, Vec<u8>)> {
+    if address.to_lowercase() != address && address.to_uppercase() != address {
+        return None;
+    }
+    let address = address.to_lowercase();
+    let (prefix, payload) = match address.split_once(':') {
+        Some((prefix, payload)) => (prefix.to_string(), payload),
+        None => (default_prefix.to_string(), address.as_str()),
+    };
+    if prefix != default_prefix || payload.len() <= CHECKSUM_LEN {
+        return None;
+    }
+    let values = payload
+        .bytes()
+        .map(|x| CHARSET.iter().position(|&c| c == x).map(|x| x as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    if polymod(&[prefix_values(&prefix), values.clone()].concat()) != 0 {
+        return None;
+    }
+    let data = from_5bit(&values[..values.len() - CHECKSUM_LEN])?;
+    let (&version, hash) = data.split_first()?;
+    // 低 3 位为 hash 长度，仅支持 160 位
+    if version & 0x07 != 0 || hash.len() != 20 {
+        return None;
+    }
+    Some((version & 0x78, hash.to_vec()))
+}
+
+pub fn encode(prefix: &str, address_type: u8, hash: &[u8]) -> String {
+    let values = to_5bit(&[&[address_type], hash].concat());
+    let checksum =
+        polymod(&[prefix_values(prefix), values.clone(), vec![0; CHECKSUM_LEN]].concat());
+    let checksum = (0..CHECKSUM_LEN).map(|i| ((checksum >> (5 * (7 - i))) & 0x1f) as u8);
+    let payload: String = values
+        .into_iter()
+        .chain(checksum)
+        .map(|x| CHARSET[x as usize] as char)
+        .collect();
+    format!("{}:{}", prefix, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a";
+    const HASH: &str = "76a04053bda0a88bda5177b86a15c3b29f559873";
+
+    #[test]
+    fn test_decode_encode() {
+        let (address_type, hash) = decode(ADDRESS, "bitcoincash").unwrap();
+        assert_eq!(address_type, TYPE_P2PKH);
+        assert_eq!(
+            hash,
+            (0..HASH.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&HASH[i..i + 2], 16).unwrap())
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(encode("bitcoincash", TYPE_P2PKH, &hash), ADDRESS);
+
+        // 省略前缀与全大写
+        assert!(decode("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a", "bitcoincash").is_some());
+        assert!(decode(&ADDRESS.to_uppercase(), "bitcoincash").is_some());
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        // 校验和错误
+        assert!(decode(
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b",
+            "bitcoincash"
+        )
+        .is_none());
+        // 前缀不匹配
+        assert!(decode(
+            "bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            "bitcoincash"
+        )
+        .is_none());
+        // 大小写混合
+        assert!(decode(
+            "bitcoincash:Qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            "bitcoincash"
+        )
+        .is_none());
+    }
+}
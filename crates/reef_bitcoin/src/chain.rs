@@ -0,0 +1,74 @@
+use primitives::Chain;
+
+/// 比特币系 UTXO 链
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitcoinChain {
+    Bitcoin,
+    BitcoinCash,
+    Litecoin,
+    Doge,
+}
+
+impl BitcoinChain {
+    pub fn from_chain(chain: Chain) -> Option<Self> {
+        match chain {
+            Chain::Bitcoin => Some(Self::Bitcoin),
+            Chain::BitcoinCash => Some(Self::BitcoinCash),
+            Chain::Litecoin => Some(Self::Litecoin),
+            Chain::Doge => Some(Self::Doge),
+            _ => None,
+        }
+    }
+
+    pub fn to_chain(self) -> Chain {
+        match self {
+            Self::Bitcoin => Chain::Bitcoin,
+            Self::BitcoinCash => Chain::BitcoinCash,
+            Self::Litecoin => Chain::Litecoin,
+            Self::Doge => Chain::Doge,
+        }
+    }
+
+    /// base58 P2PKH 版本字节
+    pub fn p2pkh_version(&self) -> u8 {
+        match self {
+            Self::Bitcoin | Self::BitcoinCash => 0x00,
+            Self::Litecoin => 0x30,
+            Self::Doge => 0x1e,
+        }
+    }
+
+    /// base58 P2SH 版本字节，Litecoin 仍兼容旧的 `3` 开头地址
+    pub fn p2sh_versions(&self) -> &'static [u8] {
+        match self {
+            Self::Bitcoin | Self::BitcoinCash => &[0x05],
+            Self::Litecoin => &[0x32, 0x05],
+            Self::Doge => &[0x16],
+        }
+    }
+
+    /// bech32/bech32m 隔离见证地址前缀
+    pub fn segwit_hrp(&self) -> Option<&'static str> {
+        match self {
+            Self::Bitcoin => Some("bc"),
+            Self::Litecoin => Some("ltc"),
+            Self::BitcoinCash | Self::Doge => None,
+        }
+    }
+
+    /// CashAddr 前缀
+    pub fn cashaddr_prefix(&self) -> Option<&'static str> {
+        match self {
+            Self::BitcoinCash => Some("bitcoincash"),
+            _ => None,
+        }
+    }
+
+    /// 节点接受的最低费率 sat/vB
+    pub fn min_fee_rate(&self) -> u64 {
+        match self {
+            Self::Doge => 1000,
+            _ => 1,
+        }
+    }
+}
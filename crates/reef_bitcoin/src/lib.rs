@@ -0,0 +1,15 @@
+pub mod address;
+pub mod cashaddr;
+pub mod chain;
+pub mod models;
+pub mod provider;
+pub mod rpc;
+
+// 比特币系链的金额都以 satoshi 计
+pub const BITCOIN_DECIMALS: u8 = 8;
+pub const SATOSHIS_PER_COIN: u64 = 100_000_000;
+
+// Re-export for convenience
+pub use chain::BitcoinChain;
+pub use models::*;
+pub use rpc::BitcoinClient;
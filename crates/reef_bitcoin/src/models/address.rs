@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Blockbook `/api/v2/address/{address}`，金额为 satoshi 字符串
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockbookAddress {
+    pub address: String,
+    pub balance: String,
+    /// 内存池中的变动，可能为负
+    #[serde(default)]
+    pub unconfirmed_balance: String,
+    #[serde(default)]
+    pub txs: u64,
+}
+
+/// Blockbook `/api/v2/utxo/{address}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockbookUtxo {
+    pub txid: String,
+    pub vout: u32,
+    pub value: String,
+    /// 内存池中的输出没有高度
+    pub height: Option<u64>,
+    #[serde(default)]
+    pub confirmations: u32,
+    pub address: Option<String>,
+}
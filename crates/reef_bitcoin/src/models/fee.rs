@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Blockbook `/api/v2/estimatefee/{blocks}`，单位为币/kB
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockbookFee {
+    pub result: String,
+}
+
+/// 不同确认速度的费率，单位 sat/vB
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeRates {
+    pub fast: u64,
+    pub normal: u64,
+    pub slow: u64,
+}
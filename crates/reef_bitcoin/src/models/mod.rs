@@ -0,0 +1,7 @@
+pub mod address;
+pub mod fee;
+pub mod transaction;
+
+pub use address::*;
+pub use fee::*;
+pub use transaction::*;
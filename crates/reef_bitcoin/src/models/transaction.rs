@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Blockbook `/api/v2/sendtx/{hex}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockbookResult {
+    pub result: String,
+}
+
+/// Blockbook `/api/v2/tx/{txid}`，未确认时 `blockHeight` 为 -1 或 0
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockbookTransaction {
+    pub txid: String,
+    #[serde(default)]
+    pub block_height: i64,
+    #[serde(default)]
+    pub confirmations: u32,
+}
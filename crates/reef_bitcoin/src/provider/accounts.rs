@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use primitives::{
+    Chain, ChainNft, ChainProvider, ChainState, ChainToken, ChainTraits, ChainTransactions,
+};
+use reef_client::Client;
+use std::error::Error;
+
+use crate::rpc::client::BitcoinClient;
+
+impl<C: Client + Clone> ChainTraits for BitcoinClient<C> {}
+
+impl<C: Client + Clone> ChainProvider for BitcoinClient<C> {
+    fn get_chain(&self) -> Chain {
+        self.get_chain()
+    }
+
+    fn verify_address(&self, address: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.validate_address(&address)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainState for BitcoinClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainTransactions for BitcoinClient<C> {}
+
+// 比特币系链没有代币与 NFT
+#[async_trait]
+impl<C: Client + Clone> ChainToken for BitcoinClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainNft for BitcoinClient<C> {}
@@ -0,0 +1,84 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use num_bigint::BigInt;
+use primitives::{AssetBalance, Balance, ChainBalances};
+use reef_client::Client;
+
+use crate::models::BlockbookAddress;
+use crate::rpc::client::BitcoinClient;
+use crate::BITCOIN_DECIMALS;
+
+/// 已确认余额加上内存池中的变动
+fn map_balance(
+    chain: primitives::Chain,
+    address: &BlockbookAddress,
+) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+    let confirmed = address
+        .balance
+        .parse::<BigInt>()
+        .map_err(|e| format!("Invalid balance {}: {}", address.balance, e))?;
+    let unconfirmed = address
+        .unconfirmed_balance
+        .parse::<BigInt>()
+        .unwrap_or_default();
+    let available = (confirmed + unconfirmed).to_biguint().unwrap_or_default();
+
+    Ok(AssetBalance {
+        chain,
+        contract_address: None,
+        balance: Balance::coin_balance(available, BITCOIN_DECIMALS),
+        is_active: Some(address.txs > 0),
+    })
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for BitcoinClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let address = self.get_address(&address).await?;
+        map_balance(self.get_chain(), &address)
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        _address: String,
+        _token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::BitcoinChain;
+    use primitives::Chain;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let mock = MockClient::new().with_get(
+            &format!("/api/v2/address/{}?details=basic", ADDRESS),
+            json!({
+                "address": ADDRESS,
+                "balance": "150000000",
+                "unconfirmedBalance": "-50000000",
+                "txs": 4
+            }),
+        );
+        let client = BitcoinClient::new_with_client(mock, BitcoinChain::Bitcoin);
+
+        let balance = client.get_balance_coin(ADDRESS.to_string()).await.unwrap();
+        assert_eq!(balance.chain, Chain::Bitcoin);
+        assert_eq!(balance.balance.amount, "100000000");
+        assert_eq!(balance.balance.decimals, 8);
+        assert_eq!(balance.balance.ui_amount, Some(1.0));
+        assert_eq!(balance.is_active, Some(true));
+    }
+}
@@ -0,0 +1,69 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use primitives::{ChainUtxo, UTXO};
+use reef_client::Client;
+
+use crate::models::BlockbookUtxo;
+use crate::rpc::client::BitcoinClient;
+
+fn map_utxo(address: &str, utxo: BlockbookUtxo) -> UTXO {
+    UTXO {
+        transaction_id: utxo.txid,
+        vout: utxo.vout,
+        value: utxo.value,
+        // xpub 查询时才返回地址
+        address: utxo.address.unwrap_or_else(|| address.to_string()),
+        confirmations: utxo.confirmations,
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainUtxo for BitcoinClient<C> {
+    async fn get_utxos(&self, address: String) -> Result<Vec<UTXO>, Box<dyn Error + Sync + Send>> {
+        let utxos = self.get_address_utxos(&address).await?;
+        Ok(utxos.into_iter().map(|x| map_utxo(&address, x)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::BitcoinChain;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "DH5yaieqoZN36fDVciNyRueRGvGLR3mr7L";
+
+    #[tokio::test]
+    async fn test_get_utxos() {
+        let mock = MockClient::new().with_get(
+            &format!("/api/v2/utxo/{}", ADDRESS),
+            json!([
+                {
+                    "txid": "a1",
+                    "vout": 1,
+                    "value": "500000000",
+                    "height": 5000000,
+                    "confirmations": 12
+                },
+                { "txid": "b2", "vout": 0, "value": "100000000", "confirmations": 0 }
+            ]),
+        );
+        let client = BitcoinClient::new_with_client(mock, BitcoinChain::Doge);
+
+        let utxos = client.get_utxos(ADDRESS.to_string()).await.unwrap();
+        assert_eq!(utxos.len(), 2);
+        assert_eq!(
+            utxos[0],
+            UTXO {
+                transaction_id: "a1".to_string(),
+                vout: 1,
+                value: "500000000".to_string(),
+                address: ADDRESS.to_string(),
+                confirmations: 12,
+            }
+        );
+        assert_eq!(utxos[1].confirmations, 0);
+    }
+}
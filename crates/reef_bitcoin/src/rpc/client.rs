@@ -0,0 +1,229 @@
+use crate::address::{validate_address, AddressType};
+use crate::chain::BitcoinChain;
+use crate::models::{
+    BlockbookAddress, BlockbookFee, BlockbookResult, BlockbookTransaction, BlockbookUtxo, FeeRates,
+};
+use crate::SATOSHIS_PER_COIN;
+use primitives::{Chain, TransactionStatus, TransactionUpdate};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use std::error::Error;
+
+/// 各速度对应的目标确认区块数
+pub const FEE_TARGET_FAST: u32 = 1;
+pub const FEE_TARGET_NORMAL: u32 = 6;
+pub const FEE_TARGET_SLOW: u32 = 24;
+
+/// 交易 ID 为 32 字节的十六进制
+pub fn validate_txid(txid: &str) -> Result<(), String> {
+    if txid.len() != 64 || !txid.chars().all(|x| x.is_ascii_hexdigit()) {
+        return Err(format!("Invalid transaction id: {}", txid));
+    }
+    Ok(())
+}
+
+/// Blockbook REST API 客户端
+#[derive(Debug, Clone)]
+pub struct BitcoinClient<C: Client + Clone = ReqwestClient> {
+    pub chain: BitcoinChain,
+    client: C,
+}
+
+impl BitcoinClient {
+    pub fn new(url: String, chain: BitcoinChain) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url), chain)
+    }
+
+    pub fn new_with_config(
+        url: String,
+        config: &ClientConfig,
+        chain: BitcoinChain,
+    ) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(
+            ReqwestClient::new_with_config(url, config)?,
+            chain,
+        ))
+    }
+}
+
+impl<C: Client + Clone> BitcoinClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C, chain: BitcoinChain) -> Self {
+        Self { chain, client }
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        self.chain.to_chain()
+    }
+
+    pub fn validate_address(&self, address: &str) -> Result<AddressType, String> {
+        validate_address(self.chain, address)
+    }
+
+    pub async fn get_address(
+        &self,
+        address: &str,
+    ) -> Result<BlockbookAddress, Box<dyn Error + Send + Sync>> {
+        self.validate_address(address)?;
+        let path = format!("/api/v2/address/{}?details=basic", address);
+        Ok(self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get address: {}", e))?)
+    }
+
+    pub async fn get_address_utxos(
+        &self,
+        address: &str,
+    ) -> Result<Vec<BlockbookUtxo>, Box<dyn Error + Send + Sync>> {
+        self.validate_address(address)?;
+        let path = format!("/api/v2/utxo/{}", address);
+        Ok(self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get utxos: {}", e))?)
+    }
+
+    /// `blocks` 个区块内确认的费率，单位 sat/vB，不低于链的最低费率
+    pub async fn get_fee_rate(&self, blocks: u32) -> Result<u64, Box<dyn Error + Send + Sync>> {
+        let path = format!("/api/v2/estimatefee/{}", blocks);
+        let fee: BlockbookFee = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to estimate fee: {}", e))?;
+        let per_kb = fee
+            .result
+            .parse::<f64>()
+            .map_err(|e| format!("Invalid fee {}: {}", fee.result, e))?;
+        // 节点无法估算时返回 -1
+        if per_kb <= 0.0 {
+            return Ok(self.chain.min_fee_rate());
+        }
+        let per_vbyte = (per_kb * SATOSHIS_PER_COIN as f64 / 1000.0).ceil() as u64;
+        Ok(per_vbyte.max(self.chain.min_fee_rate()))
+    }
+
+    pub async fn get_fee_rates(&self) -> Result<FeeRates, Box<dyn Error + Send + Sync>> {
+        Ok(FeeRates {
+            fast: self.get_fee_rate(FEE_TARGET_FAST).await?,
+            normal: self.get_fee_rate(FEE_TARGET_NORMAL).await?,
+            slow: self.get_fee_rate(FEE_TARGET_SLOW).await?,
+        })
+    }
+
+    /// 广播已签名的原始交易 hex，返回交易 ID。交易放在 POST 正文中，GET 路径有长度限制
+    pub async fn broadcast_transaction(
+        &self,
+        data: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        if data.is_empty() || !data.chars().all(|x| x.is_ascii_hexdigit()) {
+            return Err("Invalid transaction hex".into());
+        }
+        let result: BlockbookResult = self
+            .client
+            .post_bytes("/api/v2/sendtx/", data.as_bytes().to_vec(), "text/plain")
+            .await
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        Ok(result.result)
+    }
+
+    pub async fn get_transaction(
+        &self,
+        txid: &str,
+    ) -> Result<BlockbookTransaction, Box<dyn Error + Send + Sync>> {
+        validate_txid(txid)?;
+        let path = format!("/api/v2/tx/{}", txid);
+        Ok(self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get transaction: {}", e))?)
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        txid: &str,
+    ) -> Result<TransactionUpdate, Box<dyn Error + Send + Sync>> {
+        let transaction = self.get_transaction(txid).await?;
+        let confirmed = transaction.confirmations > 0 && transaction.block_height > 0;
+        Ok(TransactionUpdate {
+            hash: transaction.txid,
+            status: if confirmed {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Pending
+            },
+            block_number: confirmed.then_some(transaction.block_height as u64),
+            confirmations: transaction.confirmations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const TXID: &str = "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4";
+
+    #[tokio::test]
+    async fn test_get_fee_rates() {
+        let mock = MockClient::new()
+            .with_get("/api/v2/estimatefee/1", json!({ "result": "0.00025" }))
+            .with_get("/api/v2/estimatefee/6", json!({ "result": "0.0000401" }))
+            .with_get("/api/v2/estimatefee/24", json!({ "result": "-1" }));
+        let client = BitcoinClient::new_with_client(mock, BitcoinChain::Bitcoin);
+
+        let rates = client.get_fee_rates().await.unwrap();
+        assert_eq!(
+            rates,
+            FeeRates {
+                fast: 25,
+                normal: 5,
+                slow: 1
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_and_status() {
+        let mock = MockClient::new()
+            .with_post_bytes("/api/v2/sendtx/", b"0100", json!({ "result": TXID }))
+            .with_get(
+                &format!("/api/v2/tx/{}", TXID),
+                json!({ "txid": TXID, "blockHeight": 800000, "confirmations": 3 }),
+            );
+        let client = BitcoinClient::new_with_client(mock, BitcoinChain::Bitcoin);
+
+        assert_eq!(client.broadcast_transaction("0100").await.unwrap(), TXID);
+
+        let update = client.get_transaction_status(TXID).await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Confirmed));
+        assert_eq!(update.block_number, Some(800000));
+        assert_eq!(update.confirmations, 3);
+
+        let missing = client.get_transaction_status(&TXID.replace('f', "e")).await;
+        assert!(missing.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_validate_path_params() {
+        let client = BitcoinClient::new_with_client(MockClient::new(), BitcoinChain::Bitcoin);
+        for txid in ["00", "../address/x", &format!("{}/../x", &TXID[..60])] {
+            let result = client.get_transaction(txid).await;
+            assert!(
+                result.unwrap_err().to_string().contains("Invalid"),
+                "{}",
+                txid
+            );
+        }
+        for address in ["../tx/x", "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4?x=1"] {
+            assert!(client.get_address(address).await.is_err());
+            assert!(client.get_address_utxos(address).await.is_err());
+        }
+        assert!(client.broadcast_transaction("01zz").await.is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::types::ClientError;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClientAuth {
+    Bearer(String),
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+    /// Key sent as a header, e.g. `TRON-PRO-API-KEY`
+    ApiKeyHeader {
+        name: String,
+        value: String,
+    },
+    /// Key appended to the endpoint URL, e.g. `?apikey=`
+    ApiKeyQuery {
+        name: String,
+        value: String,
+    },
+}
+
+/// Settings for the `reqwest::Client` behind a `ReqwestClient`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientConfig {
+    pub connect_timeout: Duration,
+    /// Whole request including the response body
+    pub timeout: Duration,
+    pub headers: HashMap<String, String>,
+    pub auth: Option<ClientAuth>,
+    pub gzip: bool,
+    pub brotli: bool,
+    /// Speak HTTP/2 without ALPN negotiation, for nodes known to support it
+    pub http2_prior_knowledge: bool,
+    /// `http://`, `https://` or `socks5://` proxy for every request
+    pub proxy: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            headers: HashMap::new(),
+            auth: None,
+            gzip: true,
+            brotli: true,
+            http2_prior_knowledge: false,
+            proxy: None,
+            user_agent: None,
+        }
+    }
+}
+
+fn config_error(error: impl std::fmt::Display) -> ClientError {
+    ClientError::RequestFailed(format!("Invalid client config: {}", error))
+}
+
+fn header(name: &str, value: &str) -> Result<(HeaderName, HeaderValue), ClientError> {
+    let name = HeaderName::from_bytes(name.as_bytes()).map_err(config_error)?;
+    let mut value = HeaderValue::from_str(value).map_err(config_error)?;
+    if name == AUTHORIZATION || name.as_str().contains("key") {
+        value.set_sensitive(true);
+    }
+    Ok((name, value))
+}
+
+impl ClientConfig {
+    pub fn with_timeouts(mut self, connect_timeout: Duration, timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn with_auth(mut self, auth: Option<ClientAuth>) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    pub fn with_proxy(mut self, proxy: Option<String>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.user_agent = user_agent;
+        self
+    }
+
+    fn default_headers(&self) -> Result<HeaderMap, ClientError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let (name, value) = header(name, value)?;
+            headers.insert(name, value);
+        }
+        match &self.auth {
+            Some(ClientAuth::Bearer(token)) => {
+                let (name, value) = header(AUTHORIZATION.as_str(), &format!("Bearer {}", token))?;
+                headers.insert(name, value);
+            }
+            Some(ClientAuth::ApiKeyHeader { name, value }) => {
+                let (name, value) = header(name, value)?;
+                headers.insert(name, value);
+            }
+            // applied per request by `ReqwestClient`
+            Some(ClientAuth::Basic { .. }) | Some(ClientAuth::ApiKeyQuery { .. }) | None => {}
+        }
+        Ok(headers)
+    }
+
+    pub fn build(&self) -> Result<reqwest::Client, ClientError> {
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.timeout)
+            .default_headers(self.default_headers()?)
+            .gzip(self.gzip)
+            .brotli(self.brotli);
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy).map_err(config_error)?);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        builder.build().map_err(config_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = ClientConfig::default();
+        assert_eq!(config.connect_timeout, DEFAULT_CONNECT_TIMEOUT);
+        assert_eq!(config.timeout, DEFAULT_REQUEST_TIMEOUT);
+        assert!(config.gzip && config.brotli);
+        assert!(config.build().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let config = ClientConfig::default().with_header("bad header", "value");
+        assert!(matches!(config.build(), Err(ClientError::RequestFailed(_))));
+
+        let config = ClientConfig::default().with_proxy(Some("::not a proxy::".to_string()));
+        assert!(config.build().is_err());
+    }
+}
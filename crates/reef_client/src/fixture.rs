@@ -0,0 +1,353 @@
+use crate::mock_client::MockClient;
+use crate::types::{Client, ClientError};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Set to record fixtures from the real endpoint instead of replaying them
+pub const RECORD_FIXTURES_ENV: &str = "REEF_RECORD_FIXTURES";
+
+/// One recorded request, `method` is `GET <path>` or `POST <path>` for plain HTTP requests
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureEntry {
+    pub method: String,
+    /// `null` matches any params
+    #[serde(default)]
+    pub params: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Value>,
+}
+
+impl FixtureEntry {
+    pub fn matches(&self, method: &str, params: &Value) -> bool {
+        self.method == method && (self.params.is_null() || &self.params == params)
+    }
+}
+
+pub fn get_method(path: &str) -> String {
+    format!("GET {}", path)
+}
+
+pub fn post_method(path: &str) -> String {
+    format!("POST {}", path)
+}
+
+/// Raw request bodies are recorded as lowercase hex
+pub fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+/// JSON-RPC request or batch, anything else is a plain HTTP body
+pub fn is_jsonrpc(request: &Value) -> bool {
+    request.is_array() || request.get("jsonrpc").is_some()
+}
+
+/// JSON fixture file, an array of entries
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Fixture {
+    pub entries: Vec<FixtureEntry>,
+}
+
+impl Fixture {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ClientError> {
+        let path = path.as_ref();
+        let data = std::fs::read_to_string(path)
+            .map_err(|e| ClientError::RequestFailed(format!("{}: {}", path.display(), e)))?;
+        serde_json::from_str(&data).map_err(|e| ClientError::SerializationError(e.to_string()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ClientError> {
+        let path = path.as_ref();
+        let data = serde_json::to_string_pretty(self)
+            .map_err(|e| ClientError::SerializationError(e.to_string()))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| ClientError::RequestFailed(e.to_string()))?;
+        }
+        std::fs::write(path, data + "\n")
+            .map_err(|e| ClientError::RequestFailed(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Keeps the first response for a request, later identical requests are not recorded
+    pub fn push(&mut self, entry: FixtureEntry) {
+        let exists = self
+            .entries
+            .iter()
+            .any(|x| x.method == entry.method && x.params == entry.params);
+        if !exists {
+            self.entries.push(entry);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Recorder {
+    fixture: Mutex<Fixture>,
+    /// Written when the last clone is dropped
+    path: Option<PathBuf>,
+}
+
+impl Recorder {
+    /// A test that panicked while recording must not make the drop panic too
+    fn lock(&self) -> MutexGuard<'_, Fixture> {
+        self.fixture.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Some(path) = &self.path {
+            let fixture = self.lock();
+            if let Err(error) = fixture.save(path) {
+                tracing::warn!("failed to save fixture {}: {}", path.display(), error);
+            }
+        }
+    }
+}
+
+/// Forwards requests to `inner` and records every JSON-RPC response
+#[derive(Debug, Clone)]
+pub struct RecordingClient<C: Client> {
+    inner: C,
+    recorder: Arc<Recorder>,
+}
+
+impl<C: Client> RecordingClient<C> {
+    pub fn new(inner: C) -> Self {
+        Self::new_with_path(inner, None)
+    }
+
+    /// Saves the fixture to `path` once every clone is dropped
+    pub fn new_with_path(inner: C, path: Option<PathBuf>) -> Self {
+        Self {
+            inner,
+            recorder: Arc::new(Recorder {
+                fixture: Mutex::new(Fixture::default()),
+                path,
+            }),
+        }
+    }
+
+    pub fn fixture(&self) -> Fixture {
+        self.recorder.lock().clone()
+    }
+
+    fn record(&self, request: &Value, response: &Value) {
+        let mut fixture = self.recorder.lock();
+        let responses: Vec<&Value> = match response {
+            Value::Array(x) => x.iter().collect(),
+            x => vec![x],
+        };
+        let requests: Vec<&Value> = match request {
+            Value::Array(x) => x.iter().collect(),
+            x => vec![x],
+        };
+        for request in requests {
+            let Some(response) = responses.iter().find(|x| x.get("id") == request.get("id")) else {
+                continue;
+            };
+            fixture.push(FixtureEntry {
+                method: request["method"].as_str().unwrap_or_default().to_string(),
+                params: request.get("params").cloned().unwrap_or(Value::Null),
+                result: response.get("result").cloned(),
+                error: response.get("error").cloned(),
+            });
+        }
+    }
+}
+
+#[async_trait]
+impl<C: Client> Client for RecordingClient<C> {
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        let response: Value = self.inner.get(path).await?;
+        self.recorder.lock().push(FixtureEntry {
+            method: get_method(path),
+            params: Value::Null,
+            result: Some(response.clone()),
+            error: None,
+        });
+        serde_json::from_value(response).map_err(|e| ClientError::SerializationError(e.to_string()))
+    }
+
+    async fn post<T, R>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        let request = serde_json::to_value(body)
+            .map_err(|e| ClientError::SerializationError(e.to_string()))?;
+        let response: Value = self.inner.post(path, &request, headers).await?;
+        if is_jsonrpc(&request) {
+            self.record(&request, &response);
+        } else {
+            self.recorder.lock().push(FixtureEntry {
+                method: post_method(path),
+                params: request,
+                result: Some(response.clone()),
+                error: None,
+            });
+        }
+        serde_json::from_value(response).map_err(|e| ClientError::SerializationError(e.to_string()))
+    }
+
+    async fn post_bytes<R>(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        let request = Value::String(hex_string(&body));
+        let response: Value = self.inner.post_bytes(path, body, content_type).await?;
+        self.recorder.lock().push(FixtureEntry {
+            method: post_method(path),
+            params: request,
+            result: Some(response.clone()),
+            error: None,
+        });
+        serde_json::from_value(response).map_err(|e| ClientError::SerializationError(e.to_string()))
+    }
+}
+
+/// Replays `path` offline, or records it through `inner` when `REEF_RECORD_FIXTURES` is set
+#[derive(Debug, Clone)]
+pub enum FixtureClient<C: Client> {
+    Replay(MockClient),
+    Record(RecordingClient<C>),
+}
+
+impl<C: Client> FixtureClient<C> {
+    pub fn new(path: impl AsRef<Path>, inner: C) -> Result<Self, ClientError> {
+        let path = path.as_ref();
+        if std::env::var_os(RECORD_FIXTURES_ENV).is_some() {
+            return Ok(Self::Record(RecordingClient::new_with_path(
+                inner,
+                Some(path.to_path_buf()),
+            )));
+        }
+        let fixture = Fixture::load(path)?;
+        Ok(Self::Replay(
+            MockClient::new_with_url(inner.base_url().to_string()).with_fixture(fixture),
+        ))
+    }
+}
+
+#[async_trait]
+impl<C: Client> Client for FixtureClient<C> {
+    fn base_url(&self) -> &str {
+        match self {
+            Self::Replay(client) => client.base_url(),
+            Self::Record(client) => client.base_url(),
+        }
+    }
+
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        match self {
+            Self::Replay(client) => client.get(path).await,
+            Self::Record(client) => client.get(path).await,
+        }
+    }
+
+    async fn post<T, R>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        match self {
+            Self::Replay(client) => client.post(path, body, headers).await,
+            Self::Record(client) => client.post(path, body, headers).await,
+        }
+    }
+
+    async fn post_bytes<R>(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        match self {
+            Self::Replay(client) => client.post_bytes(path, body, content_type).await,
+            Self::Record(client) => client.post_bytes(path, body, content_type).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let upstream = MockClient::new()
+            .with_response("getSlot", json!([]), json!(42))
+            .with_get("/status", json!({ "ok": true }));
+        let recording = RecordingClient::new(upstream);
+
+        let request = json!([
+            { "jsonrpc": "2.0", "id": 1, "method": "getSlot", "params": [] },
+            { "jsonrpc": "2.0", "id": 2, "method": "getSlot", "params": [] },
+        ]);
+        let _: Value = recording.post("", &request, None).await.unwrap();
+        let _: Value = recording.get("/status").await.unwrap();
+
+        let fixture = recording.fixture();
+        assert_eq!(fixture.entries.len(), 2);
+        assert_eq!(fixture.entries[0].result, Some(json!(42)));
+        assert_eq!(fixture.entries[1].method, "GET /status");
+
+        let path = std::env::temp_dir().join(format!("reef_fixture_{}.json", std::process::id()));
+        fixture.save(&path).unwrap();
+        let loaded = Fixture::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, fixture);
+
+        let replay = MockClient::new().with_fixture(loaded);
+        let response: Value = replay
+            .post(
+                "",
+                &json!({ "jsonrpc": "2.0", "id": 9, "method": "getSlot", "params": [] }),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response, json!({ "jsonrpc": "2.0", "id": 9, "result": 42 }));
+    }
+
+    #[test]
+    fn test_fixture_client_missing_file() {
+        let result = FixtureClient::new("/nonexistent/fixture.json", MockClient::new());
+        assert!(matches!(result, Err(ClientError::RequestFailed(_))));
+    }
+}
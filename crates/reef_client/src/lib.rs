@@ -1,5 +1,17 @@
+mod client_config;
+mod fixture;
+mod mock_client;
 mod reqwest_client;
 mod types;
+mod untrusted_client;
 
+pub use client_config::{
+    ClientAuth, ClientConfig, DEFAULT_CONNECT_TIMEOUT, DEFAULT_REQUEST_TIMEOUT,
+};
+pub use fixture::{Fixture, FixtureClient, FixtureEntry, RecordingClient, RECORD_FIXTURES_ENV};
+pub use mock_client::{MockClient, ERROR_NO_MOCK};
 pub use reqwest_client::ReqwestClient;
 pub use types::{Client, ClientError};
+pub use untrusted_client::{
+    is_public_ip, UntrustedClient, UNTRUSTED_MAX_BODY_BYTES, UNTRUSTED_TIMEOUT,
+};
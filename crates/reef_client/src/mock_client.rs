@@ -0,0 +1,291 @@
+use crate::fixture::{get_method, hex_string, is_jsonrpc, post_method, Fixture, FixtureEntry};
+use crate::types::{Client, ClientError};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// JSON-RPC error code for requests without a canned response
+pub const ERROR_NO_MOCK: i32 = -32601;
+
+/// Answers JSON-RPC requests from canned responses, matched by method and params
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    base_url: String,
+    fixture: Fixture,
+    /// `(method, params)` of every request, in order
+    requests: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        Self::new_with_url("http://mock".to_string())
+    }
+
+    pub fn new_with_url(base_url: String) -> Self {
+        Self {
+            base_url,
+            ..Default::default()
+        }
+    }
+
+    /// Responds to `method` called with exactly `params`
+    pub fn with_response(self, method: &str, params: Value, result: Value) -> Self {
+        self.with_entry(FixtureEntry {
+            method: method.to_string(),
+            params,
+            result: Some(result),
+            error: None,
+        })
+    }
+
+    /// Responds to `method` whatever the params
+    pub fn with_method_response(self, method: &str, result: Value) -> Self {
+        self.with_response(method, Value::Null, result)
+    }
+
+    pub fn with_error(self, method: &str, params: Value, code: i32, message: &str) -> Self {
+        self.with_entry(FixtureEntry {
+            method: method.to_string(),
+            params,
+            result: None,
+            error: Some(json!({ "code": code, "message": message })),
+        })
+    }
+
+    /// Responds to a GET of `path` with `response` as the body
+    pub fn with_get(self, path: &str, response: Value) -> Self {
+        self.with_response(&get_method(path), Value::Null, response)
+    }
+
+    /// Responds to a plain POST of `body` to `path`, a `null` body matches any body
+    pub fn with_post(self, path: &str, body: Value, response: Value) -> Self {
+        self.with_response(&post_method(path), body, response)
+    }
+
+    /// Responds to a raw POST to `path`, the body is matched as a lowercase hex string
+    pub fn with_post_bytes(self, path: &str, body: &[u8], response: Value) -> Self {
+        self.with_post(path, Value::String(hex_string(body)), response)
+    }
+
+    /// Earlier entries win over later ones for the same request
+    pub fn with_entry(mut self, entry: FixtureEntry) -> Self {
+        self.fixture.entries.push(entry);
+        self
+    }
+
+    pub fn with_fixture(mut self, fixture: Fixture) -> Self {
+        self.fixture.entries.extend(fixture.entries);
+        self
+    }
+
+    pub fn requests(&self) -> Vec<(String, Value)> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn find(&self, method: &str, params: &Value) -> Option<&FixtureEntry> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((method.to_string(), params.clone()));
+        self.fixture
+            .entries
+            .iter()
+            .find(|x| x.matches(method, params))
+    }
+
+    /// Plain HTTP request, 404 without a canned response
+    fn respond_http<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        body: &Value,
+    ) -> Result<R, ClientError> {
+        let entry = self.find(method, body).ok_or(ClientError::HttpError {
+            status: 404,
+            retry_after: None,
+        })?;
+        serde_json::from_value(entry.result.clone().unwrap_or(Value::Null))
+            .map_err(|e| ClientError::SerializationError(e.to_string()))
+    }
+
+    fn respond(&self, request: &Value) -> Value {
+        let method = request["method"].as_str().unwrap_or_default();
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+
+        match self.find(method, &params) {
+            Some(FixtureEntry {
+                error: Some(error), ..
+            }) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+            Some(entry) => {
+                json!({ "jsonrpc": "2.0", "id": id, "result": entry.result.clone().unwrap_or(Value::Null) })
+            }
+            None => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {
+                    "code": ERROR_NO_MOCK,
+                    "message": format!("No mock response for {} {}", method, params),
+                },
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl Client for MockClient {
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.respond_http(&get_method(path), &Value::Null)
+    }
+
+    async fn post<T, R>(
+        &self,
+        path: &str,
+        body: &T,
+        _headers: Option<HashMap<String, String>>,
+    ) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        let request = serde_json::to_value(body)
+            .map_err(|e| ClientError::SerializationError(e.to_string()))?;
+        if !is_jsonrpc(&request) {
+            return self.respond_http(&post_method(path), &request);
+        }
+        let response = match &request {
+            Value::Array(requests) => {
+                Value::Array(requests.iter().map(|x| self.respond(x)).collect())
+            }
+            request => self.respond(request),
+        };
+        serde_json::from_value(response).map_err(|e| ClientError::SerializationError(e.to_string()))
+    }
+
+    async fn post_bytes<R>(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        _content_type: &str,
+    ) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.respond_http(&post_method(path), &Value::String(hex_string(&body)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(id: u64, method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+    }
+
+    #[tokio::test]
+    async fn test_match_method_and_params() {
+        let client = MockClient::new()
+            .with_response("getBalance", json!(["a"]), json!({ "value": 1 }))
+            .with_method_response("getBalance", json!({ "value": 0 }))
+            .with_error("getSlot", json!([]), -32005, "Node is behind");
+
+        let response: Value = client
+            .post("", &call(1, "getBalance", json!(["a"])), None)
+            .await
+            .unwrap();
+        assert_eq!(response["result"], json!({ "value": 1 }));
+
+        let response: Value = client
+            .post("", &call(2, "getBalance", json!(["b"])), None)
+            .await
+            .unwrap();
+        assert_eq!(response["result"], json!({ "value": 0 }));
+
+        let response: Value = client
+            .post("", &call(3, "getSlot", json!([])), None)
+            .await
+            .unwrap();
+        assert_eq!(response["error"]["code"], -32005);
+
+        let response: Value = client
+            .post("", &call(4, "getHealth", json!([])), None)
+            .await
+            .unwrap();
+        assert_eq!(response["id"], 4);
+        assert_eq!(response["error"]["code"], ERROR_NO_MOCK);
+
+        assert_eq!(client.requests().len(), 4);
+        assert_eq!(
+            client.requests()[1],
+            ("getBalance".to_string(), json!(["b"]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_and_get() {
+        let client = MockClient::new()
+            .with_method_response("eth_chainId", json!("0x1"))
+            .with_get("/api/v2/status", json!({ "ok": true }));
+
+        let response: Value = client
+            .post(
+                "",
+                &json!([
+                    call(1, "eth_chainId", json!([])),
+                    call(2, "eth_blockNumber", json!([]))
+                ]),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response[0]["result"], "0x1");
+        assert_eq!(response[1]["error"]["code"], ERROR_NO_MOCK);
+
+        let status: Value = client.get("/api/v2/status").await.unwrap();
+        assert_eq!(status, json!({ "ok": true }));
+        let missing = client.get::<Value>("/missing").await;
+        assert!(matches!(
+            missing,
+            Err(ClientError::HttpError { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_post() {
+        let client = MockClient::new().with_post(
+            "/wallet/getaccount",
+            json!({ "address": "a" }),
+            json!({ "balance": 1 }),
+        );
+
+        let account: Value = client
+            .post("/wallet/getaccount", &json!({ "address": "a" }), None)
+            .await
+            .unwrap();
+        assert_eq!(account, json!({ "balance": 1 }));
+
+        let missing = client
+            .post::<Value, Value>("/wallet/getaccount", &json!({ "address": "b" }), None)
+            .await;
+        assert!(matches!(
+            missing,
+            Err(ClientError::HttpError { status: 404, .. })
+        ));
+        assert_eq!(
+            client.requests()[0],
+            (
+                "POST /wallet/getaccount".to_string(),
+                json!({ "address": "a" })
+            )
+        );
+    }
+}
@@ -1,32 +1,78 @@
+use crate::client_config::{ClientAuth, ClientConfig};
 use crate::types::{Client, ClientError};
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Serialize};
 use std::collections::HashMap;
 
+/// The url may carry an API key in its path or query, so it is stripped from the message
+fn send_error(error: reqwest::Error) -> ClientError {
+    let error = error.without_url();
+    if error.is_timeout() {
+        ClientError::Timeout(error.to_string())
+    } else {
+        ClientError::NetworkError(error.to_string())
+    }
+}
+
+fn decode_error(error: reqwest::Error) -> ClientError {
+    ClientError::SerializationError(error.without_url().to_string())
+}
+
+fn http_error(response: &reqwest::Response) -> ClientError {
+    // only the delay-seconds form, HTTP dates are ignored
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| x.trim().parse::<u64>().ok());
+    ClientError::HttpError {
+        status: response.status().as_u16(),
+        retry_after,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ReqwestClient {
     base_url: String,
     client: reqwest::Client,
+    /// Basic auth and query keys, header credentials are default headers of `client`
+    auth: Option<ClientAuth>,
 }
 
 impl ReqwestClient {
     pub fn new(base_url: String, client: reqwest::Client) -> Self {
-        Self { base_url, client }
+        Self {
+            base_url,
+            client,
+            auth: None,
+        }
     }
 
+    /// Default timeouts, see `ClientConfig::default`
     pub fn new_with_url(base_url: String) -> Self {
-        let client = reqwest::Client::new();
-        Self::new(base_url, client)
+        Self::new_with_config(base_url, &ClientConfig::default())
+            .expect("default client config is valid")
     }
-}
 
-#[async_trait]
-impl Client for ReqwestClient {
-    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
-    where
-        R: DeserializeOwned,
-    {
-        let url = if path.is_empty() {
+    pub fn new_with_config(base_url: String, config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(base_url, config.build()?, config))
+    }
+
+    /// Share one connection pool built from `config` across several endpoints
+    pub fn new_with_client(
+        base_url: String,
+        client: reqwest::Client,
+        config: &ClientConfig,
+    ) -> Self {
+        Self {
+            base_url,
+            client,
+            auth: config.auth.clone(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        if path.is_empty() {
             self.base_url.clone()
         } else {
             format!(
@@ -34,25 +80,43 @@ impl Client for ReqwestClient {
                 self.base_url.trim_end_matches('/'),
                 path.trim_start_matches('/')
             )
-        };
+        }
+    }
+
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.auth {
+            Some(ClientAuth::Basic { username, password }) => {
+                request.basic_auth(username, password.as_ref())
+            }
+            Some(ClientAuth::ApiKeyQuery { name, value }) => request.query(&[(name, value)]),
+            _ => request,
+        }
+    }
+}
+
+#[async_trait]
+impl Client for ReqwestClient {
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        let url = self.url(path);
 
         let response = self
-            .client
-            .get(&url)
+            .authorize(self.client.get(&url))
             .send()
             .await
-            .map_err(|e| ClientError::NetworkError(e.to_string()))?;
+            .map_err(send_error)?;
 
         if !response.status().is_success() {
-            return Err(ClientError::HttpError {
-                status: response.status().as_u16(),
-            });
+            return Err(http_error(&response));
         }
 
-        response
-            .json::<R>()
-            .await
-            .map_err(|e| ClientError::SerializationError(e.to_string()))
+        response.json::<R>().await.map_err(decode_error)
     }
 
     async fn post<T, R>(
@@ -65,17 +129,9 @@ impl Client for ReqwestClient {
         T: Serialize + Send + Sync,
         R: DeserializeOwned,
     {
-        let url = if path.is_empty() {
-            self.base_url.clone()
-        } else {
-            format!(
-                "{}/{}",
-                self.base_url.trim_end_matches('/'),
-                path.trim_start_matches('/')
-            )
-        };
+        let url = self.url(path);
 
-        let mut request = self.client.post(&url).json(body);
+        let mut request = self.authorize(self.client.post(&url)).json(body);
 
         if let Some(headers) = headers {
             for (key, value) in headers {
@@ -83,38 +139,43 @@ impl Client for ReqwestClient {
             }
         }
 
-        let response = request
+        let response = request.send().await.map_err(send_error)?;
+
+        if !response.status().is_success() {
+            return Err(http_error(&response));
+        }
+
+        response.json::<R>().await.map_err(decode_error)
+    }
+
+    async fn post_bytes<R>(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        let response = self
+            .authorize(self.client.post(self.url(path)))
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body)
             .send()
             .await
-            .map_err(|e| ClientError::NetworkError(e.to_string()))?;
+            .map_err(send_error)?;
 
         if !response.status().is_success() {
-            return Err(ClientError::HttpError {
-                status: response.status().as_u16(),
-            });
+            return Err(http_error(&response));
         }
 
-        response
-            .json::<R>()
-            .await
-            .map_err(|e| ClientError::SerializationError(e.to_string()))
+        response.json::<R>().await.map_err(decode_error)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde::{Deserialize, Serialize};
-
-    #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    struct TestRequest {
-        message: String,
-    }
-
-    #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    struct TestResponse {
-        echo: String,
-    }
 
     #[test]
     fn test_reqwest_client_creation() {
@@ -136,4 +197,148 @@ mod tests {
         let cloned = client.clone();
         assert_eq!(client.base_url, cloned.base_url);
     }
+
+    #[tokio::test]
+    async fn test_config_headers_and_auth() {
+        use wiremock::matchers::{header, method, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("TRON-PRO-API-KEY", "trongrid_secret"))
+            .and(header("x-client", "reef"))
+            .and(header("user-agent", "reef-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!("header")))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(query_param("apikey", "ankr_secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!("query")))
+            .mount(&server)
+            .await;
+
+        let config = ClientConfig::default()
+            .with_header("x-client", "reef")
+            .with_user_agent(Some("reef-test".to_string()))
+            .with_auth(Some(ClientAuth::ApiKeyHeader {
+                name: "TRON-PRO-API-KEY".to_string(),
+                value: "trongrid_secret".to_string(),
+            }));
+        let client = ReqwestClient::new_with_config(server.uri(), &config).unwrap();
+        let result: String = client.post("", &serde_json::json!({}), None).await.unwrap();
+        assert_eq!(result, "header");
+
+        let config = ClientConfig::default().with_auth(Some(ClientAuth::ApiKeyQuery {
+            name: "apikey".to_string(),
+            value: "ankr_secret".to_string(),
+        }));
+        let client = ReqwestClient::new_with_config(server.uri(), &config).unwrap();
+        let result: String = client.get("/v1/status").await.unwrap();
+        assert_eq!(result, "query");
+        assert_eq!(client.base_url(), server.uri());
+    }
+
+    #[tokio::test]
+    async fn test_config_basic_auth() {
+        use wiremock::matchers::{header, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        // base64("user:pass")
+        Mock::given(method("GET"))
+            .and(header("authorization", "Basic dXNlcjpwYXNz"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!(true)))
+            .mount(&server)
+            .await;
+
+        let config = ClientConfig::default().with_auth(Some(ClientAuth::Basic {
+            username: "user".to_string(),
+            password: Some("pass".to_string()),
+        }));
+        let client = ReqwestClient::new_with_config(server.uri(), &config).unwrap();
+        assert!(client.get::<bool>("").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_post_bytes() {
+        use wiremock::matchers::{body_bytes, header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transactions"))
+            .and(header(
+                "content-type",
+                "application/x.aptos.signed_transaction+bcs",
+            ))
+            .and(body_bytes(vec![0xde, 0xad]))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(serde_json::json!({ "hash": "0x1" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ReqwestClient::new_with_url(server.uri());
+        let result: serde_json::Value = client
+            .post_bytes(
+                "/v1/transactions",
+                vec![0xde, 0xad],
+                "application/x.aptos.signed_transaction+bcs",
+            )
+            .await
+            .unwrap();
+        assert_eq!(result["hash"], "0x1");
+    }
+
+    #[tokio::test]
+    async fn test_config_timeout() {
+        use std::time::Duration;
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(500)))
+            .mount(&server)
+            .await;
+
+        let config = ClientConfig::default()
+            .with_timeouts(Duration::from_secs(1), Duration::from_millis(50));
+        let client = ReqwestClient::new_with_config(server.uri(), &config).unwrap();
+        let result = client
+            .post::<_, String>("", &serde_json::json!({}), None)
+            .await;
+        assert!(matches!(result, Err(ClientError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_errors_do_not_leak_url() {
+        use std::time::Duration;
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+            .mount(&server)
+            .await;
+
+        let config = ClientConfig::default().with_auth(Some(ClientAuth::ApiKeyQuery {
+            name: "apikey".to_string(),
+            value: "ankr_secret".to_string(),
+        }));
+        let client =
+            ReqwestClient::new_with_config(format!("{}/v2/path_secret", server.uri()), &config)
+                .unwrap();
+        let error = client.get::<String>("").await.unwrap_err().to_string();
+        assert!(!error.contains("secret"), "{}", error);
+
+        // nothing listens on port 9 of the loopback
+        let config = config.with_timeouts(Duration::from_millis(200), Duration::from_millis(200));
+        let client = ReqwestClient::new_with_config(
+            "http://127.0.0.1:9/v2/path_secret".to_string(),
+            &config,
+        )
+        .unwrap();
+        let error = client.get::<String>("").await.unwrap_err().to_string();
+        assert!(!error.contains("secret"), "{}", error);
+    }
 }
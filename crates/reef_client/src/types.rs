@@ -11,12 +11,21 @@ pub enum ClientError {
     SerializationError(String),
     #[error("Network error: {0}")]
     NetworkError(String),
+    #[error("Timeout: {0}")]
+    Timeout(String),
+    /// `retry_after` is the `Retry-After` header in seconds, if the server sent one
     #[error("HTTP error: {status}")]
-    HttpError { status: u16 },
+    HttpError {
+        status: u16,
+        retry_after: Option<u64>,
+    },
 }
 
 #[async_trait]
 pub trait Client: Send + Sync + Debug {
+    /// Endpoint requests are sent to, may contain an API key
+    fn base_url(&self) -> &str;
+
     async fn get<R>(&self, path: &str) -> Result<R, ClientError>
     where
         R: DeserializeOwned;
@@ -30,6 +39,21 @@ pub trait Client: Send + Sync + Debug {
     where
         T: Serialize + Send + Sync,
         R: DeserializeOwned;
+
+    /// POST a raw body, e.g. a BCS encoded transaction, and parse the JSON response
+    async fn post_bytes<R>(
+        &self,
+        _path: &str,
+        _body: Vec<u8>,
+        _content_type: &str,
+    ) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        Err(ClientError::RequestFailed(
+            "Raw request bodies are not supported".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -41,7 +65,10 @@ mod tests {
         let error1 = ClientError::RequestFailed("Request timeout".to_string());
         assert_eq!(error1.to_string(), "Request failed: Request timeout");
 
-        let error2 = ClientError::HttpError { status: 404 };
+        let error2 = ClientError::HttpError {
+            status: 404,
+            retry_after: None,
+        };
         assert_eq!(error2.to_string(), "HTTP error: 404");
 
         let error3 = ClientError::NetworkError("Connection refused".to_string());
@@ -49,6 +76,9 @@ mod tests {
 
         let error4 = ClientError::SerializationError("Invalid JSON".to_string());
         assert_eq!(error4.to_string(), "Serialization error: Invalid JSON");
+
+        let error5 = ClientError::Timeout("operation timed out".to_string());
+        assert_eq!(error5.to_string(), "Timeout: operation timed out");
     }
 
     #[test]
@@ -0,0 +1,54 @@
+use bech32::{primitives::decode::CheckedHrpstring, Bech32};
+
+use crate::chain::CosmosChain;
+
+/// 账户为 20 字节，合约与模块账户为 32 字节
+const ADDRESS_LENGTHS: [usize; 2] = [20, 32];
+
+/// 校验 bech32 地址与链的前缀
+pub fn validate_address(chain: CosmosChain, address: &str) -> Result<(), String> {
+    let invalid = |reason: &str| format!("Invalid {:?} address {}: {}", chain, address, reason);
+    let decoded = CheckedHrpstring::new::<Bech32>(address).map_err(|e| invalid(&e.to_string()))?;
+    if decoded.hrp().as_str() != chain.hrp() {
+        return Err(invalid("wrong prefix"));
+    }
+    if !ADDRESS_LENGTHS.contains(&decoded.byte_iter().count()) {
+        return Err(invalid("wrong length"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_address() {
+        assert!(validate_address(
+            CosmosChain::Cosmos,
+            "cosmos1fl48vsnmsdzcv85q5d2q4z5ajdha8yu34mf0eh"
+        )
+        .is_ok());
+        // 同一账户在其他链上只有前缀不同
+        let (_, data) = bech32::decode("cosmos1fl48vsnmsdzcv85q5d2q4z5ajdha8yu34mf0eh").unwrap();
+        let osmo = bech32::encode::<Bech32>(bech32::Hrp::parse("osmo").unwrap(), &data).unwrap();
+        assert!(validate_address(CosmosChain::Osmosis, &osmo).is_ok());
+        let contract =
+            bech32::encode::<Bech32>(bech32::Hrp::parse("osmo").unwrap(), &[7; 32]).unwrap();
+        assert!(validate_address(CosmosChain::Osmosis, &contract).is_ok());
+
+        // 前缀不匹配
+        assert!(validate_address(
+            CosmosChain::Osmosis,
+            "cosmos1fl48vsnmsdzcv85q5d2q4z5ajdha8yu34mf0eh"
+        )
+        .is_err());
+        // 校验和错误
+        assert!(validate_address(
+            CosmosChain::Cosmos,
+            "cosmos1fl48vsnmsdzcv85q5d2q4z5ajdha8yu34mf0ei"
+        )
+        .is_err());
+        assert!(validate_address(CosmosChain::Cosmos, "").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use primitives::Chain;
+
+/// Cosmos SDK 链
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CosmosChain {
+    Cosmos,
+    Osmosis,
+    Injective,
+    Celestia,
+    Sei,
+    Noble,
+}
+
+impl CosmosChain {
+    pub fn all() -> [Self; 6] {
+        [
+            Self::Cosmos,
+            Self::Osmosis,
+            Self::Injective,
+            Self::Celestia,
+            Self::Sei,
+            Self::Noble,
+        ]
+    }
+
+    /// 原生 denom 所属的链，用于确定跨链后 IBC 代币的精度
+    pub fn from_denom(denom: &str) -> Option<Self> {
+        Self::all().into_iter().find(|x| x.denom() == denom)
+    }
+
+    pub fn from_chain(chain: Chain) -> Option<Self> {
+        match chain {
+            Chain::Cosmos => Some(Self::Cosmos),
+            Chain::Osmosis => Some(Self::Osmosis),
+            Chain::Injective => Some(Self::Injective),
+            Chain::Celestia => Some(Self::Celestia),
+            Chain::Sei => Some(Self::Sei),
+            Chain::Noble => Some(Self::Noble),
+            _ => None,
+        }
+    }
+
+    pub fn to_chain(self) -> Chain {
+        match self {
+            Self::Cosmos => Chain::Cosmos,
+            Self::Osmosis => Chain::Osmosis,
+            Self::Injective => Chain::Injective,
+            Self::Celestia => Chain::Celestia,
+            Self::Sei => Chain::Sei,
+            Self::Noble => Chain::Noble,
+        }
+    }
+
+    /// bech32 地址前缀
+    pub fn hrp(&self) -> &'static str {
+        match self {
+            Self::Cosmos => "cosmos",
+            Self::Osmosis => "osmo",
+            Self::Injective => "inj",
+            Self::Celestia => "celestia",
+            Self::Sei => "sei",
+            Self::Noble => "noble",
+        }
+    }
+
+    /// 原生代币 denom，Noble 使用 USDC 支付手续费
+    pub fn denom(&self) -> &'static str {
+        match self {
+            Self::Cosmos => "uatom",
+            Self::Osmosis => "uosmo",
+            Self::Injective => "inj",
+            Self::Celestia => "utia",
+            Self::Sei => "usei",
+            Self::Noble => "uusdc",
+        }
+    }
+
+    pub fn decimals(&self) -> u8 {
+        match self {
+            Self::Injective => 18,
+            _ => 6,
+        }
+    }
+
+    pub fn chain_id(&self) -> &'static str {
+        match self {
+            Self::Cosmos => "cosmoshub-4",
+            Self::Osmosis => "osmosis-1",
+            Self::Injective => "injective-1",
+            Self::Celestia => "celestia",
+            Self::Sei => "pacific-1",
+            Self::Noble => "noble-1",
+        }
+    }
+}
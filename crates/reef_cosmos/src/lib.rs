@@ -0,0 +1,13 @@
+pub mod address;
+pub mod chain;
+pub mod models;
+pub mod provider;
+pub mod rpc;
+
+// IBC 代币 denom 前缀，后接 denom trace 的 hash
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+// Re-export for convenience
+pub use chain::CosmosChain;
+pub use models::*;
+pub use rpc::CosmosClient;
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Coin {
+    pub denom: String,
+    pub amount: String,
+}
+
+/// `/cosmos/bank/v1beta1/balances/{address}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalancesResponse {
+    pub balances: Vec<Coin>,
+}
+
+/// `/ibc/apps/transfer/v1/denom_traces/{hash}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DenomTraceResponse {
+    pub denom_trace: DenomTrace,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenomTrace {
+    /// 转移路径，例如 `transfer/channel-141`
+    pub path: String,
+    pub base_denom: String,
+}
+
+/// `/cosmos/bank/v1beta1/denoms_metadata_by_query_string`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DenomMetadataResponse {
+    pub metadata: DenomMetadata,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenomUnit {
+    pub denom: String,
+    #[serde(default)]
+    pub exponent: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenomMetadata {
+    pub base: String,
+    pub display: String,
+    #[serde(default)]
+    pub symbol: String,
+    pub denom_units: Vec<DenomUnit>,
+}
+
+impl DenomMetadata {
+    /// `display` 单位的指数，未登记该单位时为 None
+    pub fn decimals(&self) -> Option<u8> {
+        let unit = self.denom_units.iter().find(|x| x.denom == self.display)?;
+        u8::try_from(unit.exponent).ok()
+    }
+}
+
+/// 余额及 IBC 代币的来源，`decimals` 为 None 表示精度未知
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CosmosBalance {
+    pub denom: String,
+    pub amount: String,
+    pub trace: Option<DenomTrace>,
+    pub decimals: Option<u8>,
+}
@@ -0,0 +1,7 @@
+pub mod bank;
+pub mod staking;
+pub mod transaction;
+
+pub use bank::*;
+pub use staking::*;
+pub use transaction::*;
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use super::Coin;
+
+/// `/cosmos/staking/v1beta1/delegations/{address}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegationsResponse {
+    pub delegation_responses: Vec<DelegationResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegationResponse {
+    pub delegation: Delegation,
+    pub balance: Coin,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Delegation {
+    pub delegator_address: String,
+    pub validator_address: String,
+    pub shares: String,
+}
+
+/// `/cosmos/distribution/v1beta1/delegators/{address}/rewards`，金额带小数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardsResponse {
+    pub rewards: Vec<ValidatorRewards>,
+    #[serde(default)]
+    pub total: Vec<Coin>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorRewards {
+    pub validator_address: String,
+    #[serde(default)]
+    pub reward: Vec<Coin>,
+}
+
+/// 单个验证人的原生代币质押与未领取奖励
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CosmosDelegation {
+    pub validator_address: String,
+    pub amount: String,
+    pub rewards: String,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+pub const BROADCAST_MODE_SYNC: &str = "BROADCAST_MODE_SYNC";
+
+/// `POST /cosmos/tx/v1beta1/txs`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BroadcastTxRequest {
+    /// base64 编码的已签名 `TxRaw`
+    pub tx_bytes: String,
+    pub mode: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxResponseWrapper {
+    pub tx_response: TxResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxResponse {
+    pub txhash: String,
+    /// 0 表示成功
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub height: String,
+    #[serde(default)]
+    pub raw_log: String,
+}
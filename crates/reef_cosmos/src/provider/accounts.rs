@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use primitives::{
+    Chain, ChainNft, ChainProvider, ChainState, ChainTraits, ChainTransactions, ChainUtxo,
+};
+use reef_client::Client;
+use std::error::Error;
+
+use crate::rpc::client::CosmosClient;
+
+impl<C: Client + Clone> ChainTraits for CosmosClient<C> {}
+
+impl<C: Client + Clone> ChainProvider for CosmosClient<C> {
+    fn get_chain(&self) -> Chain {
+        self.get_chain()
+    }
+
+    fn verify_address(&self, address: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.validate_address(&address)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainState for CosmosClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainTransactions for CosmosClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainNft for CosmosClient<C> {}
+
+// 账户模型，没有 UTXO
+#[async_trait]
+impl<C: Client + Clone> ChainUtxo for CosmosClient<C> {}
@@ -0,0 +1,190 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{AssetBalance, Balance, ChainBalances};
+use reef_client::Client;
+
+use crate::models::CosmosBalance;
+use crate::rpc::client::CosmosClient;
+
+fn parse_balance(amount: &str) -> BigUint {
+    amount.parse().unwrap_or_default()
+}
+
+/// 非原生 denom 作为代币；精度未知时不返回，以免按错误精度显示金额
+fn map_token_balance(chain: primitives::Chain, balance: &CosmosBalance) -> Option<AssetBalance> {
+    Some(AssetBalance::new_token(
+        chain,
+        Some(balance.denom.clone()),
+        parse_balance(&balance.amount),
+        balance.decimals?,
+    ))
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for CosmosClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let balances = self.get_balances(&address).await?;
+        // 未收到过转账的账户查询结果为空
+        let coin = balances.iter().find(|x| x.denom == self.chain.denom());
+        Ok(AssetBalance {
+            chain: self.get_chain(),
+            contract_address: None,
+            balance: Balance::coin_balance(
+                coin.map(|x| parse_balance(&x.amount)).unwrap_or_default(),
+                self.chain.decimals(),
+            ),
+            is_active: Some(coin.is_some()),
+        })
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let balances = self.get_balances_with_traces(&address).await?;
+        Ok(balances
+            .iter()
+            .filter(|x| token_addresses.contains(&x.denom))
+            .filter_map(|x| map_token_balance(self.get_chain(), x))
+            .collect())
+    }
+
+    async fn get_assets_balances(
+        &self,
+        address: String,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        let balances = self.get_balances_with_traces(&address).await?;
+        Ok(balances
+            .iter()
+            .filter(|x| x.denom != self.chain.denom())
+            .filter_map(|x| map_token_balance(self.get_chain(), x))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::CosmosChain;
+    use primitives::Chain;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "cosmos1fl48vsnmsdzcv85q5d2q4z5ajdha8yu34mf0eh";
+    const IBC_OSMO: &str = "ibc/14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC";
+    const IBC_INJ: &str = "ibc/64BA6E31FE887D66C6F8F31C7B1A80C7CA179239677B4088BB55F5EA07DBE273";
+    const IBC_STARS: &str = "ibc/987C17B11ABC2B20019178ACE62929FE9840202CE79498E29FE8E5CB02B7C0A4";
+    const IBC_UNKNOWN: &str =
+        "ibc/0025F8A87464A471E66B234C4F93AEC5B4DA3D42D7986451A059273426290DD5";
+
+    fn trace_path(denom: &str) -> String {
+        format!(
+            "/ibc/apps/transfer/v1/denom_traces/{}",
+            denom.trim_start_matches("ibc/")
+        )
+    }
+
+    fn client() -> CosmosClient<MockClient> {
+        let mock = MockClient::new()
+            .with_get(
+                &format!("/cosmos/bank/v1beta1/balances/{}", ADDRESS),
+                json!({
+                    "balances": [
+                        { "denom": IBC_OSMO, "amount": "2500000" },
+                        { "denom": IBC_INJ, "amount": "1500000000000000000" },
+                        { "denom": IBC_STARS, "amount": "700000" },
+                        { "denom": IBC_UNKNOWN, "amount": "42" },
+                        { "denom": "uatom", "amount": "12345678" }
+                    ],
+                    "pagination": { "next_key": null, "total": "5" }
+                }),
+            )
+            .with_get(
+                &trace_path(IBC_OSMO),
+                json!({
+                    "denom_trace": { "path": "transfer/channel-141", "base_denom": "uosmo" }
+                }),
+            )
+            .with_get(
+                &trace_path(IBC_INJ),
+                json!({
+                    "denom_trace": { "path": "transfer/channel-220", "base_denom": "inj" }
+                }),
+            )
+            .with_get(
+                &trace_path(IBC_STARS),
+                json!({
+                    "denom_trace": { "path": "transfer/channel-730", "base_denom": "ustars" }
+                }),
+            )
+            .with_get(
+                &trace_path(IBC_UNKNOWN),
+                json!({
+                    "denom_trace": { "path": "transfer/channel-9", "base_denom": "umystery" }
+                }),
+            )
+            .with_get(
+                &format!(
+                    "/cosmos/bank/v1beta1/denoms_metadata_by_query_string?denom={}",
+                    IBC_STARS.replace('/', "%2F")
+                ),
+                json!({
+                    "metadata": {
+                        "description": "Stargaze",
+                        "denom_units": [
+                            { "denom": IBC_STARS, "exponent": 0, "aliases": [] },
+                            { "denom": "stars", "exponent": 6, "aliases": [] }
+                        ],
+                        "base": IBC_STARS,
+                        "display": "stars",
+                        "name": "STARS",
+                        "symbol": "STARS"
+                    }
+                }),
+            );
+        CosmosClient::new_with_client(mock, CosmosChain::Cosmos)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let balance = client()
+            .get_balance_coin(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balance.chain, Chain::Cosmos);
+        assert_eq!(balance.balance.amount, "12345678");
+        assert_eq!(balance.balance.decimals, 6);
+        assert_eq!(balance.is_active, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_assets_balances() {
+        let client = client();
+        let balances = client
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .unwrap();
+        // 精度未知的代币不返回
+        assert_eq!(balances.len(), 3);
+        assert_eq!(balances[0].contract_address.as_deref(), Some(IBC_OSMO));
+        assert_eq!(balances[0].balance.amount, "2500000");
+        assert_eq!(balances[0].balance.decimals, 6);
+        // 跨链到 Cosmos Hub 的 INJ 仍为 18 位
+        assert_eq!(balances[1].contract_address.as_deref(), Some(IBC_INJ));
+        assert_eq!(balances[1].balance.decimals, 18);
+        assert_eq!(balances[2].contract_address.as_deref(), Some(IBC_STARS));
+        assert_eq!(balances[2].balance.decimals, 6);
+
+        let balances = client
+            .get_balance_tokens(ADDRESS.to_string(), vec!["ibc/unknown".to_string()])
+            .await
+            .unwrap();
+        assert!(balances.is_empty());
+    }
+}
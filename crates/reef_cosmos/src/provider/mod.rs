@@ -0,0 +1,4 @@
+pub mod accounts;
+pub mod balances;
+pub mod token;
+pub mod token_mapper;
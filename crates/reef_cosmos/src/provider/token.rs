@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+use crate::{provider::token_mapper::map_token_data, rpc::client::CosmosClient, IBC_DENOM_PREFIX};
+use primitives::{Asset, ChainToken};
+use reef_client::Client;
+
+// Token Factory 代币 denom 前缀，例如 `factory/{creator}/{subdenom}`
+const FACTORY_DENOM_PREFIX: &str = "factory/";
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for CosmosClient<C> {
+    /// 只支持 IBC 代币，通过 denom trace 获取原始 denom，精度未知时返回错误
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let trace = self.get_denom_trace(&token_address).await?;
+        let decimals = self
+            .get_denom_decimals(&token_address, Some(&trace))
+            .await?
+            .ok_or_else(|| format!("Unknown decimals for {}", token_address))?;
+        Ok(map_token_data(
+            self.get_chain(),
+            token_address,
+            &trace,
+            decimals,
+        ))
+    }
+
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        token_address.starts_with(IBC_DENOM_PREFIX)
+            || token_address.starts_with(FACTORY_DENOM_PREFIX)
+    }
+
+    async fn get_tokens_data(
+        &self,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<Asset>, Box<dyn Error + Sync + Send>> {
+        let mut assets = Vec::new();
+        for token_address in token_addresses {
+            // 跳过获取失败的代币
+            if let Ok(asset) = self.get_token_data(token_address).await {
+                assets.push(asset);
+            }
+        }
+        Ok(assets)
+    }
+}
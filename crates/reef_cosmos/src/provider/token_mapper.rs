@@ -0,0 +1,55 @@
+use primitives::{Asset, AssetType, Chain};
+
+use crate::models::DenomTrace;
+
+/// 去掉 `u`/`a` 单位前缀后大写，例如 `uosmo` -> `OSMO`，只用于显示
+pub fn denom_symbol(base_denom: &str) -> String {
+    let is_prefixed = base_denom.len() > 1
+        && base_denom.starts_with(['u', 'a'])
+        && base_denom[1..].chars().all(|x| x.is_ascii_lowercase());
+    let symbol = if is_prefixed {
+        &base_denom[1..]
+    } else {
+        base_denom
+    };
+    symbol.to_uppercase()
+}
+
+pub fn map_token_data(chain: Chain, denom: String, trace: &DenomTrace, decimals: u8) -> Asset {
+    let symbol = denom_symbol(&trace.base_denom);
+    Asset::new(
+        symbol.clone(),
+        symbol,
+        decimals as i32,
+        chain,
+        Some(denom),
+        AssetType::IBC,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denom_symbol() {
+        assert_eq!(denom_symbol("uatom"), "ATOM");
+        assert_eq!(denom_symbol("aevmos"), "EVMOS");
+        assert_eq!(denom_symbol("inj"), "INJ");
+        assert_eq!(denom_symbol("u"), "U");
+    }
+
+    #[test]
+    fn test_map_token_data() {
+        let trace = DenomTrace {
+            path: "transfer/channel-0".to_string(),
+            base_denom: "uosmo".to_string(),
+        };
+        let denom = "ibc/14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC";
+        let asset = map_token_data(Chain::Cosmos, denom.to_string(), &trace, 6);
+        assert_eq!(asset.symbol, "OSMO");
+        assert_eq!(asset.decimals, 6);
+        assert_eq!(asset.contract_address.as_deref(), Some(denom));
+        assert_eq!(asset.asset_type, AssetType::IBC);
+    }
+}
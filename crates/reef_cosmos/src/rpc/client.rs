@@ -0,0 +1,421 @@
+use crate::address::validate_address;
+use crate::chain::CosmosChain;
+use crate::models::{
+    BalancesResponse, BroadcastTxRequest, Coin, CosmosBalance, CosmosDelegation,
+    DelegationsResponse, DenomMetadata, DenomMetadataResponse, DenomTrace, DenomTraceResponse,
+    RewardsResponse, TxResponse, TxResponseWrapper, BROADCAST_MODE_SYNC,
+};
+use crate::IBC_DENOM_PREFIX;
+use num_bigint::BigUint;
+use primitives::{Chain, TransactionStatus, TransactionUpdate};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use std::error::Error;
+
+/// 奖励等金额带 18 位小数，只保留整数部分
+pub fn parse_amount(amount: &str) -> BigUint {
+    let integer = amount.split('.').next().unwrap_or_default();
+    integer.parse().unwrap_or_default()
+}
+
+fn sum_denom(coins: &[Coin], denom: &str) -> BigUint {
+    coins
+        .iter()
+        .filter(|x| x.denom == denom)
+        .map(|x| parse_amount(&x.amount))
+        .sum()
+}
+
+/// 交易 hash 与 IBC denom hash 均为 32 字节的十六进制
+fn validate_hash(hash: &str) -> Result<(), String> {
+    if hash.len() != 64 || !hash.chars().all(|x| x.is_ascii_hexdigit()) {
+        return Err(format!("Invalid hash: {}", hash));
+    }
+    Ok(())
+}
+
+/// Cosmos SDK 的 denom 规则：字母开头，3 到 128 位字母数字或 `/:._-`
+fn validate_denom(denom: &str) -> Result<(), String> {
+    let valid = (3..=128).contains(&denom.len())
+        && denom.starts_with(|x: char| x.is_ascii_alphabetic())
+        && denom
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || "/:._-".contains(x));
+    if !valid {
+        return Err(format!("Invalid denom: {}", denom));
+    }
+    Ok(())
+}
+
+/// Cosmos SDK LCD/REST 客户端
+#[derive(Debug, Clone)]
+pub struct CosmosClient<C: Client + Clone = ReqwestClient> {
+    pub chain: CosmosChain,
+    client: C,
+}
+
+impl CosmosClient {
+    pub fn new(url: String, chain: CosmosChain) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url), chain)
+    }
+
+    pub fn new_with_config(
+        url: String,
+        config: &ClientConfig,
+        chain: CosmosChain,
+    ) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(
+            ReqwestClient::new_with_config(url, config)?,
+            chain,
+        ))
+    }
+}
+
+impl<C: Client + Clone> CosmosClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C, chain: CosmosChain) -> Self {
+        Self { chain, client }
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        self.chain.to_chain()
+    }
+
+    pub fn validate_address(&self, address: &str) -> Result<(), String> {
+        validate_address(self.chain, address)
+    }
+
+    pub async fn get_balances(
+        &self,
+        address: &str,
+    ) -> Result<Vec<Coin>, Box<dyn Error + Send + Sync>> {
+        self.validate_address(address)?;
+        let path = format!("/cosmos/bank/v1beta1/balances/{}", address);
+        let response: BalancesResponse = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get balances: {}", e))?;
+        Ok(response.balances)
+    }
+
+    /// `ibc/{hash}` 代币的原始 denom 与转移路径
+    pub async fn get_denom_trace(
+        &self,
+        denom: &str,
+    ) -> Result<DenomTrace, Box<dyn Error + Send + Sync>> {
+        let hash = denom
+            .strip_prefix(IBC_DENOM_PREFIX)
+            .ok_or_else(|| format!("Not an IBC denom: {}", denom))?;
+        validate_hash(hash)?;
+        let path = format!("/ibc/apps/transfer/v1/denom_traces/{}", hash);
+        let response: DenomTraceResponse = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get denom trace: {}", e))?;
+        Ok(response.denom_trace)
+    }
+
+    /// 链上登记的 denom 元数据，未登记时为 None；denom 可能含 `/`，因此用查询参数
+    pub async fn get_denom_metadata(
+        &self,
+        denom: &str,
+    ) -> Result<Option<DenomMetadata>, Box<dyn Error + Send + Sync>> {
+        validate_denom(denom)?;
+        let path = format!(
+            "/cosmos/bank/v1beta1/denoms_metadata_by_query_string?denom={}",
+            denom.replace('/', "%2F")
+        );
+        match self.client.get::<DenomMetadataResponse>(&path).await {
+            Ok(response) => Ok(Some(response.metadata)),
+            Err(ClientError::HttpError { status: 404, .. }) => Ok(None),
+            Err(e) => Err(format!("Failed to get denom metadata: {}", e).into()),
+        }
+    }
+
+    /// 精度依次取自：本链原生代币、链上 denom 元数据、已知链的原生 denom；都没有时为 None
+    pub async fn get_denom_decimals(
+        &self,
+        denom: &str,
+        trace: Option<&DenomTrace>,
+    ) -> Result<Option<u8>, Box<dyn Error + Send + Sync>> {
+        if denom == self.chain.denom() {
+            return Ok(Some(self.chain.decimals()));
+        }
+        if let Some(decimals) = self
+            .get_denom_metadata(denom)
+            .await?
+            .and_then(|x| x.decimals())
+        {
+            return Ok(Some(decimals));
+        }
+        let base_denom = trace.map(|x| x.base_denom.as_str()).unwrap_or(denom);
+        Ok(CosmosChain::from_denom(base_denom).map(|x| x.decimals()))
+    }
+
+    /// 所有余额，IBC 代币附带 denom trace，解析失败时为 None
+    pub async fn get_balances_with_traces(
+        &self,
+        address: &str,
+    ) -> Result<Vec<CosmosBalance>, Box<dyn Error + Send + Sync>> {
+        let mut balances = Vec::new();
+        for coin in self.get_balances(address).await? {
+            let trace = if coin.denom.starts_with(IBC_DENOM_PREFIX) {
+                self.get_denom_trace(&coin.denom).await.ok()
+            } else {
+                None
+            };
+            let decimals = self.get_denom_decimals(&coin.denom, trace.as_ref()).await?;
+            balances.push(CosmosBalance {
+                denom: coin.denom,
+                amount: coin.amount,
+                trace,
+                decimals,
+            });
+        }
+        Ok(balances)
+    }
+
+    /// 原生代币按验证人汇总的质押与未领取奖励
+    pub async fn get_delegations(
+        &self,
+        address: &str,
+    ) -> Result<Vec<CosmosDelegation>, Box<dyn Error + Send + Sync>> {
+        self.validate_address(address)?;
+        let path = format!("/cosmos/staking/v1beta1/delegations/{}", address);
+        let delegations: DelegationsResponse = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get delegations: {}", e))?;
+        let path = format!(
+            "/cosmos/distribution/v1beta1/delegators/{}/rewards",
+            address
+        );
+        let rewards: RewardsResponse = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get rewards: {}", e))?;
+
+        let denom = self.chain.denom();
+        Ok(delegations
+            .delegation_responses
+            .into_iter()
+            .filter(|x| x.balance.denom == denom)
+            .map(|x| {
+                let validator_address = x.delegation.validator_address;
+                let rewards = rewards
+                    .rewards
+                    .iter()
+                    .find(|r| r.validator_address == validator_address)
+                    .map(|r| sum_denom(&r.reward, denom))
+                    .unwrap_or_default();
+                CosmosDelegation {
+                    validator_address,
+                    amount: x.balance.amount,
+                    rewards: rewards.to_string(),
+                }
+            })
+            .collect())
+    }
+
+    /// 广播 base64 编码的已签名交易，返回交易 hash
+    pub async fn broadcast_transaction(
+        &self,
+        tx_bytes: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let request = BroadcastTxRequest {
+            tx_bytes: tx_bytes.to_string(),
+            mode: BROADCAST_MODE_SYNC.to_string(),
+        };
+        let response: TxResponseWrapper = self
+            .client
+            .post("/cosmos/tx/v1beta1/txs", &request, None)
+            .await
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        let response = response.tx_response;
+        if response.code != 0 {
+            return Err(format!(
+                "Broadcast failed with code {}: {}",
+                response.code, response.raw_log
+            )
+            .into());
+        }
+        Ok(response.txhash)
+    }
+
+    pub async fn get_transaction(
+        &self,
+        hash: &str,
+    ) -> Result<Option<TxResponse>, Box<dyn Error + Send + Sync>> {
+        validate_hash(hash)?;
+        let path = format!("/cosmos/tx/v1beta1/txs/{}", hash);
+        match self.client.get::<TxResponseWrapper>(&path).await {
+            Ok(response) => Ok(Some(response.tx_response)),
+            // 尚未打包的交易查询不到
+            Err(ClientError::HttpError { status: 404, .. }) => Ok(None),
+            Err(e) => Err(format!("Failed to get transaction: {}", e).into()),
+        }
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        hash: &str,
+    ) -> Result<TransactionUpdate, Box<dyn Error + Send + Sync>> {
+        let Some(response) = self.get_transaction(hash).await? else {
+            return Ok(TransactionUpdate {
+                hash: hash.to_string(),
+                status: TransactionStatus::Pending,
+                block_number: None,
+                confirmations: 0,
+            });
+        };
+        Ok(TransactionUpdate {
+            hash: response.txhash,
+            status: if response.code == 0 {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Failed
+            },
+            block_number: response.height.parse().ok(),
+            confirmations: 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "cosmos1fl48vsnmsdzcv85q5d2q4z5ajdha8yu34mf0eh";
+    const HASH: &str = "14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC";
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(
+            parse_amount("1234.567800000000000000"),
+            BigUint::from(1234u32)
+        );
+        assert_eq!(parse_amount("42"), BigUint::from(42u32));
+        assert_eq!(parse_amount(""), BigUint::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_get_delegations() {
+        let mock = MockClient::new()
+            .with_get(
+                &format!("/cosmos/staking/v1beta1/delegations/{}", ADDRESS),
+                json!({
+                    "delegation_responses": [
+                        {
+                            "delegation": {
+                                "delegator_address": ADDRESS,
+                                "validator_address": "cosmosvaloper1a",
+                                "shares": "1000000.000000000000000000"
+                            },
+                            "balance": { "denom": "uatom", "amount": "1000000" }
+                        },
+                        {
+                            "delegation": {
+                                "delegator_address": ADDRESS,
+                                "validator_address": "cosmosvaloper1b",
+                                "shares": "250000.000000000000000000"
+                            },
+                            "balance": { "denom": "uatom", "amount": "250000" }
+                        }
+                    ]
+                }),
+            )
+            .with_get(
+                &format!("/cosmos/distribution/v1beta1/delegators/{}/rewards", ADDRESS),
+                json!({
+                    "rewards": [
+                        {
+                            "validator_address": "cosmosvaloper1a",
+                            "reward": [
+                                { "denom": "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2", "amount": "3.5" },
+                                { "denom": "uatom", "amount": "1520.981000000000000000" }
+                            ]
+                        }
+                    ],
+                    "total": [{ "denom": "uatom", "amount": "1520.981000000000000000" }]
+                }),
+            );
+        let client = CosmosClient::new_with_client(mock, CosmosChain::Cosmos);
+
+        let delegations = client.get_delegations(ADDRESS).await.unwrap();
+        assert_eq!(
+            delegations,
+            vec![
+                CosmosDelegation {
+                    validator_address: "cosmosvaloper1a".to_string(),
+                    amount: "1000000".to_string(),
+                    rewards: "1520".to_string(),
+                },
+                CosmosDelegation {
+                    validator_address: "cosmosvaloper1b".to_string(),
+                    amount: "250000".to_string(),
+                    rewards: "0".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_and_status() {
+        let mock = MockClient::new()
+            .with_post(
+                "/cosmos/tx/v1beta1/txs",
+                json!({ "tx_bytes": "CpIBCo8B", "mode": BROADCAST_MODE_SYNC }),
+                json!({ "tx_response": { "txhash": HASH, "code": 0, "height": "0", "raw_log": "" } }),
+            )
+            .with_post(
+                "/cosmos/tx/v1beta1/txs",
+                serde_json::Value::Null,
+                json!({ "tx_response": { "txhash": "", "code": 5, "raw_log": "insufficient funds" } }),
+            )
+            .with_get(
+                &format!("/cosmos/tx/v1beta1/txs/{}", HASH),
+                json!({ "tx_response": { "txhash": HASH, "code": 0, "height": "21000000" } }),
+            );
+        let client = CosmosClient::new_with_client(mock, CosmosChain::Cosmos);
+
+        assert_eq!(
+            client.broadcast_transaction("CpIBCo8B").await.unwrap(),
+            HASH
+        );
+        let error = client.broadcast_transaction("AA==").await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Broadcast failed with code 5: insufficient funds"
+        );
+
+        let update = client.get_transaction_status(HASH).await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Confirmed));
+        assert_eq!(update.block_number, Some(21000000));
+
+        let update = client
+            .get_transaction_status(&HASH.replace('F', "E"))
+            .await
+            .unwrap();
+        assert!(matches!(update.status, TransactionStatus::Pending));
+    }
+
+    #[tokio::test]
+    async fn test_validate_path_params() {
+        let client = CosmosClient::new_with_client(MockClient::new(), CosmosChain::Cosmos);
+        assert!(client.get_transaction("00").await.is_err());
+        assert!(client
+            .get_transaction(&format!("{}/../x", &HASH[..58]))
+            .await
+            .is_err());
+        assert!(client.get_balances("../../x").await.is_err());
+        assert!(client.get_delegations("osmo1x?y=1").await.is_err());
+        assert!(client.get_denom_trace("ibc/../../x").await.is_err());
+        assert!(client.get_denom_metadata("uatom&x=1").await.is_err());
+        assert_eq!(client.get_denom_metadata("uatom").await.unwrap(), None);
+    }
+}
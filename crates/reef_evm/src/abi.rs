@@ -0,0 +1,301 @@
+use num_bigint::BigUint;
+use sha3::{Digest, Keccak256};
+
+/// Size of an ABI word in hex characters (32 bytes)
+pub const WORD_HEX_LEN: usize = 64;
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// 4-byte function selector for a canonical signature, e.g. `ownerOf(uint256)`
+pub fn function_selector(signature: &str) -> String {
+    format!("0x{}", hex::encode(&keccak256(signature.as_bytes())[..4]))
+}
+
+/// Event topic0 for a canonical event signature
+pub fn event_topic(signature: &str) -> String {
+    format!("0x{}", hex::encode(keccak256(signature.as_bytes())))
+}
+
+pub fn encode_address(address: &str) -> String {
+    let address = address.strip_prefix("0x").unwrap_or(address);
+    format!("{:0>64}", address.to_lowercase())
+}
+
+pub fn encode_uint256(value: &BigUint) -> String {
+    format!("{:0>64}", value.to_str_radix(16))
+}
+
+pub fn encode_bool(value: bool) -> String {
+    encode_uint256(&BigUint::from(value as u8))
+}
+
+/// Left-aligned fixed bytes (bytes4 .. bytes32)
+pub fn encode_fixed_bytes(hex_value: &str) -> String {
+    let hex_value = hex_value.strip_prefix("0x").unwrap_or(hex_value);
+    format!("{:0<64}", hex_value.to_lowercase())
+}
+
+/// Dynamic `bytes` tail: length word followed by right-padded data
+pub fn encode_bytes(data: &[u8]) -> String {
+    let length = encode_uint256(&BigUint::from(data.len()));
+    let padded_len = data.len().div_ceil(32) * 64;
+    format!(
+        "{}{:0<width$}",
+        length,
+        hex::encode(data),
+        width = padded_len
+    )
+}
+
+/// Concatenate a selector with already encoded head words
+pub fn encode_call(selector: &str, words: &[String]) -> String {
+    let mut data = selector.to_string();
+    for word in words {
+        data.push_str(word);
+    }
+    data
+}
+
+/// Call whose arguments are all dynamic (`bytes` / `string`), e.g. `resolve(bytes,bytes)`
+pub fn encode_dynamic_call(selector: &str, args: &[&[u8]]) -> String {
+    let mut head = Vec::with_capacity(args.len());
+    let mut tail = String::new();
+    for arg in args {
+        let offset = args.len() * 32 + tail.len() / 2;
+        head.push(encode_uint256(&BigUint::from(offset)));
+        tail.push_str(&encode_bytes(arg));
+    }
+    head.push(tail);
+    encode_call(selector, &head)
+}
+
+/// Address used as an indexed topic
+pub fn address_topic(address: &str) -> String {
+    format!("0x{}", encode_address(address))
+}
+
+fn words(hex_result: &str) -> &str {
+    hex_result.strip_prefix("0x").unwrap_or(hex_result)
+}
+
+fn word_at(hex_result: &str, index: usize) -> Option<&str> {
+    let data = words(hex_result);
+    let start = index.checked_mul(WORD_HEX_LEN)?;
+    data.get(start..start.checked_add(WORD_HEX_LEN)?)
+}
+
+fn word_to_usize(word: &str) -> Option<usize> {
+    let value = BigUint::parse_bytes(word.as_bytes(), 16)?;
+    usize::try_from(value).ok()
+}
+
+pub fn decode_uint256(hex_result: &str) -> Option<BigUint> {
+    decode_uint256_at(hex_result, 0)
+}
+
+pub fn decode_uint256_at(hex_result: &str, index: usize) -> Option<BigUint> {
+    BigUint::parse_bytes(word_at(hex_result, index)?.as_bytes(), 16)
+}
+
+pub fn decode_address(hex_result: &str) -> Option<String> {
+    decode_address_at(hex_result, 0)
+}
+
+pub fn decode_address_at(hex_result: &str, index: usize) -> Option<String> {
+    let word = word_at(hex_result, index)?;
+    Some(format!("0x{}", &word[24..]))
+}
+
+pub fn decode_bool(hex_result: &str) -> Option<bool> {
+    decode_uint256(hex_result).map(|x| x != BigUint::from(0u32))
+}
+
+/// Hex position of the tail referenced by the head word at `index` and the length word
+/// found there. Offsets come from untrusted return data, overflow decodes as `None`
+fn dynamic_tail(hex_result: &str, index: usize) -> Option<(usize, usize)> {
+    let data = words(hex_result);
+    let offset = word_to_usize(word_at(hex_result, index)?)?.checked_mul(2)?;
+    let start = offset.checked_add(WORD_HEX_LEN)?;
+    let length = word_to_usize(data.get(offset..start)?)?;
+    Some((start, length))
+}
+
+/// Dynamic `bytes` referenced by the head word at `index`
+pub fn decode_bytes_at(hex_result: &str, index: usize) -> Option<Vec<u8>> {
+    let data = words(hex_result);
+    let (start, length) = dynamic_tail(hex_result, index)?;
+    let end = start.checked_add(length.checked_mul(2)?)?;
+    hex::decode(data.get(start..end)?).ok()
+}
+
+/// Dynamic `string` referenced by the head word at `index`
+pub fn decode_string_at(hex_result: &str, index: usize) -> Option<String> {
+    String::from_utf8(decode_bytes_at(hex_result, index)?).ok()
+}
+
+pub fn decode_string(hex_result: &str) -> Option<String> {
+    decode_string_at(hex_result, 0)
+}
+
+/// Dynamic `string[]` referenced by the head word at `index`
+pub fn decode_string_array_at(hex_result: &str, index: usize) -> Option<Vec<String>> {
+    let data = words(hex_result);
+    let (start, length) = dynamic_tail(hex_result, index)?;
+    // element offsets are relative to the first word after the length
+    let items = data.get(start..)?;
+
+    (0..length).map(|i| decode_string_at(items, i)).collect()
+}
+
+/// Dynamic `uint256[]` referenced by the head word at `index`
+pub fn decode_uint256_array_at(hex_result: &str, index: usize) -> Option<Vec<BigUint>> {
+    let data = words(hex_result);
+    let (start, length) = dynamic_tail(hex_result, index)?;
+    let items = data.get(start..)?;
+
+    (0..length).map(|i| decode_uint256_at(items, i)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_selector() {
+        assert_eq!(function_selector("balanceOf(address)"), "0x70a08231");
+        assert_eq!(function_selector("ownerOf(uint256)"), "0x6352211e");
+        assert_eq!(function_selector("supportsInterface(bytes4)"), "0x01ffc9a7");
+    }
+
+    #[test]
+    fn test_event_topic() {
+        assert_eq!(
+            event_topic("Transfer(address,address,uint256)"),
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+    }
+
+    #[test]
+    fn test_encode_address() {
+        assert_eq!(
+            encode_address("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+            "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+        );
+    }
+
+    #[test]
+    fn test_encode_uint256_and_fixed_bytes() {
+        assert_eq!(
+            encode_uint256(&BigUint::from(255u32)),
+            "00000000000000000000000000000000000000000000000000000000000000ff"
+        );
+        assert_eq!(
+            encode_fixed_bytes("0x80ac58cd"),
+            "80ac58cd00000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_encode_bytes() {
+        assert_eq!(encode_bytes(&[]), encode_uint256(&BigUint::from(0u32)));
+        let encoded = encode_bytes(&[0xab, 0xcd]);
+        assert_eq!(encoded.len(), 128);
+        assert!(encoded[64..].starts_with("abcd0000"));
+    }
+
+    #[test]
+    fn test_decode_string() {
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000855534420436f696e000000000000000000000000000000000000000000000000";
+        assert_eq!(decode_string(hex), Some("USD Coin".to_string()));
+        assert_eq!(decode_string("0x"), None);
+    }
+
+    #[test]
+    fn test_decode_address_and_bool() {
+        let hex = "0x000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        assert_eq!(
+            decode_address(hex),
+            Some("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string())
+        );
+        assert_eq!(decode_bool(&format!("0x{}", encode_bool(true))), Some(true));
+        assert_eq!(
+            decode_bool(&format!("0x{}", encode_bool(false))),
+            Some(false)
+        );
+        assert_eq!(decode_bool("0x"), None);
+    }
+
+    #[test]
+    fn test_encode_dynamic_call() {
+        let data = encode_dynamic_call("0x9061b923", &[b"abc", &[0xde, 0xad]]);
+        let args = &data[10..];
+
+        assert!(data.starts_with("0x9061b923"));
+        assert_eq!(decode_bytes_at(args, 0), Some(b"abc".to_vec()));
+        assert_eq!(decode_bytes_at(args, 1), Some(vec![0xde, 0xad]));
+    }
+
+    #[test]
+    fn test_decode_string_array() {
+        // (string[]) = (["a", "bc"])
+        let data = [
+            encode_uint256(&BigUint::from(0x20u32)),
+            encode_uint256(&BigUint::from(2u32)),
+            encode_uint256(&BigUint::from(0x40u32)),
+            encode_uint256(&BigUint::from(0x80u32)),
+            encode_bytes(b"a"),
+            encode_bytes(b"bc"),
+        ]
+        .concat();
+
+        assert_eq!(
+            decode_string_array_at(&data, 0),
+            Some(vec!["a".to_string(), "bc".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_decode_uint256_array() {
+        // (uint256[] ids, uint256[] values) = ([1, 2], [10, 20])
+        let data = [
+            encode_uint256(&BigUint::from(0x40u32)),
+            encode_uint256(&BigUint::from(0xa0u32)),
+            encode_uint256(&BigUint::from(2u32)),
+            encode_uint256(&BigUint::from(1u32)),
+            encode_uint256(&BigUint::from(2u32)),
+            encode_uint256(&BigUint::from(2u32)),
+            encode_uint256(&BigUint::from(10u32)),
+            encode_uint256(&BigUint::from(20u32)),
+        ]
+        .concat();
+
+        let ids = decode_uint256_array_at(&data, 0).unwrap();
+        let values = decode_uint256_array_at(&data, 1).unwrap();
+        assert_eq!(ids, vec![BigUint::from(1u32), BigUint::from(2u32)]);
+        assert_eq!(values, vec![BigUint::from(10u32), BigUint::from(20u32)]);
+    }
+
+    #[test]
+    fn test_decode_overflowing_offsets() {
+        let huge = format!("8{:0>63}", "");
+        let usize_top = format!("{:0>64}", format!("8{:0>15}", ""));
+        let max = format!("{:0>64}", "f".repeat(16));
+
+        for offset in [&huge, &usize_top, &max] {
+            let data = format!("0x{}{}", offset, encode_uint256(&BigUint::from(1u32)));
+            assert_eq!(decode_bytes_at(&data, 0), None);
+            assert_eq!(decode_string_array_at(&data, 0), None);
+            assert_eq!(decode_uint256_array_at(&data, 0), None);
+        }
+
+        // valid offset, length word near usize::MAX
+        for length in [&usize_top, &max] {
+            let data = [encode_uint256(&BigUint::from(0x20u32)), length.clone()].concat();
+            assert_eq!(decode_bytes_at(&data, 0), None);
+            assert_eq!(decode_string_array_at(&data, 0), None);
+            assert_eq!(decode_uint256_array_at(&data, 0), None);
+        }
+    }
+}
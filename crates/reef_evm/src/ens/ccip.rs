@@ -0,0 +1,242 @@
+use std::error::Error;
+
+use num_bigint::BigUint;
+use reef_client::UntrustedClient;
+use reef_jsonrpc::JsonRpcError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::abi;
+use crate::ens::OFFCHAIN_LOOKUP_SELECTOR;
+
+/// EIP-3668 recommends limiting the number of chained lookups
+pub const MAX_CCIP_REDIRECTS: usize = 4;
+/// Gateways tried per lookup, the list comes from the name owner's contract
+pub const MAX_CCIP_GATEWAYS: usize = 4;
+
+/// Decoded `OffchainLookup` revert
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffchainLookup {
+    pub sender: String,
+    pub urls: Vec<String>,
+    pub call_data: Vec<u8>,
+    pub callback_function: String,
+    pub extra_data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatewayRequest {
+    Get(String),
+    Post(String, GatewayRequestBody),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GatewayRequestBody {
+    pub data: String,
+    pub sender: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GatewayResponse {
+    data: String,
+}
+
+impl OffchainLookup {
+    pub fn decode(revert_data: &str) -> Option<Self> {
+        let data = revert_data.strip_prefix("0x").unwrap_or(revert_data);
+        let selector = OFFCHAIN_LOOKUP_SELECTOR.trim_start_matches("0x");
+        let args = data.strip_prefix(selector)?;
+
+        let callback = args.get(3 * abi::WORD_HEX_LEN..3 * abi::WORD_HEX_LEN + 8)?;
+        Some(Self {
+            sender: abi::decode_address_at(args, 0)?,
+            urls: abi::decode_string_array_at(args, 1)?,
+            call_data: abi::decode_bytes_at(args, 2)?,
+            callback_function: format!("0x{}", callback),
+            extra_data: abi::decode_bytes_at(args, 4)?,
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        let word = |value: usize| abi::encode_uint256(&BigUint::from(value));
+
+        // string[]: length, element offsets, then the elements
+        let mut urls = vec![word(self.urls.len())];
+        let mut elements = String::new();
+        for url in &self.urls {
+            urls.push(word(self.urls.len() * 32 + elements.len() / 2));
+            elements.push_str(&abi::encode_bytes(url.as_bytes()));
+        }
+        urls.push(elements);
+        let urls = urls.concat();
+        let call_data = abi::encode_bytes(&self.call_data);
+
+        let urls_offset = 5 * 32;
+        let call_data_offset = urls_offset + urls.len() / 2;
+        let extra_data_offset = call_data_offset + call_data.len() / 2;
+        abi::encode_call(
+            OFFCHAIN_LOOKUP_SELECTOR,
+            &[
+                abi::encode_address(&self.sender),
+                word(urls_offset),
+                word(call_data_offset),
+                abi::encode_fixed_bytes(&self.callback_function),
+                word(extra_data_offset),
+                urls,
+                call_data,
+                abi::encode_bytes(&self.extra_data),
+            ],
+        )
+    }
+
+    /// Gateway URL templates use `{sender}` and `{data}`, templates without `{data}` are POSTed
+    pub fn gateway_request(&self, url: &str) -> GatewayRequest {
+        let sender = self.sender.to_lowercase();
+        let data = format!("0x{}", hex::encode(&self.call_data));
+        if url.contains("{data}") {
+            GatewayRequest::Get(url.replace("{sender}", &sender).replace("{data}", &data))
+        } else {
+            GatewayRequest::Post(
+                url.replace("{sender}", &sender),
+                GatewayRequestBody { data, sender },
+            )
+        }
+    }
+
+    /// `callback(bytes response, bytes extraData)` call sent back to the sender
+    pub fn callback_data(&self, response: &[u8]) -> String {
+        abi::encode_dynamic_call(&self.callback_function, &[response, &self.extra_data])
+    }
+}
+
+/// Revert data of a failed `eth_call`, nodes return it either directly or nested in an object
+pub fn revert_data(error: &JsonRpcError) -> Option<&str> {
+    match error.data.as_ref()? {
+        Value::String(data) => Some(data),
+        Value::Object(object) => object.get("data")?.as_str(),
+        _ => None,
+    }
+}
+
+/// Query the first `MAX_CCIP_GATEWAYS` gateways in order until one answers. Gateway urls
+/// are attacker controlled, `UntrustedClient` only reaches public https hosts
+pub async fn fetch_gateway(
+    lookup: &OffchainLookup,
+    client: &UntrustedClient,
+) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+    let mut last_error: Box<dyn Error + Send + Sync> = "OffchainLookup has no gateway urls".into();
+
+    for url in lookup.urls.iter().take(MAX_CCIP_GATEWAYS) {
+        let result = match lookup.gateway_request(url) {
+            GatewayRequest::Get(url) => client.get::<GatewayResponse>(&url).await,
+            GatewayRequest::Post(url, body) => client.post::<_, GatewayResponse>(&url, &body).await,
+        };
+
+        match result {
+            Ok(response) => {
+                let data = response.data.trim_start_matches("0x");
+                return Ok(hex::decode(data)?);
+            }
+            Err(e) => last_error = format!("CCIP gateway {} failed: {}", url, e).into(),
+        }
+    }
+
+    Err(last_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_decode_offchain_lookup() {
+        let revert = OffchainLookup {
+            sender: "0xC1735677a60884ABbCF72295E88d47764BeDa282".to_string(),
+            urls: vec!["https://gateway.example.com/{sender}/{data}.json".to_string()],
+            call_data: vec![0x12, 0x34],
+            callback_function: "0xb4a85801".to_string(),
+            extra_data: b"extra".to_vec(),
+        }
+        .encode();
+
+        let lookup = OffchainLookup::decode(&revert).unwrap();
+        assert_eq!(lookup.sender, "0xc1735677a60884abbcf72295e88d47764beda282");
+        assert_eq!(
+            lookup.urls,
+            vec!["https://gateway.example.com/{sender}/{data}.json".to_string()]
+        );
+        assert_eq!(lookup.call_data, vec![0x12, 0x34]);
+        assert_eq!(lookup.callback_function, "0xb4a85801");
+        assert_eq!(lookup.extra_data, b"extra".to_vec());
+
+        assert_eq!(OffchainLookup::decode("0x08c379a0"), None);
+    }
+
+    #[test]
+    fn test_gateway_request() {
+        let lookup = OffchainLookup {
+            sender: "0xC1735677a60884ABbCF72295E88d47764BeDa282".to_string(),
+            urls: vec![],
+            call_data: vec![0xab],
+            callback_function: "0xb4a85801".to_string(),
+            extra_data: vec![],
+        };
+
+        assert_eq!(
+            lookup.gateway_request("https://gw.example.com/{sender}/{data}.json"),
+            GatewayRequest::Get(
+                "https://gw.example.com/0xc1735677a60884abbcf72295e88d47764beda282/0xab.json"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            lookup.gateway_request("https://gw.example.com/lookup"),
+            GatewayRequest::Post(
+                "https://gw.example.com/lookup".to_string(),
+                GatewayRequestBody {
+                    data: "0xab".to_string(),
+                    sender: "0xc1735677a60884abbcf72295e88d47764beda282".to_string(),
+                }
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_gateway_rejects_internal_urls() {
+        let lookup = OffchainLookup {
+            sender: "0xC1735677a60884ABbCF72295E88d47764BeDa282".to_string(),
+            urls: vec![
+                "http://gateway.example.com/{sender}/{data}.json".to_string(),
+                "https://169.254.169.254/latest/meta-data/{data}".to_string(),
+                "https://127.0.0.1/lookup".to_string(),
+            ],
+            call_data: vec![0xab],
+            callback_function: "0xb4a85801".to_string(),
+            extra_data: vec![],
+        };
+
+        let error = fetch_gateway(&lookup, &UntrustedClient::new())
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("127.0.0.1"), "{}", error);
+        assert!(error.contains("non-public address"), "{}", error);
+    }
+
+    #[test]
+    fn test_revert_data() {
+        let error = |data| JsonRpcError {
+            code: 3,
+            message: "execution reverted".to_string(),
+            data: Some(data),
+        };
+
+        assert_eq!(revert_data(&error(json!("0x556f1830"))), Some("0x556f1830"));
+        assert_eq!(
+            revert_data(&error(json!({ "data": "0x556f1830" }))),
+            Some("0x556f1830")
+        );
+        assert_eq!(revert_data(&error(json!(1))), None);
+    }
+}
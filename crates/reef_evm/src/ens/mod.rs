@@ -0,0 +1,49 @@
+pub mod ccip;
+pub mod namehash;
+pub mod resolver;
+
+pub use resolver::EnsResolver;
+
+/// ENS registry, same address on mainnet and testnets
+pub const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+/// resolver(bytes32)
+pub const ENS_RESOLVER_SELECTOR: &str = "0x0178b8bf";
+/// addr(bytes32)
+pub const ENS_ADDR_SELECTOR: &str = "0x3b3b57de";
+/// name(bytes32)
+pub const ENS_NAME_SELECTOR: &str = "0x691f3431";
+/// text(bytes32,string)
+pub const ENS_TEXT_SELECTOR: &str = "0x59d1d43c";
+/// resolve(bytes,bytes), also the ENSIP-10 `IExtendedResolver` interface id
+pub const ENS_RESOLVE_SELECTOR: &str = "0x9061b923";
+/// OffchainLookup(address,string[],bytes,bytes4,bytes) revert of EIP-3668
+pub const OFFCHAIN_LOOKUP_SELECTOR: &str = "0x556f1830";
+
+/// Top level domains handled by the ENS resolver
+pub const ENS_TLDS: &[&str] = &["eth"];
+
+pub const ENS_TEXT_AVATAR: &str = "avatar";
+pub const ENS_TEXT_URL: &str = "url";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::function_selector;
+
+    #[test]
+    fn test_ens_selectors() {
+        assert_eq!(function_selector("resolver(bytes32)"), ENS_RESOLVER_SELECTOR);
+        assert_eq!(function_selector("addr(bytes32)"), ENS_ADDR_SELECTOR);
+        assert_eq!(function_selector("name(bytes32)"), ENS_NAME_SELECTOR);
+        assert_eq!(
+            function_selector("text(bytes32,string)"),
+            ENS_TEXT_SELECTOR
+        );
+        assert_eq!(function_selector("resolve(bytes,bytes)"), ENS_RESOLVE_SELECTOR);
+        assert_eq!(
+            function_selector("OffchainLookup(address,string[],bytes,bytes4,bytes)"),
+            OFFCHAIN_LOOKUP_SELECTOR
+        );
+    }
+}
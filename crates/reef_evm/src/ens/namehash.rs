@@ -0,0 +1,116 @@
+use std::error::Error;
+
+use crate::abi::keccak256;
+
+/// ENSIP-15 label extensions such as `xn--` are not valid names
+fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .bytes()
+            .all(|x| x.is_ascii_lowercase() || x.is_ascii_digit() || x == b'-')
+        && label.get(2..4) != Some("--")
+}
+
+/// ASCII subset of ENSIP-15, where normalisation is just lowercasing. Names with any
+/// other character are rejected rather than hashed to a node wallets may not agree on
+pub fn normalize(name: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let name = name.trim().trim_end_matches('.').to_ascii_lowercase();
+    if name.is_empty() || !name.split('.').all(is_valid_label) {
+        return Err(format!("Invalid or unsupported ENS name: {}", name).into());
+    }
+    Ok(name)
+}
+
+pub fn labelhash(label: &str) -> [u8; 32] {
+    keccak256(label.as_bytes())
+}
+
+/// EIP-137 namehash
+pub fn namehash(name: &str) -> [u8; 32] {
+    let mut node = [0u8; 32];
+    if name.is_empty() {
+        return node;
+    }
+    for label in name.rsplit('.') {
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(&node);
+        data[32..].copy_from_slice(&labelhash(label));
+        node = keccak256(&data);
+    }
+    node
+}
+
+/// Namehash as an ABI `bytes32` word
+pub fn namehash_word(name: &str) -> String {
+    hex::encode(namehash(name))
+}
+
+/// DNS wire format used by ENSIP-10 `resolve(bytes,bytes)`
+pub fn dns_encode(name: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+    let mut encoded = Vec::with_capacity(name.len() + 2);
+    for label in name.split('.') {
+        let length = u8::try_from(label.len()).map_err(|_| format!("Label too long: {}", label))?;
+        encoded.push(length);
+        encoded.extend_from_slice(label.as_bytes());
+    }
+    encoded.push(0);
+    Ok(encoded)
+}
+
+/// `<address>.addr.reverse` node name for reverse resolution
+pub fn reverse_name(address: &str) -> String {
+    let address = address.strip_prefix("0x").unwrap_or(address);
+    format!("{}.addr.reverse", address.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namehash() {
+        assert_eq!(namehash_word(""), "0".repeat(64));
+        assert_eq!(
+            namehash_word("eth"),
+            "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+        );
+        assert_eq!(
+            namehash_word("foo.eth"),
+            "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(" Vitalik.ETH ").unwrap(), "vitalik.eth");
+        assert_eq!(normalize("vitalik.eth.").unwrap(), "vitalik.eth");
+        assert!(normalize("").is_err());
+        assert!(normalize("vitalik..eth").is_err());
+        assert_eq!(normalize("my-wallet-01.eth").unwrap(), "my-wallet-01.eth");
+
+        // unicode and confusables are not normalised, so they are refused
+        assert!(normalize("vitаlik.eth").is_err());
+        assert!(normalize("VİTALIK.eth").is_err());
+        assert!(normalize("caf\u{e9}.eth").is_err());
+        assert!(normalize("💩.eth").is_err());
+        assert!(normalize("xn--ls8h.eth").is_err());
+        assert!(normalize("under_score.eth").is_err());
+    }
+
+    #[test]
+    fn test_dns_encode() {
+        assert_eq!(
+            dns_encode("foo.eth").unwrap(),
+            b"\x03foo\x03eth\x00".to_vec()
+        );
+        assert!(dns_encode(&"a".repeat(256)).is_err());
+    }
+
+    #[test]
+    fn test_reverse_name() {
+        assert_eq!(
+            reverse_name("0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045.addr.reverse"
+        );
+    }
+}
@@ -0,0 +1,473 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{Chain, EVMChain, NameProvider, NameRecord, NameResolver};
+use reef_client::UntrustedClient;
+use serde_json::json;
+
+use crate::abi;
+use crate::ens::ccip::{fetch_gateway, revert_data, OffchainLookup, MAX_CCIP_REDIRECTS};
+use crate::ens::namehash::{dns_encode, namehash_word, normalize, reverse_name};
+use crate::ens::{
+    ENS_ADDR_SELECTOR, ENS_NAME_SELECTOR, ENS_REGISTRY, ENS_RESOLVER_SELECTOR,
+    ENS_RESOLVE_SELECTOR, ENS_TEXT_AVATAR, ENS_TEXT_SELECTOR, ENS_TEXT_URL, ENS_TLDS,
+};
+use crate::jsonrpc::encode_supports_interface_call;
+use crate::models::TransactionObject;
+use crate::rpc::client::EthereumClient;
+
+/// Resolver contract found for a name
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ResolverContract {
+    address: String,
+    /// Implements ENSIP-10 `resolve(bytes,bytes)`
+    extended: bool,
+}
+
+fn is_zero_address(address: &str) -> bool {
+    address.trim_start_matches("0x").chars().all(|c| c == '0')
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+pub struct EnsResolver {
+    client: EthereumClient,
+    registry: String,
+    gateway_client: UntrustedClient,
+}
+
+impl EnsResolver {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            client: EthereumClient::new(rpc_url, EVMChain::Ethereum),
+            registry: ENS_REGISTRY.to_string(),
+            gateway_client: UntrustedClient::new(),
+        }
+    }
+
+    /// Client for CCIP-read gateways, see `UntrustedClient` for the defaults
+    pub fn with_gateway_client(mut self, gateway_client: UntrustedClient) -> Self {
+        self.gateway_client = gateway_client;
+        self
+    }
+
+    /// `eth_call` that follows EIP-3668 `OffchainLookup` reverts through the CCIP-read gateways
+    pub async fn call_ccip(
+        &self,
+        to: &str,
+        data: String,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let mut data = data;
+        for _ in 0..=MAX_CCIP_REDIRECTS {
+            let params = json!([TransactionObject::new_call(to, &data), "latest"]);
+            let error = match self.client.client.call::<String>("eth_call", params).await {
+                Ok(result) => return Ok(result),
+                Err(error) => error,
+            };
+
+            let lookup = revert_data(&error)
+                .and_then(OffchainLookup::decode)
+                .ok_or_else(|| format!("Failed to call contract: {}", error))?;
+            if !lookup.sender.eq_ignore_ascii_case(to) {
+                return Err("OffchainLookup sender does not match the called contract".into());
+            }
+            let response = fetch_gateway(&lookup, &self.gateway_client).await?;
+            data = lookup.callback_data(&response);
+        }
+        Err("Too many CCIP-read redirects".into())
+    }
+
+    /// ENSIP-10: use the resolver of the closest ancestor that has one
+    async fn find_resolver(
+        &self,
+        name: &str,
+    ) -> Result<ResolverContract, Box<dyn Error + Send + Sync>> {
+        let labels: Vec<&str> = name.split('.').collect();
+        let calls = (0..labels.len())
+            .map(|i| {
+                let node = namehash_word(&labels[i..].join("."));
+                (
+                    self.registry.clone(),
+                    abi::encode_call(ENS_RESOLVER_SELECTOR, &[node]),
+                )
+            })
+            .collect();
+        let results = self.client.batch_contract_calls(calls).await?;
+
+        let (index, address) = results
+            .iter()
+            .enumerate()
+            .find_map(|(i, result)| {
+                let address = abi::decode_address(result.as_ref().ok()?)?;
+                (!is_zero_address(&address)).then_some((i, address))
+            })
+            .ok_or_else(|| format!("No resolver found for {}", name))?;
+
+        let extended = self
+            .client
+            .call_contract(
+                &address,
+                &encode_supports_interface_call(ENS_RESOLVE_SELECTOR),
+            )
+            .await
+            .ok()
+            .and_then(|x| abi::decode_bool(&x))
+            .unwrap_or(false);
+        if index > 0 && !extended {
+            return Err(format!("No resolver found for {}", name).into());
+        }
+
+        Ok(ResolverContract { address, extended })
+    }
+
+    /// Call a resolver record function, wrapped in `resolve(bytes,bytes)` for extended resolvers
+    async fn resolver_call(
+        &self,
+        name: &str,
+        resolver: &ResolverContract,
+        data: String,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        if !resolver.extended {
+            return self.call_ccip(&resolver.address, data).await;
+        }
+
+        let call_data = hex::decode(data.trim_start_matches("0x"))?;
+        let wrapped =
+            abi::encode_dynamic_call(ENS_RESOLVE_SELECTOR, &[&dns_encode(name)?, &call_data]);
+        let result = self.call_ccip(&resolver.address, wrapped).await?;
+        let inner = abi::decode_bytes_at(&result, 0).ok_or("Invalid resolve() response")?;
+        Ok(format!("0x{}", hex::encode(inner)))
+    }
+
+    async fn get_text(&self, name: &str, resolver: &ResolverContract, key: &str) -> Option<String> {
+        let data = abi::encode_call(
+            ENS_TEXT_SELECTOR,
+            &[
+                namehash_word(name),
+                abi::encode_uint256(&BigUint::from(64u32)),
+                abi::encode_bytes(key.as_bytes()),
+            ],
+        );
+        let result = self.resolver_call(name, resolver, data).await.ok()?;
+        abi::decode_string(&result).and_then(non_empty)
+    }
+
+    /// Forward resolution of a normalised name to its ETH address
+    pub async fn get_address(&self, name: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let resolver = self.find_resolver(name).await?;
+        self.get_resolver_address(name, &resolver).await
+    }
+
+    async fn get_resolver_address(
+        &self,
+        name: &str,
+        resolver: &ResolverContract,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let data = abi::encode_call(ENS_ADDR_SELECTOR, &[namehash_word(name)]);
+        let result = self.resolver_call(name, resolver, data).await?;
+        abi::decode_address(&result)
+            .filter(|x| !is_zero_address(x))
+            .ok_or_else(|| format!("{} has no address record", name).into())
+    }
+
+    /// Primary name from the reverse record, not verified against forward resolution
+    pub async fn get_name(&self, address: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let reverse = reverse_name(address);
+        let node = namehash_word(&reverse);
+
+        let result = self
+            .client
+            .call_contract(
+                &self.registry,
+                &abi::encode_call(ENS_RESOLVER_SELECTOR, std::slice::from_ref(&node)),
+            )
+            .await?;
+        let resolver = abi::decode_address(&result)
+            .filter(|x| !is_zero_address(x))
+            .ok_or_else(|| format!("{} has no reverse record", address))?;
+
+        let result = self
+            .call_ccip(&resolver, abi::encode_call(ENS_NAME_SELECTOR, &[node]))
+            .await?;
+        abi::decode_string(&result)
+            .and_then(non_empty)
+            .ok_or_else(|| format!("{} has no reverse record", address).into())
+    }
+}
+
+#[async_trait]
+impl NameResolver for EnsResolver {
+    fn provider(&self) -> NameProvider {
+        NameProvider::Ens
+    }
+
+    fn get_chain(&self) -> Chain {
+        Chain::Ethereum
+    }
+
+    fn is_supported_name(&self, name: &str) -> bool {
+        let name = name.trim().trim_end_matches('.').to_lowercase();
+        name.rsplit_once('.')
+            .is_some_and(|(_, tld)| ENS_TLDS.contains(&tld))
+    }
+
+    async fn resolve_name(&self, name: String) -> Result<NameRecord, Box<dyn Error + Sync + Send>> {
+        let name = normalize(&name)?;
+        let resolver = self.find_resolver(&name).await?;
+
+        let (address, avatar, url) = tokio::join!(
+            self.get_resolver_address(&name, &resolver),
+            self.get_text(&name, &resolver, ENS_TEXT_AVATAR),
+            self.get_text(&name, &resolver, ENS_TEXT_URL),
+        );
+
+        Ok(NameRecord {
+            name,
+            chain: self.get_chain(),
+            address: address?,
+            provider: self.provider(),
+            avatar,
+            url,
+        })
+    }
+
+    async fn lookup_address(
+        &self,
+        address: String,
+    ) -> Result<NameRecord, Box<dyn Error + Sync + Send>> {
+        self.client.verify_address(address.clone())?;
+
+        let name = self.get_name(&address).await?;
+        // Anyone can set a reverse record, it must resolve back to the address
+        let record = self.resolve_name(name).await?;
+        if !record.address.eq_ignore_ascii_case(&address) {
+            return Err(format!(
+                "Reverse record {} does not resolve back to {}",
+                record.name, address
+            )
+            .into());
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ens::namehash::namehash;
+    use serde_json::Value;
+    use wiremock::matchers::{method, path_regex};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    const RESOLVER: &str = "0x4976fb03c32e5b8cfe2b6ccb31c09ba78ebaba41";
+    const OFFCHAIN_RESOLVER: &str = "0xc1735677a60884abbcf72295e88d47764beda282";
+    const VITALIK: &str = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    const IMPOSTER: &str = "0x1111111111111111111111111111111111111111";
+    const OFFCHAIN_TARGET: &str = "0x2222222222222222222222222222222222222222";
+    const CALLBACK: &str = "0xb4a85801";
+
+    fn word(value: usize) -> String {
+        abi::encode_uint256(&BigUint::from(value))
+    }
+
+    fn encode_string(value: &str) -> String {
+        format!("0x{}{}", word(32), abi::encode_bytes(value.as_bytes()))
+    }
+
+    fn encode_address(address: &str) -> String {
+        format!("0x{}", abi::encode_address(address))
+    }
+
+    fn node(name: &str) -> String {
+        hex::encode(namehash(name))
+    }
+
+    /// Record lookups answered by both the on-chain resolver and the CCIP gateway
+    fn record(data: &str) -> String {
+        let (selector, args) = data.split_at(10);
+        match selector {
+            ENS_ADDR_SELECTOR if args[..64] == node("vitalik.eth") => encode_address(VITALIK),
+            ENS_ADDR_SELECTOR if args[..64] == node("sub.offchain.eth") => {
+                encode_address(OFFCHAIN_TARGET)
+            }
+            ENS_TEXT_SELECTOR if args[..64] == node("vitalik.eth") => {
+                match abi::decode_string_at(args, 1).as_deref() {
+                    Some(ENS_TEXT_AVATAR) => encode_string("https://euc.li/vitalik.eth"),
+                    Some(ENS_TEXT_URL) => encode_string("https://vitalik.ca"),
+                    _ => encode_string(""),
+                }
+            }
+            ENS_NAME_SELECTOR if args == node(&reverse_name(VITALIK)) => {
+                encode_string("vitalik.eth")
+            }
+            ENS_NAME_SELECTOR if args == node(&reverse_name(IMPOSTER)) => {
+                encode_string("vitalik.eth")
+            }
+            _ => format!("0x{}", word(0)),
+        }
+    }
+
+    fn eth_call(server_uri: &str, to: &str, data: &str) -> Result<String, Value> {
+        let (selector, args) = data.split_at(10);
+        match (to, selector) {
+            (ENS_REGISTRY, ENS_RESOLVER_SELECTOR) => {
+                let resolver = if args == node("vitalik.eth")
+                    || args == node(&reverse_name(VITALIK))
+                    || args == node(&reverse_name(IMPOSTER))
+                {
+                    RESOLVER
+                } else if args == node("offchain.eth") {
+                    OFFCHAIN_RESOLVER
+                } else {
+                    "0x0000000000000000000000000000000000000000"
+                };
+                Ok(encode_address(resolver))
+            }
+            (_, "0x01ffc9a7") => Ok(format!(
+                "0x{}",
+                word((to == OFFCHAIN_RESOLVER && args.starts_with("9061b923")) as usize)
+            )),
+            (RESOLVER, _) => Ok(record(data)),
+            (OFFCHAIN_RESOLVER, ENS_RESOLVE_SELECTOR) => Err(json!(OffchainLookup {
+                sender: OFFCHAIN_RESOLVER.to_string(),
+                urls: vec![format!("{}/gateway/{{sender}}/{{data}}.json", server_uri)],
+                call_data: hex::decode(&data[2..]).unwrap(),
+                callback_function: CALLBACK.to_string(),
+                extra_data: b"extra".to_vec(),
+            }
+            .encode())),
+            (OFFCHAIN_RESOLVER, CALLBACK) => {
+                let response = abi::decode_bytes_at(args, 0).unwrap();
+                assert_eq!(abi::decode_bytes_at(args, 1).unwrap(), b"extra".to_vec());
+                Ok(format!("0x{}{}", word(32), abi::encode_bytes(&response)))
+            }
+            _ => Ok("0x".to_string()),
+        }
+    }
+
+    /// Mock Ethereum node with an ENS registry, a classic resolver and an ENSIP-10 offchain resolver
+    async fn mock_ens_server() -> MockServer {
+        let server = MockServer::start().await;
+        let server_uri = server.uri();
+
+        let handle = move |request: &Value| -> Value {
+            assert_eq!(request["method"], "eth_call");
+            let to = request["params"][0]["to"].as_str().unwrap().to_lowercase();
+            let to = if to == ENS_REGISTRY.to_lowercase() {
+                ENS_REGISTRY.to_string()
+            } else {
+                to
+            };
+            let data = request["params"][0]["data"].as_str().unwrap();
+            match eth_call(&server_uri, &to, data) {
+                Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+                Err(revert) => json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": { "code": 3, "message": "execution reverted", "data": revert }
+                }),
+            }
+        };
+
+        Mock::given(method("POST"))
+            .respond_with(move |request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                let response = match body.as_array() {
+                    Some(batch) => Value::Array(batch.iter().map(&handle).collect()),
+                    None => handle(&body),
+                };
+                ResponseTemplate::new(200).set_body_json(response)
+            })
+            .mount(&server)
+            .await;
+
+        // CCIP-read gateway: `{data}` is the resolve(bytes,bytes) call, answer the wrapped record call
+        Mock::given(method("GET"))
+            .and(path_regex("^/gateway/"))
+            .respond_with(|request: &Request| {
+                let data = request
+                    .url
+                    .path()
+                    .rsplit('/')
+                    .next()
+                    .unwrap()
+                    .trim_end_matches(".json");
+                let inner = abi::decode_bytes_at(&data[10..], 1).unwrap();
+                let result = record(&format!("0x{}", hex::encode(inner)));
+                ResponseTemplate::new(200).set_body_json(json!({ "data": result }))
+            })
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[test]
+    fn test_is_supported_name() {
+        let resolver = EnsResolver::new("http://localhost".to_string());
+
+        assert!(resolver.is_supported_name("vitalik.eth"));
+        assert!(resolver.is_supported_name("Sub.Vitalik.ETH"));
+        assert!(!resolver.is_supported_name("bonfida.sol"));
+        assert!(!resolver.is_supported_name("eth"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_name() {
+        let server = mock_ens_server().await;
+        let resolver = EnsResolver::new(server.uri());
+
+        let record = resolver
+            .resolve_name("Vitalik.eth".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(record.name, "vitalik.eth");
+        assert_eq!(record.address, VITALIK);
+        assert_eq!(record.chain, Chain::Ethereum);
+        assert_eq!(record.provider, NameProvider::Ens);
+        assert_eq!(
+            record.avatar,
+            Some("https://euc.li/vitalik.eth".to_string())
+        );
+        assert_eq!(record.url, Some("https://vitalik.ca".to_string()));
+
+        assert!(resolver
+            .resolve_name("unknown.eth".to_string())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_wildcard_ccip_read() {
+        let server = mock_ens_server().await;
+        let resolver = EnsResolver::new(server.uri())
+            .with_gateway_client(UntrustedClient::new().allow_local());
+
+        let record = resolver
+            .resolve_name("sub.offchain.eth".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(record.address, OFFCHAIN_TARGET);
+        assert_eq!(record.avatar, None);
+    }
+
+    #[tokio::test]
+    async fn test_lookup_address() {
+        let server = mock_ens_server().await;
+        let resolver = EnsResolver::new(server.uri());
+
+        let record = resolver
+            .lookup_address("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045".to_string())
+            .await
+            .unwrap();
+        assert_eq!(record.name, "vitalik.eth");
+
+        // Reverse record claims vitalik.eth but the name resolves elsewhere
+        assert!(resolver.lookup_address(IMPOSTER.to_string()).await.is_err());
+    }
+}
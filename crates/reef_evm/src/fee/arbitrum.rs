@@ -0,0 +1,129 @@
+use std::error::Error;
+
+use num_bigint::BigUint;
+use reef_client::Client;
+use serde_json::json;
+
+use crate::abi;
+use crate::fee::{GAS_ESTIMATE_COMPONENTS_SELECTOR, NODE_INTERFACE};
+use crate::models::{FeeEstimate, L1FeeParams, TransactionObject};
+use crate::rpc::client::EthereumClient;
+
+/// `gasEstimateComponents(to, contractCreation, data)` called with the sender and value of `tx`
+pub fn encode_gas_estimate_components(tx: &TransactionObject) -> TransactionObject {
+    let contract_creation = tx.to.is_empty();
+    let to = if contract_creation {
+        abi::encode_address("0x0")
+    } else {
+        abi::encode_address(&tx.to)
+    };
+    let data = hex::decode(tx.data.strip_prefix("0x").unwrap_or(&tx.data)).unwrap_or_default();
+    let data = abi::encode_call(
+        GAS_ESTIMATE_COMPONENTS_SELECTOR,
+        &[
+            to,
+            abi::encode_bool(contract_creation),
+            abi::encode_uint256(&BigUint::from(3u32 * 32)),
+            abi::encode_bytes(&data),
+        ],
+    );
+    TransactionObject {
+        from: tx.from.clone(),
+        to: NODE_INTERFACE.to_string(),
+        gas: None,
+        gas_price: None,
+        value: tx.value.clone(),
+        data,
+    }
+}
+
+impl<C: Client + Clone> EthereumClient<C> {
+    pub(crate) async fn estimate_arbitrum_fee(
+        &self,
+        tx: &TransactionObject,
+    ) -> Result<FeeEstimate, Box<dyn Error + Send + Sync>> {
+        let params = json!([encode_gas_estimate_components(tx), "latest"]);
+        let result: String = self
+            .client
+            .call("eth_call", params)
+            .await
+            .map_err(|e| format!("Failed to get gas estimate components: {}", e))?;
+
+        // (uint64 gasEstimate, uint64 gasEstimateForL1, uint256 baseFee, uint256 l1BaseFeeEstimate)
+        let invalid = || format!("Invalid gasEstimateComponents result: {}", result);
+        let gas_limit = abi::decode_uint256_at(&result, 0).ok_or_else(invalid)?;
+        let l1_gas = abi::decode_uint256_at(&result, 1).ok_or_else(invalid)?;
+        let base_fee = abi::decode_uint256_at(&result, 2).ok_or_else(invalid)?;
+        let l1_base_fee_estimate = abi::decode_uint256_at(&result, 3).ok_or_else(invalid)?;
+        if l1_gas > gas_limit {
+            return Err(invalid().into());
+        }
+
+        // the L1 share is charged as extra L2 gas at the L2 base fee
+        Ok(FeeEstimate {
+            l2_fee: (&gas_limit - &l1_gas) * &base_fee,
+            l1_fee: &l1_gas * &base_fee,
+            l1_fee_params: Some(L1FeeParams::Arbitrum {
+                l1_gas,
+                l1_base_fee_estimate,
+            }),
+            ..FeeEstimate::new(gas_limit, base_fee)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::EVMChain;
+    use reef_client::MockClient;
+
+    const TO: &str = "0x000000000000000000000000000000000000dead";
+
+    #[test]
+    fn test_encode_gas_estimate_components() {
+        let tx = TransactionObject::new_call(TO, "0xabcd");
+        let call = encode_gas_estimate_components(&tx);
+
+        assert_eq!(call.to, NODE_INTERFACE);
+        assert_eq!(abi::decode_address_at(&call.data[10..], 0).unwrap(), TO);
+        assert_eq!(abi::decode_bool(&call.data[10 + 64..]), Some(false));
+        assert_eq!(
+            abi::decode_bytes_at(&call.data[10..], 2),
+            Some(vec![0xab, 0xcd])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_estimate_fee_arbitrum() {
+        let tx = TransactionObject::new_call_with_value(TO, "0x1", "0x");
+        let result = format!(
+            "0x{}{}{}{}",
+            abi::encode_uint256(&BigUint::from(121000u32)),
+            abi::encode_uint256(&BigUint::from(100000u32)),
+            abi::encode_uint256(&BigUint::from(10_000_000u32)),
+            abi::encode_uint256(&BigUint::from(20_000_000_000u64)),
+        );
+        let mock = MockClient::new().with_response(
+            "eth_call",
+            json!([encode_gas_estimate_components(&tx), "latest"]),
+            json!(result),
+        );
+        let client = EthereumClient::new_with_client(mock, EVMChain::Arbitrum);
+
+        let fee = client.estimate_fee(&tx).await.unwrap();
+
+        assert_eq!(fee.gas_limit, BigUint::from(121000u32));
+        assert_eq!(fee.gas_price, BigUint::from(10_000_000u32));
+        assert_eq!(fee.l2_fee, BigUint::from(210_000_000_000u64));
+        assert_eq!(fee.l1_fee, BigUint::from(1_000_000_000_000u64));
+        assert_eq!(fee.total_fee(), BigUint::from(1_210_000_000_000u64));
+        assert_eq!(
+            fee.l1_fee_params,
+            Some(L1FeeParams::Arbitrum {
+                l1_gas: BigUint::from(100000u32),
+                l1_base_fee_estimate: BigUint::from(20_000_000_000u64),
+            })
+        );
+    }
+}
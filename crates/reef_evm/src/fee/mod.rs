@@ -0,0 +1,126 @@
+pub mod arbitrum;
+pub mod op_stack;
+pub mod rlp;
+
+use std::error::Error;
+
+use num_bigint::BigUint;
+use reef_client::Client;
+
+use crate::models::{FeeEstimate, TransactionObject};
+use crate::rpc::client::EthereumClient;
+
+/// OP Stack `GasPriceOracle` predeploy
+pub const GAS_PRICE_ORACLE: &str = "0x420000000000000000000000000000000000000F";
+/// getL1Fee(bytes)
+pub const GET_L1_FEE_SELECTOR: &str = "0x49948e0e";
+/// l1BaseFee()
+pub const L1_BASE_FEE_SELECTOR: &str = "0x519b4bd3";
+/// blobBaseFee()
+pub const BLOB_BASE_FEE_SELECTOR: &str = "0xf8206140";
+/// baseFeeScalar()
+pub const BASE_FEE_SCALAR_SELECTOR: &str = "0xc5985918";
+/// blobBaseFeeScalar()
+pub const BLOB_BASE_FEE_SCALAR_SELECTOR: &str = "0x68d5dca6";
+/// isEcotone()
+pub const IS_ECOTONE_SELECTOR: &str = "0x4ef6e224";
+/// isFjord()
+pub const IS_FJORD_SELECTOR: &str = "0x960e3a23";
+
+/// Arbitrum `NodeInterface` virtual contract, only reachable through `eth_call`
+pub const NODE_INTERFACE: &str = "0x00000000000000000000000000000000000000C8";
+/// gasEstimateComponents(address,bool,bytes)
+pub const GAS_ESTIMATE_COMPONENTS_SELECTOR: &str = "0xc94e6eeb";
+
+/// Parse a `0x` quantity returned by the node
+pub fn parse_quantity(hex_value: &str) -> Option<BigUint> {
+    let hex_value = hex_value.strip_prefix("0x").unwrap_or(hex_value);
+    if hex_value.is_empty() {
+        return Some(BigUint::ZERO);
+    }
+    BigUint::parse_bytes(hex_value.as_bytes(), 16)
+}
+
+fn quantity(hex_value: &str, field: &str) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+    parse_quantity(hex_value).ok_or_else(|| format!("Invalid {}: {}", field, hex_value).into())
+}
+
+impl<C: Client + Clone> EthereumClient<C> {
+    /// Fee quote for `tx`, adding the L1 data cost on OP Stack and Arbitrum chains
+    pub async fn estimate_fee(
+        &self,
+        tx: &TransactionObject,
+    ) -> Result<FeeEstimate, Box<dyn Error + Send + Sync>> {
+        if self.chain.is_arbitrum_stack() {
+            return self.estimate_arbitrum_fee(tx).await;
+        }
+        let gas_limit = quantity(&self.estimate_gas(tx).await?, "gas limit")?;
+        let gas_price = quantity(&self.get_gas_price().await?, "gas price")?;
+        if self.chain.is_opstack() {
+            return self.estimate_op_stack_fee(tx, gas_limit, gas_price).await;
+        }
+        Ok(FeeEstimate::new(gas_limit, gas_price))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::{self, function_selector};
+    use primitives::EVMChain;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    #[test]
+    fn test_fee_selectors() {
+        assert_eq!(function_selector("getL1Fee(bytes)"), GET_L1_FEE_SELECTOR);
+        assert_eq!(function_selector("l1BaseFee()"), L1_BASE_FEE_SELECTOR);
+        assert_eq!(function_selector("blobBaseFee()"), BLOB_BASE_FEE_SELECTOR);
+        assert_eq!(
+            function_selector("baseFeeScalar()"),
+            BASE_FEE_SCALAR_SELECTOR
+        );
+        assert_eq!(
+            function_selector("blobBaseFeeScalar()"),
+            BLOB_BASE_FEE_SCALAR_SELECTOR
+        );
+        assert_eq!(function_selector("isEcotone()"), IS_ECOTONE_SELECTOR);
+        assert_eq!(function_selector("isFjord()"), IS_FJORD_SELECTOR);
+        assert_eq!(
+            function_selector("gasEstimateComponents(address,bool,bytes)"),
+            GAS_ESTIMATE_COMPONENTS_SELECTOR
+        );
+    }
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity("0x5208"), Some(BigUint::from(21000u32)));
+        assert_eq!(parse_quantity("0x"), Some(BigUint::ZERO));
+        assert_eq!(parse_quantity("0xzz"), None);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_fee_l1() {
+        let mock = MockClient::new()
+            .with_method_response("eth_estimateGas", json!("0x5208"))
+            .with_method_response("eth_gasPrice", json!("0x3b9aca00"));
+        let client = EthereumClient::new_with_client(mock, EVMChain::Ethereum);
+        let tx = TransactionObject::new_call_with_value(
+            "0x000000000000000000000000000000000000dead",
+            "0x1",
+            "0x",
+        );
+
+        let fee = client.estimate_fee(&tx).await.unwrap();
+
+        assert_eq!(fee.gas_limit, BigUint::from(21000u32));
+        assert_eq!(fee.l2_fee, BigUint::from(21_000_000_000_000u64));
+        assert_eq!(fee.l1_fee, BigUint::ZERO);
+        assert_eq!(fee.total_fee(), fee.l2_fee);
+        assert_eq!(fee.l1_fee_params, None);
+    }
+
+    pub(super) fn uint_result(value: u64) -> serde_json::Value {
+        json!(format!("0x{}", abi::encode_uint256(&BigUint::from(value))))
+    }
+}
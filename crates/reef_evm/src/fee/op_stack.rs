@@ -0,0 +1,213 @@
+use std::error::Error;
+
+use num_bigint::BigUint;
+use reef_client::Client;
+
+use crate::abi;
+use crate::fee::{
+    parse_quantity, rlp, BASE_FEE_SCALAR_SELECTOR, BLOB_BASE_FEE_SCALAR_SELECTOR,
+    BLOB_BASE_FEE_SELECTOR, GAS_PRICE_ORACLE, GET_L1_FEE_SELECTOR, IS_ECOTONE_SELECTOR,
+    IS_FJORD_SELECTOR, L1_BASE_FEE_SELECTOR,
+};
+use crate::models::{FeeEstimate, L1FeeParams, TransactionObject};
+use crate::rpc::client::EthereumClient;
+
+/// EIP-1559 transaction type
+const EIP1559_TX_TYPE: u8 = 0x02;
+
+fn hex_bytes(value: &str) -> Vec<u8> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value)).unwrap_or_default()
+}
+
+/// Unsigned EIP-1559 serialization passed to `getL1Fee`, the oracle adds the signature overhead itself
+pub fn serialize_unsigned(
+    chain_id: u64,
+    tx: &TransactionObject,
+    gas_limit: &BigUint,
+    gas_price: &BigUint,
+) -> Vec<u8> {
+    let value = tx
+        .value
+        .as_deref()
+        .and_then(parse_quantity)
+        .unwrap_or_default();
+    let fields = vec![
+        rlp::encode_uint(&BigUint::from(chain_id)),
+        // nonce only moves the size by a couple of bytes
+        rlp::encode_uint(&BigUint::ZERO),
+        rlp::encode_uint(gas_price),
+        rlp::encode_uint(gas_price),
+        rlp::encode_uint(gas_limit),
+        rlp::encode_bytes(&hex_bytes(&tx.to)),
+        rlp::encode_uint(&value),
+        rlp::encode_bytes(&hex_bytes(&tx.data)),
+        rlp::encode_list(&[]),
+    ];
+    let mut out = vec![EIP1559_TX_TYPE];
+    out.extend(rlp::encode_list(&fields));
+    out
+}
+
+pub fn encode_get_l1_fee(payload: &[u8]) -> String {
+    abi::encode_dynamic_call(GET_L1_FEE_SELECTOR, &[payload])
+}
+
+impl<C: Client + Clone> EthereumClient<C> {
+    pub(crate) async fn estimate_op_stack_fee(
+        &self,
+        tx: &TransactionObject,
+        gas_limit: BigUint,
+        gas_price: BigUint,
+    ) -> Result<FeeEstimate, Box<dyn Error + Send + Sync>> {
+        let payload = serialize_unsigned(self.chain.chain_id(), tx, &gas_limit, &gas_price);
+        let calls = [
+            encode_get_l1_fee(&payload),
+            L1_BASE_FEE_SELECTOR.to_string(),
+            BLOB_BASE_FEE_SELECTOR.to_string(),
+            BASE_FEE_SCALAR_SELECTOR.to_string(),
+            BLOB_BASE_FEE_SCALAR_SELECTOR.to_string(),
+            IS_ECOTONE_SELECTOR.to_string(),
+            IS_FJORD_SELECTOR.to_string(),
+        ]
+        .into_iter()
+        .map(|data| (GAS_PRICE_ORACLE.to_string(), data))
+        .collect();
+
+        let mut results = self.batch_contract_calls(calls).await?.into_iter();
+        let l1_fee = results
+            .next()
+            .ok_or("Missing getL1Fee result")?
+            .map_err(|e| format!("Failed to get L1 fee: {}", e))?;
+        let l1_fee = abi::decode_uint256(&l1_fee).ok_or("Invalid getL1Fee result")?;
+
+        // pre-Ecotone oracles revert on the newer getters
+        let mut field = || {
+            results
+                .next()
+                .and_then(|result| result.ok())
+                .and_then(|result| abi::decode_uint256(&result))
+                .unwrap_or_default()
+        };
+        let l1_base_fee = field();
+        let blob_base_fee = field();
+        let base_fee_scalar = field();
+        let blob_base_fee_scalar = field();
+        let ecotone = field() != BigUint::ZERO;
+        let fjord = field() != BigUint::ZERO;
+
+        Ok(FeeEstimate {
+            l1_fee,
+            l1_fee_params: Some(L1FeeParams::OpStack {
+                l1_base_fee,
+                blob_base_fee,
+                base_fee_scalar,
+                blob_base_fee_scalar,
+                ecotone,
+                fjord,
+            }),
+            ..FeeEstimate::new(gas_limit, gas_price)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee::tests::uint_result;
+    use primitives::EVMChain;
+    use reef_client::MockClient;
+    use serde_json::{json, Value};
+
+    const TO: &str = "0x000000000000000000000000000000000000dead";
+
+    fn oracle_call(data: &str) -> Value {
+        json!([
+            TransactionObject::new_call(GAS_PRICE_ORACLE, data),
+            "latest"
+        ])
+    }
+
+    #[test]
+    fn test_serialize_unsigned() {
+        let tx = TransactionObject::new_call_with_value(TO, "0x1", "0x");
+        let payload = serialize_unsigned(
+            8453,
+            &tx,
+            &BigUint::from(21000u32),
+            &BigUint::from(1_000_000u32),
+        );
+
+        assert_eq!(
+            hex::encode(&payload),
+            concat!(
+                "02e7",
+                "822105",
+                "80",
+                "830f4240",
+                "830f4240",
+                "825208",
+                "94000000000000000000000000000000000000dead",
+                "01",
+                "80",
+                "c0"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_estimate_fee_op_stack() {
+        let tx = TransactionObject::new_call_with_value(TO, "0x1", "0x");
+        let gas_limit = BigUint::from(21000u32);
+        let gas_price = BigUint::from(1_000_000u32);
+        let payload = serialize_unsigned(EVMChain::Base.chain_id(), &tx, &gas_limit, &gas_price);
+
+        let mock = MockClient::new()
+            .with_method_response("eth_estimateGas", json!("0x5208"))
+            .with_method_response("eth_gasPrice", json!("0xf4240"))
+            .with_response(
+                "eth_call",
+                oracle_call(&encode_get_l1_fee(&payload)),
+                uint_result(30_000_000_000),
+            )
+            .with_response(
+                "eth_call",
+                oracle_call(L1_BASE_FEE_SELECTOR),
+                uint_result(2_000_000_000),
+            )
+            .with_response(
+                "eth_call",
+                oracle_call(BLOB_BASE_FEE_SELECTOR),
+                uint_result(1),
+            )
+            .with_response(
+                "eth_call",
+                oracle_call(BASE_FEE_SCALAR_SELECTOR),
+                uint_result(2269),
+            )
+            .with_response(
+                "eth_call",
+                oracle_call(BLOB_BASE_FEE_SCALAR_SELECTOR),
+                uint_result(1055762),
+            )
+            .with_response("eth_call", oracle_call(IS_ECOTONE_SELECTOR), uint_result(1))
+            .with_response("eth_call", oracle_call(IS_FJORD_SELECTOR), uint_result(1));
+        let client = EthereumClient::new_with_client(mock, EVMChain::Base);
+
+        let fee = client.estimate_fee(&tx).await.unwrap();
+
+        assert_eq!(fee.l2_fee, BigUint::from(21_000_000_000u64));
+        assert_eq!(fee.l1_fee, BigUint::from(30_000_000_000u64));
+        assert_eq!(fee.total_fee(), BigUint::from(51_000_000_000u64));
+        assert_eq!(
+            fee.l1_fee_params,
+            Some(L1FeeParams::OpStack {
+                l1_base_fee: BigUint::from(2_000_000_000u64),
+                blob_base_fee: BigUint::from(1u32),
+                base_fee_scalar: BigUint::from(2269u32),
+                blob_base_fee_scalar: BigUint::from(1055762u32),
+                ecotone: true,
+                fjord: true,
+            })
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use num_bigint::BigUint;
+
+/// Minimal RLP encoding, enough to serialize unsigned transactions
+pub fn encode_bytes(data: &[u8]) -> Vec<u8> {
+    if data.len() == 1 && data[0] < 0x80 {
+        return data.to_vec();
+    }
+    let mut out = encode_length(data.len(), 0x80);
+    out.extend_from_slice(data);
+    out
+}
+
+/// Big-endian without leading zeros, zero encodes as the empty string
+pub fn encode_uint(value: &BigUint) -> Vec<u8> {
+    if value == &BigUint::ZERO {
+        return encode_bytes(&[]);
+    }
+    encode_bytes(&value.to_bytes_be())
+}
+
+/// List of already encoded items
+pub fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut out = encode_length(payload.len(), 0xc0);
+    out.extend(payload);
+    out
+}
+
+fn encode_length(length: usize, offset: u8) -> Vec<u8> {
+    if length <= 55 {
+        return vec![offset + length as u8];
+    }
+    let length = length.to_be_bytes();
+    let length = &length[length.iter().position(|&b| b != 0).unwrap_or(0)..];
+    let mut out = vec![offset + 55 + length.len() as u8];
+    out.extend_from_slice(length);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode_bytes(b"dog"), vec![0x83, b'd', b'o', b'g']);
+        assert_eq!(encode_bytes(&[0x0f]), vec![0x0f]);
+        assert_eq!(encode_bytes(&[]), vec![0x80]);
+        assert_eq!(encode_uint(&BigUint::ZERO), vec![0x80]);
+        assert_eq!(encode_uint(&BigUint::from(1024u32)), vec![0x82, 0x04, 0x00]);
+        assert_eq!(encode_list(&[]), vec![0xc0]);
+        assert_eq!(
+            encode_list(&[encode_bytes(b"cat"), encode_bytes(b"dog")]),
+            vec![0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']
+        );
+
+        let long = encode_bytes(&[0xaa; 56]);
+        assert_eq!(&long[..2], &[0xb8, 56]);
+        assert_eq!(long.len(), 58);
+    }
+}
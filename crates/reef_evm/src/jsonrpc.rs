@@ -1,5 +1,7 @@
 use crate::models::{BlockParameter, TransactionObject};
 use reef_jsonrpc::types::JsonRpcRequest;
+use crate::abi;
+use num_bigint::BigUint;
 use serde_json::{json, Value};
 
 #[derive(Debug, Clone)]
@@ -95,6 +97,25 @@ pub const ERC20_SYMBOL_SELECTOR: &str = "0x95d89b41";
 pub const ERC20_DECIMALS_SELECTOR: &str = "0x313ce567";
 pub const ERC20_BALANCE_OF_SELECTOR: &str = "0x70a08231";
 
+// ERC-165 / ERC-721 / ERC-1155 function selectors
+pub const ERC165_SUPPORTS_INTERFACE_SELECTOR: &str = "0x01ffc9a7";
+pub const ERC721_OWNER_OF_SELECTOR: &str = "0x6352211e";
+pub const ERC721_TOKEN_URI_SELECTOR: &str = "0xc87b56dd";
+pub const ERC1155_BALANCE_OF_SELECTOR: &str = "0x00fdd58e";
+pub const ERC1155_URI_SELECTOR: &str = "0x0e89341c";
+
+// ERC-165 interface ids
+pub const ERC721_INTERFACE_ID: &str = "0x80ac58cd";
+pub const ERC1155_INTERFACE_ID: &str = "0xd9b67a26";
+
+// Event topics
+pub const TRANSFER_EVENT_TOPIC: &str =
+    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+pub const TRANSFER_SINGLE_EVENT_TOPIC: &str =
+    "0xc3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62";
+pub const TRANSFER_BATCH_EVENT_TOPIC: &str =
+    "0x4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb";
+
 // Helper functions for ERC-20 calls
 pub fn encode_balance_of_call(address: &str) -> String {
     let address_clean = address.strip_prefix("0x").unwrap_or(address);
@@ -104,6 +125,33 @@ pub fn encode_balance_of_call(address: &str) -> String {
     )
 }
 
+// Helper functions for NFT calls
+pub fn encode_supports_interface_call(interface_id: &str) -> String {
+    abi::encode_call(
+        ERC165_SUPPORTS_INTERFACE_SELECTOR,
+        &[abi::encode_fixed_bytes(interface_id)],
+    )
+}
+
+pub fn encode_owner_of_call(token_id: &BigUint) -> String {
+    abi::encode_call(ERC721_OWNER_OF_SELECTOR, &[abi::encode_uint256(token_id)])
+}
+
+pub fn encode_token_uri_call(token_id: &BigUint) -> String {
+    abi::encode_call(ERC721_TOKEN_URI_SELECTOR, &[abi::encode_uint256(token_id)])
+}
+
+pub fn encode_uri_call(token_id: &BigUint) -> String {
+    abi::encode_call(ERC1155_URI_SELECTOR, &[abi::encode_uint256(token_id)])
+}
+
+pub fn encode_erc1155_balance_of_call(owner: &str, token_id: &BigUint) -> String {
+    abi::encode_call(
+        ERC1155_BALANCE_OF_SELECTOR,
+        &[abi::encode_address(owner), abi::encode_uint256(token_id)],
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +199,52 @@ mod tests {
         assert_eq!(ERC20_DECIMALS_SELECTOR, "0x313ce567");
         assert_eq!(ERC20_BALANCE_OF_SELECTOR, "0x70a08231");
     }
+
+    #[test]
+    fn test_nft_selectors() {
+        assert_eq!(
+            ERC165_SUPPORTS_INTERFACE_SELECTOR,
+            abi::function_selector("supportsInterface(bytes4)")
+        );
+        assert_eq!(
+            ERC721_OWNER_OF_SELECTOR,
+            abi::function_selector("ownerOf(uint256)")
+        );
+        assert_eq!(
+            ERC721_TOKEN_URI_SELECTOR,
+            abi::function_selector("tokenURI(uint256)")
+        );
+        assert_eq!(
+            ERC1155_BALANCE_OF_SELECTOR,
+            abi::function_selector("balanceOf(address,uint256)")
+        );
+        assert_eq!(ERC1155_URI_SELECTOR, abi::function_selector("uri(uint256)"));
+    }
+
+    #[test]
+    fn test_nft_event_topics() {
+        assert_eq!(
+            TRANSFER_EVENT_TOPIC,
+            abi::event_topic("Transfer(address,address,uint256)")
+        );
+        assert_eq!(
+            TRANSFER_SINGLE_EVENT_TOPIC,
+            abi::event_topic("TransferSingle(address,address,address,uint256,uint256)")
+        );
+        assert_eq!(
+            TRANSFER_BATCH_EVENT_TOPIC,
+            abi::event_topic("TransferBatch(address,address,address,uint256[],uint256[])")
+        );
+    }
+
+    #[test]
+    fn test_encode_erc1155_balance_of_call() {
+        let encoded = encode_erc1155_balance_of_call(
+            "0x123456789abcdef123456789abcdef123456789a",
+            &BigUint::from(1u32),
+        );
+        assert!(encoded.starts_with(ERC1155_BALANCE_OF_SELECTOR));
+        assert_eq!(encoded.len(), 10 + 64 * 2);
+        assert!(encoded.ends_with("01"));
+    }
 }
@@ -1,3 +1,6 @@
+pub mod abi;
+pub mod ens;
+pub mod fee;
 pub mod jsonrpc;
 pub mod models;
 pub mod provider;
@@ -1,3 +1,4 @@
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,3 +11,50 @@ pub struct FeeHistory {
     pub gas_used_ratio: Vec<f64>,
     pub reward: Option<Vec<Vec<String>>>,
 }
+
+/// Fee quote for a transaction, `l1_fee` stays zero outside rollups
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeEstimate {
+    pub gas_limit: BigUint,
+    pub gas_price: BigUint,
+    /// L2 execution cost, `gas_limit * gas_price` on L1 chains
+    pub l2_fee: BigUint,
+    /// L1 data availability cost charged by rollups
+    pub l1_fee: BigUint,
+    pub l1_fee_params: Option<L1FeeParams>,
+}
+
+impl FeeEstimate {
+    pub fn new(gas_limit: BigUint, gas_price: BigUint) -> Self {
+        Self {
+            l2_fee: &gas_limit * &gas_price,
+            gas_limit,
+            gas_price,
+            l1_fee: BigUint::ZERO,
+            l1_fee_params: None,
+        }
+    }
+
+    pub fn total_fee(&self) -> BigUint {
+        &self.l2_fee + &self.l1_fee
+    }
+}
+
+/// Inputs behind the L1 data fee
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum L1FeeParams {
+    /// `GasPriceOracle` fields, scalars are zero before Ecotone
+    OpStack {
+        l1_base_fee: BigUint,
+        blob_base_fee: BigUint,
+        base_fee_scalar: BigUint,
+        blob_base_fee_scalar: BigUint,
+        ecotone: bool,
+        fjord: bool,
+    },
+    /// `NodeInterface.gasEstimateComponents`, L1 cost expressed in L2 gas
+    Arbitrum {
+        l1_gas: BigUint,
+        l1_base_fee_estimate: BigUint,
+    },
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionObject {
@@ -106,8 +107,31 @@ pub struct Log {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Vec<String>>,
+    pub from_block: String,
+    pub to_block: String,
+    /// Each position matches any of the listed topics; `None` matches anything
+    pub topics: Vec<Option<Vec<String>>>,
+}
+
+impl LogFilter {
+    pub fn new(from_block: u64, to_block: u64, topics: Vec<Option<Vec<String>>>) -> Self {
+        Self {
+            address: None,
+            from_block: format!("0x{:x}", from_block),
+            to_block: format!("0x{:x}", to_block),
+            topics,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum BlockParameter {
+    #[default]
     Latest,
     Earliest,
     Pending,
@@ -116,21 +140,15 @@ pub enum BlockParameter {
     Number(String),
 }
 
-impl Default for BlockParameter {
-    fn default() -> Self {
-        BlockParameter::Latest
-    }
-}
-
-impl ToString for BlockParameter {
-    fn to_string(&self) -> String {
+impl fmt::Display for BlockParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            BlockParameter::Latest => "latest".to_string(),
-            BlockParameter::Earliest => "earliest".to_string(),
-            BlockParameter::Pending => "pending".to_string(),
-            BlockParameter::Safe => "safe".to_string(),
-            BlockParameter::Finalized => "finalized".to_string(),
-            BlockParameter::Number(n) => n.clone(),
+            BlockParameter::Latest => write!(f, "latest"),
+            BlockParameter::Earliest => write!(f, "earliest"),
+            BlockParameter::Pending => write!(f, "pending"),
+            BlockParameter::Safe => write!(f, "safe"),
+            BlockParameter::Finalized => write!(f, "finalized"),
+            BlockParameter::Number(n) => write!(f, "{}", n),
         }
     }
 }
@@ -228,6 +246,25 @@ mod tests {
         assert_eq!(deserialized.status, "0x1");
     }
 
+    #[test]
+    fn test_log_filter_serialization() {
+        let filter = LogFilter::new(
+            16,
+            255,
+            vec![Some(vec!["0xabc".to_string()]), None, Some(vec!["0xdef".to_string()])],
+        );
+        let json = serde_json::to_value(&filter).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "fromBlock": "0x10",
+                "toBlock": "0xff",
+                "topics": [["0xabc"], null, ["0xdef"]]
+            })
+        );
+    }
+
     #[test]
     fn test_log_creation() {
         let log = Log {
@@ -3,6 +3,7 @@ use std::error::Error;
 use async_trait::async_trait;
 use primitives::ChainBalances;
 use primitives::{AssetBalance, Balance, EVMChain};
+use reef_client::Client;
 
 use crate::rpc::client::EthereumClient;
 
@@ -22,13 +23,13 @@ fn map_balance_coin(
     Ok(AssetBalance {
         chain: chain.to_chain(),
         contract_address: None,
-        balance: Balance::coin_balance(balance_big, 18), // ETH has 18 decimals
+        balance: Balance::coin_balance(balance_big, chain.native_decimals()),
         is_active: Some(true),
     })
 }
 
 #[async_trait]
-impl ChainBalances for EthereumClient {
+impl<C: Client + Clone> ChainBalances for EthereumClient<C> {
     async fn get_balance_coin(
         &self,
         address: String,
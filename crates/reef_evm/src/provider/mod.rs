@@ -1,4 +1,6 @@
 pub mod accounts;
 pub mod balances;
+pub mod nft;
+pub mod nft_mapper;
 pub mod token;
 pub mod token_mapper;
@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{ChainNft, NFTAsset, NFTCollection};
+use reef_client::Client;
+use serde_json::json;
+
+use crate::abi;
+use crate::jsonrpc::{
+    encode_erc1155_balance_of_call, encode_owner_of_call, encode_supports_interface_call,
+    encode_token_uri_call, encode_uri_call, ERC1155_INTERFACE_ID, ERC20_NAME_SELECTOR,
+    ERC20_SYMBOL_SELECTOR, ERC721_INTERFACE_ID, TRANSFER_BATCH_EVENT_TOPIC, TRANSFER_EVENT_TOPIC,
+    TRANSFER_SINGLE_EVENT_TOPIC,
+};
+use crate::models::{Log, LogFilter};
+use crate::provider::nft_mapper::{
+    map_nft_asset, map_nft_collections, map_transfer_logs, EvmNftType, NftCandidate,
+};
+use crate::rpc::client::EthereumClient;
+
+/// Most public nodes reject `eth_getLogs` ranges wider than this
+const NFT_LOGS_BLOCK_RANGE: u64 = 10_000;
+/// How far back ownership discovery scans transfer logs unless configured per chain
+pub const DEFAULT_NFT_LOGS_LOOKBACK_BLOCKS: u64 = 100_000;
+
+fn parse_token_id(token_id: &str) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+    let parsed = match token_id.strip_prefix("0x") {
+        Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+        None => BigUint::from_str(token_id).ok(),
+    };
+    parsed.ok_or_else(|| format!("Invalid token id: {}", token_id).into())
+}
+
+fn parse_block_number(hex: &str) -> Result<u64, Box<dyn Error + Send + Sync>> {
+    Ok(u64::from_str_radix(hex.trim_start_matches("0x"), 16)?)
+}
+
+impl<C: Client + Clone> EthereumClient<C> {
+    /// Detect the NFT standard of a contract using ERC-165
+    pub async fn get_nft_type(
+        &self,
+        contract_address: &str,
+    ) -> Result<EvmNftType, Box<dyn Error + Send + Sync>> {
+        let calls = vec![
+            (
+                contract_address.to_string(),
+                encode_supports_interface_call(ERC721_INTERFACE_ID),
+            ),
+            (
+                contract_address.to_string(),
+                encode_supports_interface_call(ERC1155_INTERFACE_ID),
+            ),
+        ];
+        let results = self.batch_contract_calls(calls).await?;
+        let supports = |index: usize| {
+            results
+                .get(index)
+                .and_then(|x| x.as_ref().ok())
+                .and_then(|x| abi::decode_bool(x))
+                .unwrap_or(false)
+        };
+
+        if supports(0) {
+            Ok(EvmNftType::ERC721)
+        } else if supports(1) {
+            Ok(EvmNftType::ERC1155)
+        } else {
+            Err(format!(
+                "{} is not an ERC-721 or ERC-1155 contract",
+                contract_address
+            )
+            .into())
+        }
+    }
+
+    /// Incoming ERC-721 and ERC-1155 transfer logs for `owner` in the block range
+    pub async fn get_nft_transfer_logs(
+        &self,
+        owner: &str,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log>, Box<dyn Error + Send + Sync>> {
+        let owner_topic = abi::address_topic(owner);
+        let mut calls = Vec::new();
+
+        let mut start = from_block;
+        while start <= to_block {
+            let end = (start + NFT_LOGS_BLOCK_RANGE - 1).min(to_block);
+            let erc721 = LogFilter::new(
+                start,
+                end,
+                vec![
+                    Some(vec![TRANSFER_EVENT_TOPIC.to_string()]),
+                    None,
+                    Some(vec![owner_topic.clone()]),
+                ],
+            );
+            let erc1155 = LogFilter::new(
+                start,
+                end,
+                vec![
+                    Some(vec![
+                        TRANSFER_SINGLE_EVENT_TOPIC.to_string(),
+                        TRANSFER_BATCH_EVENT_TOPIC.to_string(),
+                    ]),
+                    None,
+                    None,
+                    Some(vec![owner_topic.clone()]),
+                ],
+            );
+            calls.push(("eth_getLogs".to_string(), json!([erc721])));
+            calls.push(("eth_getLogs".to_string(), json!([erc1155])));
+            start = end + 1;
+        }
+
+        let results = self.client.batch_call::<Vec<Log>>(calls).await?;
+        let mut logs = Vec::new();
+        for result in results {
+            logs.extend(result.map_err(|e| format!("Failed to get logs: {}", e))?);
+        }
+        Ok(logs)
+    }
+
+    /// Keep the candidates still held by `owner`, paired with their balance
+    async fn get_owned_nfts(
+        &self,
+        owner: &str,
+        candidates: Vec<NftCandidate>,
+    ) -> Result<Vec<(NftCandidate, BigUint)>, Box<dyn Error + Send + Sync>> {
+        let calls = candidates
+            .iter()
+            .map(|candidate| {
+                let data = match candidate.token_type {
+                    EvmNftType::ERC721 => encode_owner_of_call(&candidate.token_id),
+                    EvmNftType::ERC1155 => {
+                        encode_erc1155_balance_of_call(owner, &candidate.token_id)
+                    }
+                };
+                (candidate.contract_address.clone(), data)
+            })
+            .collect();
+        let results = self.batch_contract_calls(calls).await?;
+
+        let owner = owner.to_lowercase();
+        Ok(candidates
+            .into_iter()
+            .zip(results)
+            .filter_map(|(candidate, result)| {
+                let result = result.ok()?;
+                let balance = match candidate.token_type {
+                    EvmNftType::ERC721 => {
+                        (abi::decode_address(&result)? == owner).then(|| BigUint::from(1u32))?
+                    }
+                    EvmNftType::ERC1155 => abi::decode_uint256(&result)?,
+                };
+                (balance > BigUint::from(0u32)).then_some((candidate, balance))
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainNft for EthereumClient<C> {
+    async fn get_nfts(
+        &self,
+        address: String,
+    ) -> Result<Vec<NFTCollection>, Box<dyn Error + Sync + Send>> {
+        self.verify_address(address.clone())?;
+
+        let latest = parse_block_number(&self.get_block_number().await?)?;
+        let from_block = latest.saturating_sub(self.nft_logs_lookback);
+        let logs = self
+            .get_nft_transfer_logs(&address, from_block, latest)
+            .await?;
+
+        let owned = self
+            .get_owned_nfts(&address, map_transfer_logs(&logs))
+            .await?;
+        if owned.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut contracts: Vec<String> = Vec::new();
+        for (candidate, _) in &owned {
+            if !contracts.contains(&candidate.contract_address) {
+                contracts.push(candidate.contract_address.clone());
+            }
+        }
+
+        let mut calls: Vec<(String, String)> = owned
+            .iter()
+            .map(|(candidate, _)| {
+                let data = match candidate.token_type {
+                    EvmNftType::ERC721 => encode_token_uri_call(&candidate.token_id),
+                    EvmNftType::ERC1155 => encode_uri_call(&candidate.token_id),
+                };
+                (candidate.contract_address.clone(), data)
+            })
+            .collect();
+        for contract in &contracts {
+            calls.push((contract.clone(), ERC20_NAME_SELECTOR.to_string()));
+            calls.push((contract.clone(), ERC20_SYMBOL_SELECTOR.to_string()));
+        }
+
+        let results = self.batch_contract_calls(calls).await?;
+        let decode = |index: usize| {
+            results
+                .get(index)
+                .and_then(|x| x.as_ref().ok())
+                .and_then(|x| abi::decode_string(x))
+        };
+
+        let metadata: HashMap<String, (Option<String>, Option<String>)> = contracts
+            .iter()
+            .enumerate()
+            .map(|(i, contract)| {
+                let offset = owned.len() + i * 2;
+                (contract.clone(), (decode(offset), decode(offset + 1)))
+            })
+            .collect();
+
+        let nfts = owned
+            .iter()
+            .enumerate()
+            .map(|(i, (candidate, balance))| {
+                let name = metadata
+                    .get(&candidate.contract_address)
+                    .and_then(|(name, _)| name.clone());
+                map_nft_asset(
+                    self.get_chain(),
+                    candidate,
+                    Some(address.clone()),
+                    Some(balance.to_string()),
+                    decode(i),
+                    name,
+                )
+            })
+            .collect();
+
+        Ok(map_nft_collections(self.get_chain(), nfts, &metadata))
+    }
+
+    async fn get_nft(
+        &self,
+        contract_address: String,
+        token_id: String,
+    ) -> Result<NFTAsset, Box<dyn Error + Sync + Send>> {
+        self.verify_address(contract_address.clone())?;
+
+        let candidate = NftCandidate {
+            contract_address: contract_address.to_lowercase(),
+            token_id: parse_token_id(&token_id)?,
+            token_type: self.get_nft_type(&contract_address).await?,
+        };
+
+        let mut calls = vec![(contract_address.clone(), ERC20_NAME_SELECTOR.to_string())];
+        match candidate.token_type {
+            EvmNftType::ERC721 => {
+                calls.push((
+                    contract_address.clone(),
+                    encode_token_uri_call(&candidate.token_id),
+                ));
+                calls.push((
+                    contract_address.clone(),
+                    encode_owner_of_call(&candidate.token_id),
+                ));
+            }
+            EvmNftType::ERC1155 => {
+                calls.push((
+                    contract_address.clone(),
+                    encode_uri_call(&candidate.token_id),
+                ));
+            }
+        }
+
+        let results = self.batch_contract_calls(calls).await?;
+        let result = |index: usize| results.get(index).and_then(|x| x.as_ref().ok());
+
+        let name = result(0).and_then(|x| abi::decode_string(x));
+        let token_uri = result(1).and_then(|x| abi::decode_string(x));
+        let (owner, balance) = match candidate.token_type {
+            EvmNftType::ERC721 => {
+                let owner = result(2)
+                    .and_then(|x| abi::decode_address(x))
+                    .ok_or("Token does not exist")?;
+                (Some(owner), Some("1".to_string()))
+            }
+            EvmNftType::ERC1155 => (None, None),
+        };
+
+        Ok(map_nft_asset(
+            self.get_chain(),
+            &candidate,
+            owner,
+            balance,
+            token_uri,
+            name,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_id() {
+        assert_eq!(parse_token_id("42").unwrap(), BigUint::from(42u32));
+        assert_eq!(parse_token_id("0x2a").unwrap(), BigUint::from(42u32));
+        assert!(parse_token_id("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_block_number() {
+        assert_eq!(parse_block_number("0x10").unwrap(), 16);
+        assert!(parse_block_number("0xzz").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_nfts_lookback() {
+        use primitives::EVMChain;
+        use reef_client::MockClient;
+        use serde_json::Value;
+
+        let mock = MockClient::new()
+            .with_method_response("eth_blockNumber", json!("0x64"))
+            .with_method_response("eth_getLogs", json!([]));
+        let client = EthereumClient::new_with_client(mock.clone(), EVMChain::Arbitrum)
+            .with_nft_logs_lookback(10);
+
+        let collections = client
+            .get_nfts("0x31ca8395cf837de08b24da3f660e77761dfb974b".to_string())
+            .await
+            .unwrap();
+        assert!(collections.is_empty());
+
+        let filters: Vec<Value> = mock
+            .requests()
+            .into_iter()
+            .filter(|(method, _)| method == "eth_getLogs")
+            .map(|(_, params)| params[0].clone())
+            .collect();
+        assert_eq!(filters.len(), 2);
+        assert!(filters
+            .iter()
+            .all(|x| x["fromBlock"] == "0x5a" && x["toBlock"] == "0x64"));
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use num_bigint::BigUint;
+use primitives::{Chain, NFTAsset, NFTCollection, NFTType};
+
+use crate::abi;
+use crate::jsonrpc::{
+    TRANSFER_BATCH_EVENT_TOPIC, TRANSFER_EVENT_TOPIC, TRANSFER_SINGLE_EVENT_TOPIC,
+};
+use crate::models::Log;
+
+/// Token standards an EVM contract can implement, a subset of `NFTType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EvmNftType {
+    ERC721,
+    ERC1155,
+}
+
+impl From<EvmNftType> for NFTType {
+    fn from(token_type: EvmNftType) -> Self {
+        match token_type {
+            EvmNftType::ERC721 => NFTType::ERC721,
+            EvmNftType::ERC1155 => NFTType::ERC1155,
+        }
+    }
+}
+
+/// NFT seen in a transfer log, ownership still has to be verified on-chain
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NftCandidate {
+    pub contract_address: String,
+    pub token_id: BigUint,
+    pub token_type: EvmNftType,
+}
+
+fn map_topic_uint(topic: &str) -> Option<BigUint> {
+    abi::decode_uint256(topic)
+}
+
+/// Extract NFT candidates from ERC-721 `Transfer` and ERC-1155 `TransferSingle`/`TransferBatch` logs
+pub fn map_transfer_logs(logs: &[Log]) -> Vec<NftCandidate> {
+    let mut candidates: Vec<NftCandidate> = Vec::new();
+
+    for log in logs {
+        let Some(topic0) = log.topics.first() else {
+            continue;
+        };
+        let contract_address = log.address.to_lowercase();
+        let topic0 = topic0.to_lowercase();
+
+        let found = if topic0 == TRANSFER_EVENT_TOPIC {
+            // ERC-20 shares the signature but does not index the value
+            if log.topics.len() != 4 {
+                continue;
+            }
+            map_topic_uint(&log.topics[3])
+                .map(|id| vec![(id, EvmNftType::ERC721)])
+                .unwrap_or_default()
+        } else if topic0 == TRANSFER_SINGLE_EVENT_TOPIC {
+            abi::decode_uint256_at(&log.data, 0)
+                .map(|id| vec![(id, EvmNftType::ERC1155)])
+                .unwrap_or_default()
+        } else if topic0 == TRANSFER_BATCH_EVENT_TOPIC {
+            abi::decode_uint256_array_at(&log.data, 0)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|id| (id, EvmNftType::ERC1155))
+                .collect()
+        } else {
+            continue;
+        };
+
+        for (token_id, token_type) in found {
+            let candidate = NftCandidate {
+                contract_address: contract_address.clone(),
+                token_id,
+                token_type,
+            };
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    candidates
+}
+
+/// ERC-1155 clients must replace `{id}` with the lowercase, 64 character hex token id
+pub fn map_token_uri(uri: &str, token_id: &BigUint) -> String {
+    uri.replace("{id}", &abi::encode_uint256(token_id))
+}
+
+pub fn map_nft_asset(
+    chain: Chain,
+    candidate: &NftCandidate,
+    owner: Option<String>,
+    balance: Option<String>,
+    token_uri: Option<String>,
+    name: Option<String>,
+) -> NFTAsset {
+    NFTAsset {
+        chain,
+        contract_address: candidate.contract_address.clone(),
+        token_id: candidate.token_id.to_string(),
+        token_type: candidate.token_type.into(),
+        name,
+        token_uri: token_uri.map(|uri| map_token_uri(&uri, &candidate.token_id)),
+        owner,
+        balance,
+    }
+}
+
+/// Group NFTs by contract, keeping the order in which contracts were first seen
+pub fn map_nft_collections(
+    chain: Chain,
+    nfts: Vec<NFTAsset>,
+    contracts: &HashMap<String, (Option<String>, Option<String>)>,
+) -> Vec<NFTCollection> {
+    let mut collections: Vec<NFTCollection> = Vec::new();
+
+    for nft in nfts {
+        match collections
+            .iter_mut()
+            .find(|x| x.contract_address == nft.contract_address)
+        {
+            Some(collection) => collection.nfts.push(nft),
+            None => {
+                let mut collection = NFTCollection::new(chain, nft.contract_address.clone());
+                if let Some((name, symbol)) = contracts.get(&nft.contract_address) {
+                    collection.name = name.clone();
+                    collection.symbol = symbol.clone();
+                }
+                collection.nfts.push(nft);
+                collections.push(collection);
+            }
+        }
+    }
+
+    collections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER_TOPIC: &str = "0x000000000000000000000000123456789abcdef123456789abcdef123456789a";
+
+    fn log(address: &str, topics: Vec<&str>, data: String) -> Log {
+        Log {
+            address: address.to_string(),
+            topics: topics.into_iter().map(|x| x.to_string()).collect(),
+            data,
+            block_number: "0x1".to_string(),
+            transaction_hash: "0xabc".to_string(),
+            transaction_index: "0x0".to_string(),
+            block_hash: "0xdef".to_string(),
+            log_index: "0x0".to_string(),
+        }
+    }
+
+    fn word(value: u32) -> String {
+        abi::encode_uint256(&BigUint::from(value))
+    }
+
+    #[test]
+    fn test_map_transfer_logs() {
+        let token_id_topic = format!("0x{}", word(42));
+        let logs = vec![
+            // ERC-721 transfer
+            log(
+                "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D",
+                vec![
+                    TRANSFER_EVENT_TOPIC,
+                    OWNER_TOPIC,
+                    OWNER_TOPIC,
+                    &token_id_topic,
+                ],
+                "0x".to_string(),
+            ),
+            // ERC-20 transfer, ignored
+            log(
+                "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                vec![TRANSFER_EVENT_TOPIC, OWNER_TOPIC, OWNER_TOPIC],
+                format!("0x{}", word(1000)),
+            ),
+            // ERC-1155 single
+            log(
+                "0x76BE3b62873462d2142405439777e971754E8E77",
+                vec![
+                    TRANSFER_SINGLE_EVENT_TOPIC,
+                    OWNER_TOPIC,
+                    OWNER_TOPIC,
+                    OWNER_TOPIC,
+                ],
+                format!("0x{}{}", word(7), word(3)),
+            ),
+            // ERC-1155 batch
+            log(
+                "0x76BE3b62873462d2142405439777e971754E8E77",
+                vec![
+                    TRANSFER_BATCH_EVENT_TOPIC,
+                    OWNER_TOPIC,
+                    OWNER_TOPIC,
+                    OWNER_TOPIC,
+                ],
+                format!(
+                    "0x{}",
+                    [
+                        word(0x40),
+                        word(0xa0),
+                        word(2),
+                        word(7),
+                        word(8),
+                        word(2),
+                        word(1),
+                        word(1)
+                    ]
+                    .concat()
+                ),
+            ),
+        ];
+
+        let candidates = map_transfer_logs(&logs);
+
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(
+            candidates[0],
+            NftCandidate {
+                contract_address: "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d".to_string(),
+                token_id: BigUint::from(42u32),
+                token_type: EvmNftType::ERC721,
+            }
+        );
+        assert_eq!(candidates[1].token_id, BigUint::from(7u32));
+        assert_eq!(candidates[1].token_type, EvmNftType::ERC1155);
+        assert_eq!(candidates[2].token_id, BigUint::from(8u32));
+    }
+
+    #[test]
+    fn test_map_token_uri() {
+        assert_eq!(
+            map_token_uri("https://api.example.com/{id}.json", &BigUint::from(314592u32)),
+            "https://api.example.com/000000000000000000000000000000000000000000000000000000000004cce0.json"
+        );
+        assert_eq!(
+            map_token_uri("ipfs://Qm/1", &BigUint::from(1u32)),
+            "ipfs://Qm/1"
+        );
+    }
+
+    #[test]
+    fn test_map_nft_collections() {
+        let candidate = |contract: &str, id: u32| NftCandidate {
+            contract_address: contract.to_string(),
+            token_id: BigUint::from(id),
+            token_type: EvmNftType::ERC721,
+        };
+        let nfts = vec![
+            map_nft_asset(
+                Chain::Ethereum,
+                &candidate("0xa", 1),
+                None,
+                None,
+                None,
+                None,
+            ),
+            map_nft_asset(
+                Chain::Ethereum,
+                &candidate("0xb", 2),
+                None,
+                None,
+                None,
+                None,
+            ),
+            map_nft_asset(
+                Chain::Ethereum,
+                &candidate("0xa", 3),
+                None,
+                None,
+                None,
+                None,
+            ),
+        ];
+        let contracts = HashMap::from([(
+            "0xa".to_string(),
+            (Some("Apes".to_string()), Some("APE".to_string())),
+        )]);
+
+        let collections = map_nft_collections(Chain::Ethereum, nfts, &contracts);
+
+        assert_eq!(collections.len(), 2);
+        assert_eq!(collections[0].contract_address, "0xa");
+        assert_eq!(collections[0].name, Some("Apes".to_string()));
+        assert_eq!(collections[0].nfts.len(), 2);
+        assert_eq!(collections[1].name, None);
+        assert_eq!(collections[1].nfts[0].token_id, "2");
+    }
+}
@@ -5,9 +5,10 @@ use crate::rpc::client::EthereumClient;
 
 use async_trait::async_trait;
 use primitives::{Asset, ChainToken};
+use reef_client::Client;
 
 #[async_trait]
-impl ChainToken for EthereumClient {
+impl<C: Client + Clone> ChainToken for EthereumClient<C> {
     async fn get_token_data(
         &self,
         token_address: String,
@@ -27,11 +28,12 @@ impl ChainToken for EthereumClient {
             ),
         ];
 
-        let results = self.batch_contract_calls(calls).await?;
-
-        if results.len() != 3 {
-            return Err("Failed to get complete token data".into());
-        }
+        let results = self
+            .batch_contract_calls(calls)
+            .await?
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to get token data: {}", e))?;
 
         map_token_data(
             self.get_chain(),
@@ -50,7 +52,55 @@ impl ChainToken for EthereumClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use primitives::{AssetType, Chain, EVMChain};
+    use crate::abi;
+    use crate::jsonrpc::{ERC20_DECIMALS_SELECTOR, ERC20_NAME_SELECTOR, ERC20_SYMBOL_SELECTOR};
+    use crate::models::TransactionObject;
+    use num_bigint::BigUint;
+    use primitives::EVMChain;
+    use reef_client::MockClient;
+    use serde_json::{json, Value};
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn eth_call(data: &str) -> Value {
+        json!([TransactionObject::new_call(USDC, data), "latest"])
+    }
+
+    fn encode_string(value: &str) -> String {
+        format!(
+            "0x{}{}",
+            abi::encode_uint256(&BigUint::from(32u32)),
+            abi::encode_bytes(value.as_bytes())
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_token_data() {
+        let mock = MockClient::new()
+            .with_response(
+                "eth_call",
+                eth_call(ERC20_NAME_SELECTOR),
+                json!(encode_string("USD Coin")),
+            )
+            .with_response(
+                "eth_call",
+                eth_call(ERC20_SYMBOL_SELECTOR),
+                json!(encode_string("USDC")),
+            )
+            .with_response(
+                "eth_call",
+                eth_call(ERC20_DECIMALS_SELECTOR),
+                json!(format!("0x{}", abi::encode_uint256(&BigUint::from(6u32)))),
+            );
+        let client = EthereumClient::new_with_client(mock.clone(), EVMChain::Ethereum);
+
+        let asset = client.get_token_data(USDC.to_string()).await.unwrap();
+
+        assert_eq!(asset.name, "USD Coin");
+        assert_eq!(asset.symbol, "USDC");
+        assert_eq!(asset.decimals, 6);
+        assert_eq!(mock.requests().len(), 3);
+    }
 
     #[test]
     fn test_get_is_token_address() {
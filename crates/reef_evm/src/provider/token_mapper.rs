@@ -1,4 +1,4 @@
-use primitives::{Asset, AssetType, Chain};
+use primitives::{Asset, AssetType, Chain, EVMChain};
 
 pub fn map_token_data(
     chain: Chain,
@@ -18,16 +18,9 @@ pub fn map_token_data(
     let decimals = decode_uint_result(&decimals_hex).unwrap_or(18);
 
     // Determine asset type based on chain
-    let asset_type = match chain {
-        Chain::Ethereum => AssetType::ERC20,
-        Chain::SmartChain => AssetType::BEP20,
-        Chain::Polygon => AssetType::ERC20,
-        // Chain::Arbitrum => AssetType::ERC20,
-        // Chain::Optimism => AssetType::ERC20,
-        // Chain::Base => AssetType::ERC20,
-        // Chain::AvalancheC => AssetType::ERC20,
-        _ => AssetType::TOKEN,
-    };
+    let asset_type = EVMChain::from_chain(chain)
+        .map(|chain| chain.asset_type())
+        .unwrap_or(AssetType::TOKEN);
 
     Ok(Asset::new(
         name,
@@ -123,11 +116,19 @@ mod tests {
     fn test_decode_string_result() {
         let hex = "0x0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000855534420436f696e000000000000000000000000000000000000000000000000";
         assert_eq!(decode_string_result(hex), Some("USD Coin".to_string()));
+
+        assert_eq!(decode_string_result("0x"), None);
+        assert_eq!(decode_string_result("0x123"), None);
     }
 
     #[test]
     fn test_decode_uint_result() {
         let hex = "0x0000000000000000000000000000000000000000000000000000000000000006";
         assert_eq!(decode_uint_result(hex), Some(6));
+
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000012";
+        assert_eq!(decode_uint_result(hex), Some(18));
+
+        assert_eq!(decode_uint_result("0x"), None);
     }
 }
@@ -2,17 +2,23 @@ use crate::jsonrpc::{
     encode_balance_of_call, ERC20_DECIMALS_SELECTOR, ERC20_NAME_SELECTOR, ERC20_SYMBOL_SELECTOR,
 };
 use crate::models::{
-     FeeHistory, Transaction, TransactionObject, TransactionReceipt,
+    FeeHistory, Log, LogFilter, Transaction, TransactionObject, TransactionReceipt,
 };
+use crate::provider::nft::DEFAULT_NFT_LOGS_LOOKBACK_BLOCKS;
 use primitives::{Chain, EVMChain};
-use reef_jsonrpc::JsonRpcClient;
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use reef_jsonrpc::{
+    EndpointConfig, JsonRpcCache, JsonRpcClient, JsonRpcError, RateLimitConfig, RoutingStrategy,
+};
 use serde_json::json;
 use std::error::Error;
 
-pub struct EthereumClient {
+pub struct EthereumClient<C: Client + Clone = ReqwestClient> {
     pub chain: EVMChain,
     pub rpc_url: String,
-    pub client: JsonRpcClient<reef_client::ReqwestClient>,
+    pub client: JsonRpcClient<C>,
+    /// Blocks of transfer logs scanned by `get_nfts`, see `with_nft_logs_lookback`
+    pub nft_logs_lookback: u64,
 }
 
 impl EthereumClient {
@@ -22,9 +28,53 @@ impl EthereumClient {
             chain,
             rpc_url,
             client,
+            nft_logs_lookback: DEFAULT_NFT_LOGS_LOOKBACK_BLOCKS,
         }
     }
 
+    /// Failover across several nodes, `rpc_url` is the first endpoint
+    pub fn new_with_endpoints(
+        endpoints: Vec<EndpointConfig>,
+        strategy: RoutingStrategy,
+        rate_limit: Option<RateLimitConfig>,
+        config: &ClientConfig,
+        chain: EVMChain,
+    ) -> Result<Self, ClientError> {
+        let rpc_url = endpoints.first().map(|x| x.url.clone()).unwrap_or_default();
+        let client = JsonRpcClient::new_reqwest_endpoints(endpoints, strategy, rate_limit, config)?;
+        Ok(Self {
+            chain,
+            rpc_url,
+            client,
+            nft_logs_lookback: DEFAULT_NFT_LOGS_LOOKBACK_BLOCKS,
+        })
+    }
+}
+
+impl<C: Client + Clone> EthereumClient<C> {
+    /// Any transport, e.g. a `reef_client::MockClient` in tests
+    pub fn new_with_client(client: C, chain: EVMChain) -> Self {
+        Self {
+            chain,
+            rpc_url: client.base_url().to_string(),
+            client: JsonRpcClient::new(client),
+            nft_logs_lookback: DEFAULT_NFT_LOGS_LOOKBACK_BLOCKS,
+        }
+    }
+
+    /// Cache results by method, see `reef_jsonrpc::CachePolicy`
+    pub fn with_cache(mut self, cache: JsonRpcCache) -> Self {
+        self.client = self.client.with_cache(cache);
+        self
+    }
+
+    /// The default covers about two weeks on Ethereum but far less on chains with
+    /// faster blocks, NFTs last transferred before the window are not found
+    pub fn with_nft_logs_lookback(mut self, blocks: u64) -> Self {
+        self.nft_logs_lookback = blocks;
+        self
+    }
+
     pub fn get_chain(&self) -> Chain {
         self.chain.to_chain()
     }
@@ -181,6 +231,19 @@ impl EthereumClient {
         Ok(fee_history)
     }
 
+    pub async fn get_logs(
+        &self,
+        filter: &LogFilter,
+    ) -> Result<Vec<Log>, Box<dyn Error + Send + Sync>> {
+        let params = json!([filter]);
+        let logs: Vec<Log> = self
+            .client
+            .call("eth_getLogs", params)
+            .await
+            .map_err(|e| format!("Failed to get logs: {}", e))?;
+        Ok(logs)
+    }
+
     /// Get ERC-20 token balance
     pub async fn get_token_balance(
         &self,
@@ -217,11 +280,11 @@ impl EthereumClient {
             .await
     }
 
-    /// Batch call multiple contracts
+    /// Batch call multiple contracts, one result per call in order
     pub async fn batch_contract_calls(
         &self,
         calls: Vec<(String, String)>,
-    ) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+    ) -> Result<Vec<Result<String, JsonRpcError>>, Box<dyn Error + Send + Sync>> {
         let rpc_calls: Vec<(String, serde_json::Value)> = calls
             .iter()
             .map(|(to, data)| {
@@ -230,7 +293,6 @@ impl EthereumClient {
             })
             .collect();
 
-        let results = self.client.batch_call::<String>(rpc_calls).await?;
-        Ok(results.extract())
+        Ok(self.client.batch_call::<String>(rpc_calls).await?)
     }
 }
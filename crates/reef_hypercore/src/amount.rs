@@ -0,0 +1,33 @@
+/// 十进制字符串换算为有符号整数，超出精度的部分截断
+pub fn parse_decimal(value: &str, decimals: u8) -> Option<i128> {
+    let (negative, value) = match value.trim().strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.trim()),
+    };
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let fraction: String = fraction
+        .chars()
+        .chain(std::iter::repeat('0'))
+        .take(decimals as usize)
+        .collect();
+    let digits = format!("{}{}", integer, fraction);
+    if digits.is_empty() || !digits.chars().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    let amount = digits.parse::<i128>().ok()?;
+    Some(if negative { -amount } else { amount })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("14.625485", 6), Some(14_625_485));
+        assert_eq!(parse_decimal("-0.0134", 6), Some(-13_400));
+        assert_eq!(parse_decimal("2986.3", 2), Some(298_630));
+        assert_eq!(parse_decimal("0.123456789", 8), Some(12_345_678));
+        assert_eq!(parse_decimal("1e5", 6), None);
+    }
+}
@@ -0,0 +1,20 @@
+pub mod amount;
+pub mod models;
+pub mod provider;
+pub mod rpc;
+
+pub const HYPE_COIN: &str = "HYPE";
+/// 现货余额统一按 8 位小数换算
+pub const SPOT_DECIMALS: u8 = 8;
+/// 合约保证金与盈亏以 USDC 计
+pub const USD_DECIMALS: u8 = 6;
+
+/// 资产标识前缀，例如 `perpetual::ETH`、`spot::PURR`
+pub const PERPETUAL_PREFIX: &str = "perpetual::";
+pub const SPOT_PREFIX: &str = "spot::";
+/// 合约账户中未占用的 USDC 保证金
+pub const PERPETUAL_USDC: &str = "perpetual::USDC";
+
+// Re-export for convenience
+pub use models::*;
+pub use rpc::HyperCoreClient;
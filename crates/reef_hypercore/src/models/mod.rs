@@ -0,0 +1,5 @@
+pub mod perpetual;
+pub mod spot;
+
+pub use perpetual::*;
+pub use spot::*;
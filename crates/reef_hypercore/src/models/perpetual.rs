@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::amount::parse_decimal;
+use crate::USD_DECIMALS;
+
+/// `clearinghouseState`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClearinghouseState {
+    pub asset_positions: Vec<AssetPosition>,
+    pub margin_summary: MarginSummary,
+    /// 可提取的 USDC
+    pub withdrawable: String,
+}
+
+impl ClearinghouseState {
+    /// 账户价值扣除各仓位权益后的 USDC，单位 USDC 最小精度
+    pub fn free_collateral(&self) -> u128 {
+        let account_value =
+            parse_decimal(&self.margin_summary.account_value, USD_DECIMALS).unwrap_or_default();
+        let equity: i128 = self
+            .asset_positions
+            .iter()
+            .map(|x| PerpetualPosition::from(x.position.clone()).equity() as i128)
+            .sum();
+        (account_value - equity).max(0) as u128
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginSummary {
+    pub account_value: String,
+    pub total_margin_used: String,
+    pub total_ntl_pos: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetPosition {
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub coin: String,
+    /// 带符号的仓位大小，空头为负
+    pub szi: String,
+    pub entry_px: Option<String>,
+    pub leverage: Leverage,
+    pub liquidation_px: Option<String>,
+    pub margin_used: String,
+    pub position_value: String,
+    pub unrealized_pnl: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leverage {
+    /// `cross` 或 `isolated`
+    #[serde(rename = "type")]
+    pub leverage_type: String,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PositionDirection {
+    Long,
+    Short,
+}
+
+/// 合约持仓
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerpetualPosition {
+    pub coin: String,
+    pub direction: PositionDirection,
+    /// 仓位大小的绝对值
+    pub size: String,
+    pub entry_price: Option<String>,
+    pub liquidation_price: Option<String>,
+    pub position_value: String,
+    pub unrealized_pnl: String,
+    pub leverage: u32,
+    pub leverage_type: String,
+    pub margin_used: String,
+}
+
+impl PerpetualPosition {
+    /// 仓位权益：保证金加未实现盈亏，单位 USDC 最小精度，亏穿时为 0
+    pub fn equity(&self) -> u128 {
+        let margin = parse_decimal(&self.margin_used, USD_DECIMALS).unwrap_or_default();
+        let pnl = parse_decimal(&self.unrealized_pnl, USD_DECIMALS).unwrap_or_default();
+        (margin + pnl).max(0) as u128
+    }
+}
+
+impl From<Position> for PerpetualPosition {
+    fn from(position: Position) -> Self {
+        let (direction, size) = match position.szi.strip_prefix('-') {
+            Some(size) => (PositionDirection::Short, size.to_string()),
+            None => (PositionDirection::Long, position.szi),
+        };
+        Self {
+            coin: position.coin,
+            direction,
+            size,
+            entry_price: position.entry_px,
+            liquidation_price: position.liquidation_px,
+            position_value: position.position_value,
+            unrealized_pnl: position.unrealized_pnl,
+            leverage: position.leverage.value,
+            leverage_type: position.leverage.leverage_type,
+            margin_used: position.margin_used,
+        }
+    }
+}
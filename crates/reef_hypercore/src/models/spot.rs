@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// `spotClearinghouseState`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpotClearinghouseState {
+    pub balances: Vec<SpotBalance>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotBalance {
+    pub coin: String,
+    /// 代币索引
+    pub token: u32,
+    /// 挂单冻结的部分
+    pub hold: String,
+    pub total: String,
+}
@@ -0,0 +1,218 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{AssetBalance, Balance, Chain, ChainBalances};
+use reef_client::Client;
+
+use crate::amount::parse_decimal;
+use crate::models::{PerpetualPosition, SpotBalance};
+use crate::rpc::client::HyperCoreClient;
+use crate::{
+    HYPE_COIN, PERPETUAL_PREFIX, PERPETUAL_USDC, SPOT_DECIMALS, SPOT_PREFIX, USD_DECIMALS,
+};
+
+fn spot_amount(balance: &SpotBalance) -> BigUint {
+    let total = parse_decimal(&balance.total, SPOT_DECIMALS).unwrap_or_default();
+    BigUint::from(total.max(0) as u128)
+}
+
+fn map_spot_balance(balance: &SpotBalance) -> AssetBalance {
+    AssetBalance::new_token(
+        Chain::HyperCore,
+        Some(format!("{}{}", SPOT_PREFIX, balance.coin)),
+        spot_amount(balance),
+        SPOT_DECIMALS,
+    )
+}
+
+/// 持仓按仓位权益计入资产，详情见 `HyperCoreClient::get_positions`
+fn map_position(position: &PerpetualPosition) -> AssetBalance {
+    AssetBalance::new_token(
+        Chain::HyperCore,
+        Some(format!("{}{}", PERPETUAL_PREFIX, position.coin)),
+        BigUint::from(position.equity()),
+        USD_DECIMALS,
+    )
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for HyperCoreClient<C> {
+    /// 现货账户中的 HYPE
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let balances = self.get_spot_balances(&address).await?;
+        let hype = balances.iter().find(|x| x.coin == HYPE_COIN);
+        Ok(AssetBalance {
+            chain: Chain::HyperCore,
+            contract_address: None,
+            balance: Balance::coin_balance(
+                hype.map(spot_amount).unwrap_or_default(),
+                SPOT_DECIMALS,
+            ),
+            is_active: Some(!balances.is_empty()),
+        })
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let balances = self.get_assets_balances(address).await?;
+        Ok(balances
+            .into_iter()
+            .filter(|x| {
+                x.contract_address
+                    .as_ref()
+                    .is_some_and(|x| token_addresses.contains(x))
+            })
+            .collect())
+    }
+
+    /// 除 HYPE 外的现货余额、合约账户的空闲 USDC 与所有合约持仓
+    async fn get_assets_balances(
+        &self,
+        address: String,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        let spot = self.get_spot_balances(&address).await?;
+        let state = self.get_clearinghouse_state(&address).await?;
+        let collateral = state.free_collateral();
+        let positions: Vec<PerpetualPosition> = state
+            .asset_positions
+            .into_iter()
+            .map(|x| x.position.into())
+            .collect();
+        Ok(spot
+            .iter()
+            .filter(|x| x.coin != HYPE_COIN)
+            .map(map_spot_balance)
+            .chain((collateral > 0).then(|| {
+                AssetBalance::new_token(
+                    Chain::HyperCore,
+                    Some(PERPETUAL_USDC.to_string()),
+                    BigUint::from(collateral),
+                    USD_DECIMALS,
+                )
+            }))
+            .chain(positions.iter().map(map_position))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const USER: &str = "0x31ca8395cf837de08b24da3f660e77761dfb974b";
+
+    fn spot_state() -> serde_json::Value {
+        json!({
+            "balances": [
+                { "coin": "USDC", "token": 0, "hold": "0.0", "total": "14.625485", "entryNtl": "0.0" },
+                { "coin": "HYPE", "token": 150, "hold": "0.0", "total": "2.5", "entryNtl": "62.5" }
+            ]
+        })
+    }
+
+    fn client() -> HyperCoreClient<MockClient> {
+        let mock = MockClient::new()
+            .with_post(
+                "/info",
+                json!({ "type": "spotClearinghouseState", "user": USER }),
+                spot_state(),
+            )
+            .with_post(
+                "/info",
+                json!({ "type": "clearinghouseState", "user": USER }),
+                json!({
+                    "assetPositions": [{
+                        "position": {
+                            "coin": "BTC",
+                            "entryPx": "60000.0",
+                            "leverage": { "type": "cross", "value": 10 },
+                            "liquidationPx": null,
+                            "marginUsed": "600.0",
+                            "positionValue": "6150.0",
+                            "szi": "0.1",
+                            "unrealizedPnl": "150.0"
+                        },
+                        "type": "oneWay"
+                    }],
+                    "marginSummary": { "accountValue": "1000.0", "totalMarginUsed": "600.0", "totalNtlPos": "6150.0", "totalRawUsd": "-5150.0" },
+                    "withdrawable": "250.0"
+                }),
+            );
+        HyperCoreClient::new_with_client(mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let balance = client().get_balance_coin(USER.to_string()).await.unwrap();
+        assert_eq!(balance.chain, Chain::HyperCore);
+        assert_eq!(balance.balance.amount, "250000000");
+        assert_eq!(balance.balance.decimals, 8);
+    }
+
+    #[tokio::test]
+    async fn test_get_assets_balances() {
+        let client = client();
+        let balances = client.get_assets_balances(USER.to_string()).await.unwrap();
+        assert_eq!(balances.len(), 3);
+        assert_eq!(balances[0].contract_address.as_deref(), Some("spot::USDC"));
+        assert_eq!(balances[0].balance.amount, "1462548500");
+        // 1000 账户价值 - 750 仓位权益
+        assert_eq!(
+            balances[1].contract_address.as_deref(),
+            Some("perpetual::USDC")
+        );
+        assert_eq!(balances[1].balance.amount, "250000000");
+        assert_eq!(balances[1].balance.decimals, 6);
+        assert_eq!(
+            balances[2].contract_address.as_deref(),
+            Some("perpetual::BTC")
+        );
+        // 600 保证金 + 150 未实现盈亏
+        assert_eq!(balances[2].balance.amount, "750000000");
+        assert_eq!(balances[2].balance.decimals, 6);
+
+        let balances = client
+            .get_balance_tokens(USER.to_string(), vec!["perpetual::BTC".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_assets_balances_without_positions() {
+        let mock = MockClient::new()
+            .with_post(
+                "/info",
+                json!({ "type": "spotClearinghouseState", "user": USER }),
+                spot_state(),
+            )
+            .with_post(
+                "/info",
+                json!({ "type": "clearinghouseState", "user": USER }),
+                json!({
+                    "assetPositions": [],
+                    "marginSummary": { "accountValue": "320.5", "totalMarginUsed": "0.0", "totalNtlPos": "0.0", "totalRawUsd": "320.5" },
+                    "withdrawable": "320.5"
+                }),
+            );
+        let balances = HyperCoreClient::new_with_client(mock)
+            .get_assets_balances(USER.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 2);
+        assert_eq!(
+            balances[1].contract_address.as_deref(),
+            Some("perpetual::USDC")
+        );
+        assert_eq!(balances[1].balance.amount, "320500000");
+    }
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+use crate::{
+    rpc::client::HyperCoreClient, PERPETUAL_PREFIX, PERPETUAL_USDC, SPOT_DECIMALS, SPOT_PREFIX,
+    USD_DECIMALS,
+};
+use primitives::{Asset, AssetType, Chain, ChainToken};
+use reef_client::Client;
+
+/// 合约为 PERPETUAL，按 USDC 计价；现货与合约账户的 USDC 为 TOKEN
+pub fn map_asset(asset_id: &str) -> Option<Asset> {
+    if asset_id == PERPETUAL_USDC {
+        return Some(Asset::new(
+            "USDC Perpetual Margin".to_string(),
+            "USDC".to_string(),
+            USD_DECIMALS as i32,
+            Chain::HyperCore,
+            Some(asset_id.to_string()),
+            AssetType::TOKEN,
+        ));
+    }
+    if let Some(coin) = asset_id.strip_prefix(PERPETUAL_PREFIX) {
+        return (!coin.is_empty()).then(|| {
+            Asset::new(
+                format!("{} Perpetual", coin),
+                coin.to_string(),
+                USD_DECIMALS as i32,
+                Chain::HyperCore,
+                Some(asset_id.to_string()),
+                AssetType::PERPETUAL,
+            )
+        });
+    }
+    let coin = asset_id
+        .strip_prefix(SPOT_PREFIX)
+        .filter(|x| !x.is_empty())?;
+    Some(Asset::new(
+        coin.to_string(),
+        coin.to_string(),
+        SPOT_DECIMALS as i32,
+        Chain::HyperCore,
+        Some(asset_id.to_string()),
+        AssetType::TOKEN,
+    ))
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for HyperCoreClient<C> {
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        Ok(map_asset(&token_address).ok_or_else(|| format!("Unknown asset: {}", token_address))?)
+    }
+
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        map_asset(token_address).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_asset() {
+        let asset = map_asset("perpetual::ETH").unwrap();
+        assert_eq!(asset.name, "ETH Perpetual");
+        assert_eq!(asset.symbol, "ETH");
+        assert_eq!(asset.asset_type, AssetType::PERPETUAL);
+
+        let asset = map_asset("spot::PURR").unwrap();
+        assert_eq!(asset.asset_type, AssetType::TOKEN);
+        assert_eq!(asset.decimals, 8);
+
+        let asset = map_asset("perpetual::USDC").unwrap();
+        assert_eq!(asset.asset_type, AssetType::TOKEN);
+        assert_eq!(asset.decimals, 6);
+
+        assert!(map_asset("perpetual::").is_none());
+        assert!(map_asset("ETH").is_none());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::models::{ClearinghouseState, PerpetualPosition, SpotBalance, SpotClearinghouseState};
+use primitives::Chain;
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::error::Error;
+
+/// Hyperliquid info API 客户端
+#[derive(Debug, Clone)]
+pub struct HyperCoreClient<C: Client + Clone = ReqwestClient> {
+    client: C,
+}
+
+impl HyperCoreClient {
+    pub fn new(url: String) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url))
+    }
+
+    pub fn new_with_config(url: String, config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(ReqwestClient::new_with_config(
+            url, config,
+        )?))
+    }
+}
+
+impl<C: Client + Clone> HyperCoreClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C) -> Self {
+        Self { client }
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        Chain::HyperCore
+    }
+
+    /// 与 HyperEVM 共用 EVM 地址
+    pub fn validate_address(&self, address: &str) -> Result<(), String> {
+        let hex = address
+            .strip_prefix("0x")
+            .ok_or_else(|| format!("Invalid address {}: missing 0x prefix", address))?;
+        if hex.len() != 40 || !hex.chars().all(|x| x.is_ascii_hexdigit()) {
+            return Err(format!("Invalid address {}", address));
+        }
+        Ok(())
+    }
+
+    async fn info<T: DeserializeOwned>(
+        &self,
+        request: Value,
+    ) -> Result<T, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .client
+            .post("/info", &request, None)
+            .await
+            .map_err(|e| format!("Failed to query {}: {}", request["type"], e))?)
+    }
+
+    pub async fn get_clearinghouse_state(
+        &self,
+        user: &str,
+    ) -> Result<ClearinghouseState, Box<dyn Error + Send + Sync>> {
+        self.info(json!({ "type": "clearinghouseState", "user": user }))
+            .await
+    }
+
+    pub async fn get_spot_clearinghouse_state(
+        &self,
+        user: &str,
+    ) -> Result<SpotClearinghouseState, Box<dyn Error + Send + Sync>> {
+        self.info(json!({ "type": "spotClearinghouseState", "user": user }))
+            .await
+    }
+
+    /// 所有合约持仓
+    pub async fn get_positions(
+        &self,
+        user: &str,
+    ) -> Result<Vec<PerpetualPosition>, Box<dyn Error + Send + Sync>> {
+        let state = self.get_clearinghouse_state(user).await?;
+        Ok(state
+            .asset_positions
+            .into_iter()
+            .map(|x| x.position.into())
+            .collect())
+    }
+
+    pub async fn get_spot_balances(
+        &self,
+        user: &str,
+    ) -> Result<Vec<SpotBalance>, Box<dyn Error + Send + Sync>> {
+        Ok(self.get_spot_clearinghouse_state(user).await?.balances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PositionDirection;
+    use reef_client::MockClient;
+
+    const USER: &str = "0x31ca8395cf837de08b24da3f660e77761dfb974b";
+
+    #[tokio::test]
+    async fn test_get_positions() {
+        let mock = MockClient::new().with_post(
+            "/info",
+            json!({ "type": "clearinghouseState", "user": USER }),
+            json!({
+                "assetPositions": [{
+                    "position": {
+                        "coin": "ETH",
+                        "cumFunding": { "allTime": "514.085417", "sinceChange": "0.0", "sinceOpen": "0.0" },
+                        "entryPx": "2986.3",
+                        "leverage": { "rawUsd": "-95.059824", "type": "isolated", "value": 20 },
+                        "liquidationPx": "2866.26936529",
+                        "marginUsed": "4.967826",
+                        "maxLeverage": 50,
+                        "positionValue": "100.02765",
+                        "returnOnEquity": "-0.0026789",
+                        "szi": "-0.0335",
+                        "unrealizedPnl": "-0.0134"
+                    },
+                    "type": "oneWay"
+                }],
+                "crossMaintenanceMarginUsed": "0.0",
+                "crossMarginSummary": { "accountValue": "13104.514502", "totalMarginUsed": "0.0", "totalNtlPos": "0.0", "totalRawUsd": "13104.514502" },
+                "marginSummary": { "accountValue": "13109.482328", "totalMarginUsed": "4.967826", "totalNtlPos": "100.02765", "totalRawUsd": "13009.454678" },
+                "time": 1708622398623u64,
+                "withdrawable": "13104.514502"
+            }),
+        );
+        let client = HyperCoreClient::new_with_client(mock);
+
+        let positions = client.get_positions(USER).await.unwrap();
+        assert_eq!(positions.len(), 1);
+        let position = &positions[0];
+        assert_eq!(position.coin, "ETH");
+        assert_eq!(position.direction, PositionDirection::Short);
+        assert_eq!(position.size, "0.0335");
+        assert_eq!(position.entry_price.as_deref(), Some("2986.3"));
+        assert_eq!(position.leverage, 20);
+        assert_eq!(position.leverage_type, "isolated");
+        assert_eq!(position.equity(), 4_954_426);
+    }
+
+    #[test]
+    fn test_validate_address() {
+        let client = HyperCoreClient::new_with_client(MockClient::new());
+        assert!(client.validate_address(USER).is_ok());
+        assert!(client.validate_address(&USER[2..]).is_err());
+        assert!(client.validate_address("0x1234").is_err());
+    }
+}
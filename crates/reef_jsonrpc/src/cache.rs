@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use lru::LruCache;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use serde_json::Value;
+use tokio::sync::OnceCell;
+
+use crate::retry::is_idempotent;
+use crate::types::JsonRpcError;
+
+pub const DEFAULT_CACHE_CAPACITY: usize = 10_000;
+
+/// Token name, symbol, decimals and other values that practically never change
+pub const METADATA_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+pub const BALANCE_TTL: Duration = Duration::from_secs(5);
+/// Chain head values, mostly useful to coalesce bursts of identical calls
+pub const HEAD_TTL: Duration = Duration::from_secs(1);
+
+/// ERC-20/721 `name()`, `symbol()` and `decimals()`
+const METADATA_SELECTORS: &[&str] = &["0x06fdde03", "0x95d89b41", "0x313ce567"];
+const BALANCE_OF_SELECTOR: &str = "0x70a08231";
+
+const REDIS_KEY_PREFIX: &str = "jsonrpc";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheTtl {
+    Never,
+    For(Duration),
+    Forever,
+}
+
+impl CacheTtl {
+    /// `max-age` sent in the `Cache-Control` header, a year for values that never change
+    pub fn max_age(&self) -> Option<u64> {
+        match self {
+            CacheTtl::Never => None,
+            CacheTtl::For(ttl) => Some(ttl.as_secs()),
+            CacheTtl::Forever => Some(365 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// How long a method's result may be cached, methods not listed are never cached
+#[derive(Debug, Clone)]
+pub struct CachePolicy {
+    methods: HashMap<String, CacheTtl>,
+    /// `eth_call` by 4-byte selector of the call data
+    calls: HashMap<String, CacheTtl>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        let forever = ["eth_chainId", "net_version", "getGenesisHash"];
+        let metadata = ["getAsset"];
+        let balances = [
+            "eth_getBalance",
+            "getBalance",
+            "getTokenAccountsByOwner",
+            "getTokenAccountBalance",
+        ];
+        let head = [
+            "eth_blockNumber",
+            "eth_gasPrice",
+            "eth_feeHistory",
+            "getSlot",
+        ];
+
+        let methods = forever
+            .iter()
+            .map(|x| (x, CacheTtl::Forever))
+            .chain(metadata.iter().map(|x| (x, CacheTtl::For(METADATA_TTL))))
+            .chain(balances.iter().map(|x| (x, CacheTtl::For(BALANCE_TTL))))
+            .chain(head.iter().map(|x| (x, CacheTtl::For(HEAD_TTL))))
+            .map(|(method, ttl)| (method.to_string(), ttl))
+            .collect();
+        let calls = METADATA_SELECTORS
+            .iter()
+            .map(|x| (x.to_string(), CacheTtl::For(METADATA_TTL)))
+            .chain([(BALANCE_OF_SELECTOR.to_string(), CacheTtl::For(BALANCE_TTL))])
+            .collect();
+
+        Self { methods, calls }
+    }
+}
+
+impl CachePolicy {
+    pub fn with_method(mut self, method: &str, ttl: CacheTtl) -> Self {
+        self.methods.insert(method.to_string(), ttl);
+        self
+    }
+
+    pub fn with_call(mut self, selector: &str, ttl: CacheTtl) -> Self {
+        self.calls.insert(selector.to_lowercase(), ttl);
+        self
+    }
+
+    pub fn ttl(&self, method: &str, params: &Value) -> CacheTtl {
+        // a cached send would silently drop the transaction
+        if !is_idempotent(method) {
+            return CacheTtl::Never;
+        }
+        if method == "eth_call" {
+            return Self::call_selector(params)
+                .and_then(|x| self.calls.get(&x).copied())
+                .unwrap_or(CacheTtl::Never);
+        }
+        self.methods.get(method).copied().unwrap_or(CacheTtl::Never)
+    }
+
+    fn call_selector(params: &Value) -> Option<String> {
+        let data = params.get(0)?;
+        let data = data.get("data").or_else(|| data.get("input"))?.as_str()?;
+        data.get(..10).map(|x| x.to_lowercase())
+    }
+}
+
+/// Shared second-level store, e.g. Redis
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    async fn get(&self, key: &str) -> Option<String>;
+    async fn set(&self, key: &str, value: String, ttl: CacheTtl);
+}
+
+#[derive(Clone)]
+pub struct RedisCache {
+    connection: ConnectionManager,
+}
+
+impl RedisCache {
+    pub async fn new(url: &str) -> Result<Self, redis::RedisError> {
+        let client = redis::Client::open(url)?;
+        let connection = ConnectionManager::new(client).await?;
+        Ok(Self { connection })
+    }
+}
+
+/// Cache errors are not fatal, the request just goes to the node
+#[async_trait]
+impl CacheStore for RedisCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        let mut connection = self.connection.clone();
+        connection.get(key).await.ok().flatten()
+    }
+
+    async fn set(&self, key: &str, value: String, ttl: CacheTtl) {
+        let mut connection = self.connection.clone();
+        let _: Result<(), _> = match ttl {
+            CacheTtl::Never => return,
+            CacheTtl::For(ttl) => connection.set_ex(key, value, ttl.as_secs().max(1)).await,
+            CacheTtl::Forever => connection.set(key, value).await,
+        };
+    }
+}
+
+struct MemoryEntry {
+    value: Value,
+    expires_at: Option<Instant>,
+}
+
+type Inflight = Arc<OnceCell<Result<Value, JsonRpcError>>>;
+
+/// In-memory LRU in front of an optional shared store, keyed per chain
+pub struct JsonRpcCache {
+    namespace: String,
+    policy: CachePolicy,
+    memory: Mutex<LruCache<String, MemoryEntry>>,
+    store: Option<Arc<dyn CacheStore>>,
+    inflight: Mutex<HashMap<String, Inflight>>,
+}
+
+impl std::fmt::Debug for JsonRpcCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonRpcCache")
+            .field("namespace", &self.namespace)
+            .field("shared_store", &self.store.is_some())
+            .finish()
+    }
+}
+
+impl JsonRpcCache {
+    /// `namespace` separates chains sharing one store
+    pub fn new(namespace: &str, capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            namespace: namespace.to_string(),
+            policy: CachePolicy::default(),
+            memory: Mutex::new(LruCache::new(capacity)),
+            store: None,
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_policy(mut self, policy: CachePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn with_store(mut self, store: Option<Arc<dyn CacheStore>>) -> Self {
+        self.store = store;
+        self
+    }
+
+    pub fn ttl(&self, method: &str, params: &Value) -> CacheTtl {
+        self.policy.ttl(method, params)
+    }
+
+    fn key(&self, method: &str, params: &Value) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            REDIS_KEY_PREFIX, self.namespace, method, params
+        )
+    }
+
+    pub async fn get(&self, method: &str, params: &Value) -> Option<Value> {
+        if self.ttl(method, params) == CacheTtl::Never {
+            return None;
+        }
+        self.get_key(&self.key(method, params)).await
+    }
+
+    pub async fn set(&self, method: &str, params: &Value, value: &Value) {
+        let ttl = self.ttl(method, params);
+        self.set_key(&self.key(method, params), value, ttl).await
+    }
+
+    async fn get_key(&self, key: &str) -> Option<Value> {
+        {
+            let mut memory = self.memory.lock().unwrap();
+            match memory.get(key) {
+                Some(entry) if entry.expires_at.is_none_or(|x| x > Instant::now()) => {
+                    return Some(entry.value.clone())
+                }
+                Some(_) => {
+                    memory.pop(key);
+                }
+                None => {}
+            }
+        }
+
+        let store = self.store.as_ref()?;
+        let value: Value = serde_json::from_str(&store.get(key).await?).ok()?;
+        // the shared store does not expose the remaining ttl, keep a short local copy
+        self.set_memory(key, &value, CacheTtl::For(BALANCE_TTL));
+        Some(value)
+    }
+
+    fn set_memory(&self, key: &str, value: &Value, ttl: CacheTtl) {
+        let expires_at = match ttl {
+            CacheTtl::Never => return,
+            CacheTtl::For(ttl) => Some(Instant::now() + ttl),
+            CacheTtl::Forever => None,
+        };
+        self.memory.lock().unwrap().put(
+            key.to_string(),
+            MemoryEntry {
+                value: value.clone(),
+                expires_at,
+            },
+        );
+    }
+
+    async fn set_key(&self, key: &str, value: &Value, ttl: CacheTtl) {
+        // empty results, e.g. a receipt that is not mined yet, are not worth keeping
+        if ttl == CacheTtl::Never || value.is_null() {
+            return;
+        }
+        self.set_memory(key, value, ttl);
+        if let Some(store) = &self.store {
+            store.set(key, value.to_string(), ttl).await;
+        }
+    }
+
+    /// Cached result or `fetch`, concurrent identical calls share one upstream request
+    pub async fn get_or_fetch<F, Fut>(
+        &self,
+        method: &str,
+        params: &Value,
+        fetch: F,
+    ) -> Result<Value, JsonRpcError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Value, JsonRpcError>>,
+    {
+        let ttl = self.ttl(method, params);
+        if ttl == CacheTtl::Never {
+            return fetch().await;
+        }
+        let key = self.key(method, params);
+        if let Some(value) = self.get_key(&key).await {
+            return Ok(value);
+        }
+
+        let cell = self
+            .inflight
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let result = cell
+            .get_or_init(|| async {
+                let result = fetch().await;
+                if let Ok(value) = &result {
+                    self.set_key(&key, value, ttl).await;
+                }
+                result
+            })
+            .await
+            .clone();
+
+        let mut inflight = self.inflight.lock().unwrap();
+        if inflight.get(&key).is_some_and(|x| Arc::ptr_eq(x, &cell)) {
+            inflight.remove(&key);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct FakeStore {
+        values: Mutex<HashMap<String, String>>,
+    }
+
+    #[async_trait]
+    impl CacheStore for FakeStore {
+        async fn get(&self, key: &str) -> Option<String> {
+            self.values.lock().unwrap().get(key).cloned()
+        }
+
+        async fn set(&self, key: &str, value: String, _ttl: CacheTtl) {
+            self.values.lock().unwrap().insert(key.to_string(), value);
+        }
+    }
+
+    fn eth_call(data: &str) -> Value {
+        json!([{ "to": "0xdac17f958d2ee523a2206206994597c13d831ec7", "data": data }, "latest"])
+    }
+
+    #[test]
+    fn test_policy() {
+        let policy = CachePolicy::default();
+
+        assert_eq!(policy.ttl("eth_chainId", &json!([])), CacheTtl::Forever);
+        assert_eq!(
+            policy.ttl("eth_getBalance", &json!(["0x0", "latest"])),
+            CacheTtl::For(BALANCE_TTL)
+        );
+        assert_eq!(
+            policy.ttl("eth_call", &eth_call("0x313ce567")),
+            CacheTtl::For(METADATA_TTL)
+        );
+        assert_eq!(
+            policy.ttl("eth_call", &eth_call("0x70A08231000000")),
+            CacheTtl::For(BALANCE_TTL)
+        );
+        assert_eq!(
+            policy.ttl("eth_call", &eth_call("0xa9059cbb")),
+            CacheTtl::Never
+        );
+        assert_eq!(
+            policy.ttl("eth_getTransactionReceipt", &json!(["0x0"])),
+            CacheTtl::Never
+        );
+
+        let policy = policy.with_method("eth_sendRawTransaction", CacheTtl::Forever);
+        assert_eq!(
+            policy.ttl("eth_sendRawTransaction", &json!(["0x00"])),
+            CacheTtl::Never
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache() {
+        let cache = JsonRpcCache::new("ethereum", 1);
+        let params = json!([]);
+
+        cache.set("eth_chainId", &params, &json!("0x1")).await;
+        assert_eq!(cache.get("eth_chainId", &params).await, Some(json!("0x1")));
+
+        // evicted by the next entry
+        cache.set("net_version", &params, &json!("1")).await;
+        assert_eq!(cache.get("eth_chainId", &params).await, None);
+
+        cache
+            .set("eth_call", &eth_call("0xa9059cbb"), &json!("0x"))
+            .await;
+        assert_eq!(cache.get("eth_call", &eth_call("0xa9059cbb")).await, None);
+        assert_eq!(cache.get("net_version", &params).await, Some(json!("1")));
+    }
+
+    #[tokio::test]
+    async fn test_shared_store() {
+        let store = Arc::new(FakeStore::default());
+        let params = json!([]);
+
+        let cache = JsonRpcCache::new("ethereum", 10)
+            .with_store(Some(store.clone() as Arc<dyn CacheStore>));
+        cache.set("eth_chainId", &params, &json!("0x1")).await;
+        assert_eq!(
+            store.get("jsonrpc:ethereum:eth_chainId:[]").await,
+            Some("\"0x1\"".to_string())
+        );
+
+        // another process sharing the store
+        let cache = JsonRpcCache::new("ethereum", 10)
+            .with_store(Some(store.clone() as Arc<dyn CacheStore>));
+        assert_eq!(cache.get("eth_chainId", &params).await, Some(json!("0x1")));
+        let cache = JsonRpcCache::new("polygon", 10).with_store(Some(store as Arc<dyn CacheStore>));
+        assert_eq!(cache.get("eth_chainId", &params).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_coalescing() {
+        let cache = JsonRpcCache::new("ethereum", 10);
+        let fetches = AtomicUsize::new(0);
+        let params = json!(["0x0", "latest"]);
+
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(json!("0x10"))
+        };
+        let results = futures::future::join_all(
+            (0..5).map(|_| cache.get_or_fetch("eth_getBalance", &params, fetch)),
+        )
+        .await;
+
+        assert!(results
+            .iter()
+            .all(|x| x.as_ref().unwrap() == &json!("0x10")));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert!(cache.inflight.lock().unwrap().is_empty());
+
+        // errors are shared with waiting callers but not cached
+        let error = JsonRpcError {
+            code: -32000,
+            message: "header not found".to_string(),
+            data: None,
+        };
+        let result = cache
+            .get_or_fetch("eth_blockNumber", &json!([]), || async { Err(error) })
+            .await;
+        assert!(result.is_err());
+        let result = cache
+            .get_or_fetch("eth_blockNumber", &json!([]), || async { Ok(json!("0x1")) })
+            .await;
+        assert_eq!(result.unwrap(), json!("0x1"));
+    }
+}
@@ -1,16 +1,53 @@
+use crate::cache::{CacheTtl, JsonRpcCache};
+use crate::endpoint::{EndpointConfig, EndpointPool, RoutingStrategy};
+use crate::rate_limit::RateLimitConfig;
+use crate::retry::{classify_client_error, classify_rpc_error, is_idempotent, RetryPolicy};
+use crate::trace::{RequestTrace, TraceConfig};
 use crate::types::{
-    JsonRpcError, JsonRpcRequest, JsonRpcResult, JsonRpcResults, ERROR_INTERNAL_ERROR,
+    JsonRpcError, JsonRpcRequest, JsonRpcResult, ERROR_INTERNAL_ERROR, ERROR_INVALID_REQUEST,
 };
-use reef_client::{Client, ClientError};
+use futures::{stream, StreamExt, TryStreamExt};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::time::SystemTime;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::Instrument;
 
 pub type CallTuple = (String, Value);
 
+/// Many providers reject batches above 100 calls
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+pub const DEFAULT_MAX_CONCURRENT_BATCHES: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchConfig {
+    /// Calls per HTTP request, larger batches are split into chunks
+    pub max_size: usize,
+    /// Chunks in flight at once
+    pub max_concurrent: usize,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_BATCH_SIZE,
+            max_concurrent: DEFAULT_MAX_CONCURRENT_BATCHES,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct JsonRpcClient<C: Client + Clone> {
-    client: C,
+    endpoints: Arc<EndpointPool<C>>,
+    retry: RetryPolicy,
+    cache: Option<Arc<JsonRpcCache>>,
+    batch: BatchConfig,
+    trace: TraceConfig,
+    /// Shared by clones, so ids stay unique across concurrent calls
+    next_id: Arc<AtomicU64>,
 }
 
 impl From<ClientError> for JsonRpcError {
@@ -18,13 +55,53 @@ impl From<ClientError> for JsonRpcError {
         JsonRpcError {
             code: ERROR_INTERNAL_ERROR,
             message: value.to_string(),
+            data: None,
         }
     }
 }
 
 impl<C: Client + Clone> JsonRpcClient<C> {
     pub fn new(client: C) -> Self {
-        Self { client }
+        Self::new_with_pool(EndpointPool::single(client))
+    }
+
+    pub fn new_with_pool(endpoints: EndpointPool<C>) -> Self {
+        Self {
+            endpoints: Arc::new(endpoints),
+            retry: RetryPolicy::default(),
+            cache: None,
+            batch: BatchConfig::default(),
+            trace: TraceConfig::default(),
+            next_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn with_cache(mut self, cache: JsonRpcCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    pub fn with_batch_config(mut self, batch: BatchConfig) -> Self {
+        self.batch = batch;
+        self
+    }
+
+    pub fn with_trace_config(mut self, trace: TraceConfig) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    pub fn next_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub fn endpoints(&self) -> &EndpointPool<C> {
+        &self.endpoints
     }
 
     pub async fn call<T: DeserializeOwned>(
@@ -32,60 +109,221 @@ impl<C: Client + Clone> JsonRpcClient<C> {
         method: &str,
         params: impl Into<Value>,
     ) -> Result<T, JsonRpcError> {
-        let timestamp = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let req = JsonRpcRequest::new(timestamp, method, params.into());
-        let result = self._request(req, None).await?;
-        match result {
-            JsonRpcResult::Value(value) => Ok(value.result),
-            JsonRpcResult::Error(error) => Err(error.error),
+        let params = params.into();
+        let value = match &self.cache {
+            Some(cache) => {
+                let ttl = cache.ttl(method, &params);
+                cache
+                    .get_or_fetch(method, &params, || {
+                        self.call_uncached(method, params.clone(), ttl)
+                    })
+                    .await?
+            }
+            None => self.call_uncached(method, params, CacheTtl::Never).await?,
+        };
+        serde_json::from_value(value).map_err(serialization_error)
+    }
+
+    /// Bypasses the cache, for reads that must reflect the node's current state
+    pub async fn call_fresh<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: impl Into<Value>,
+    ) -> Result<T, JsonRpcError> {
+        let value = self
+            .call_uncached(method, params.into(), CacheTtl::Never)
+            .await?;
+        serde_json::from_value(value).map_err(serialization_error)
+    }
+
+    async fn call_uncached(
+        &self,
+        method: &str,
+        params: Value,
+        ttl: CacheTtl,
+    ) -> Result<Value, JsonRpcError> {
+        let req = JsonRpcRequest::new(self.next_id(), method, params);
+        let idempotent = is_idempotent(method);
+
+        let mut attempt = 0;
+        loop {
+            let (error, kind) = match self._request(&req, ttl.max_age(), attempt).await {
+                Ok(JsonRpcResult::Value(value)) => return Ok(value.result),
+                Ok(JsonRpcResult::Error(error)) => {
+                    let kind = classify_rpc_error(&error.error);
+                    (error.error, kind)
+                }
+                Err(error) => {
+                    let kind = classify_client_error(&error);
+                    (error.into(), kind)
+                }
+            };
+            match self.retry.next_delay(attempt, kind, idempotent) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            }
+            attempt += 1;
         }
     }
 
     pub async fn batch_call<T: DeserializeOwned>(
         &self,
         calls: Vec<CallTuple>,
-    ) -> Result<JsonRpcResults<T>, JsonRpcError> {
+    ) -> Result<Vec<Result<T, JsonRpcError>>, JsonRpcError> {
         if calls.is_empty() {
-            return Ok(Default::default());
+            return Ok(vec![]);
         }
         let requests: Vec<JsonRpcRequest> = calls
             .iter()
-            .enumerate()
-            .map(|(index, (method, params))| {
-                JsonRpcRequest::new(index as u64 + 1, method, params.clone())
-            })
+            .map(|(method, params)| JsonRpcRequest::new(self.next_id(), method, params.clone()))
             .collect();
 
         self.batch_request(requests).await
     }
 
+    /// One result per request in request order, cached items are answered locally
+    /// and the rest is split into chunks of at most `max_size` sent concurrently
     pub async fn batch_request<T: DeserializeOwned>(
         &self,
         requests: Vec<JsonRpcRequest>,
-    ) -> Result<JsonRpcResults<T>, JsonRpcError> {
+    ) -> Result<Vec<Result<T, JsonRpcError>>, JsonRpcError> {
         if requests.is_empty() {
-            return Ok(Default::default());
+            return Ok(vec![]);
         }
-
-        let results: Vec<JsonRpcResult<T>> = self.client.post("", &requests, None).await?;
-        if results.len() != requests.len() {
+        let mut ids = HashSet::new();
+        if !requests.iter().all(|x| ids.insert(x.id)) {
             return Err(JsonRpcError {
-                message: "Batch call response length mismatch".into(),
-                code: ERROR_INTERNAL_ERROR,
+                message: "Batch request ids must be unique".into(),
+                code: ERROR_INVALID_REQUEST,
+                data: None,
+            });
+        }
+
+        let mut cached = Vec::with_capacity(requests.len());
+        for request in &requests {
+            cached.push(match &self.cache {
+                Some(cache) => cache.get(&request.method, &request.params).await,
+                None => None,
             });
         }
+        let misses: Vec<JsonRpcRequest> = requests
+            .iter()
+            .zip(&cached)
+            .filter(|(_, cached)| cached.is_none())
+            .map(|(request, _)| request.clone())
+            .collect();
 
-        Ok(JsonRpcResults(results))
+        // futures are built up front, a lazily mapped stream is not `Send` for async_trait callers
+        let pending: Vec<_> = misses
+            .chunks(self.batch.max_size.max(1))
+            .map(|chunk| self.send_batch(chunk))
+            .collect();
+        let chunks: Vec<Vec<Result<Value, JsonRpcError>>> = stream::iter(pending)
+            .buffered(self.batch.max_concurrent.max(1))
+            .try_collect()
+            .await?;
+        let mut fetched = chunks.into_iter().flatten();
+
+        let mut results = Vec::with_capacity(requests.len());
+        for (request, cached) in requests.iter().zip(cached) {
+            let result = match cached {
+                Some(value) => Ok(value),
+                None => {
+                    let result = fetched.next().unwrap();
+                    if let (Some(cache), Ok(value)) = (&self.cache, &result) {
+                        cache.set(&request.method, &request.params, value).await;
+                    }
+                    result
+                }
+            };
+            results
+                .push(result.and_then(|x| serde_json::from_value(x).map_err(serialization_error)));
+        }
+
+        Ok(results)
     }
 
-    async fn _request<T: DeserializeOwned>(
+    /// Send one chunk, matching responses to requests by id
+    async fn send_batch(
         &self,
-        req: JsonRpcRequest,
+        requests: &[JsonRpcRequest],
+    ) -> Result<Vec<Result<Value, JsonRpcError>>, JsonRpcError> {
+        // only transport failures are retried, item errors are returned to the caller
+        let idempotent = requests.iter().all(|x| is_idempotent(&x.method));
+        let mut attempt = 0;
+        let response: BatchResponse = loop {
+            let error = match self._batch_request(requests, attempt).await {
+                Ok(response) => break response,
+                Err(error) => error,
+            };
+            let kind = classify_client_error(&error);
+            match self.retry.next_delay(attempt, kind, idempotent) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error.into()),
+            }
+            attempt += 1;
+        };
+
+        let mut responses: HashMap<u64, Result<Value, JsonRpcError>> = match response {
+            BatchResponse::Results(results) => results
+                .into_iter()
+                .map(|result| match result {
+                    JsonRpcResult::Value(value) => (value.id, Ok(value.result)),
+                    JsonRpcResult::Error(error) => (error.id, Err(error.error)),
+                })
+                .collect(),
+            // e.g. a node rejecting the batch size, the error applies to every item
+            BatchResponse::Error { error } => {
+                return Ok(requests.iter().map(|_| Err(error.clone())).collect())
+            }
+        };
+
+        Ok(requests
+            .iter()
+            .map(|request| {
+                responses.remove(&request.id).unwrap_or_else(|| {
+                    Err(JsonRpcError {
+                        message: format!("Missing response for request id {}", request.id),
+                        code: ERROR_INTERNAL_ERROR,
+                        data: None,
+                    })
+                })
+            })
+            .collect())
+    }
+
+    async fn _batch_request(
+        &self,
+        requests: &[JsonRpcRequest],
+        attempt: u32,
+    ) -> Result<BatchResponse, ClientError> {
+        let mut methods: Vec<&str> = requests.iter().map(|x| x.method.as_str()).collect();
+        methods.sort_unstable();
+        methods.dedup();
+        let trace = RequestTrace::new(self.trace, &methods.join(","), requests.len(), attempt);
+        let idempotent = requests.iter().all(|x| is_idempotent(&x.method));
+
+        let result: Result<BatchResponse, ClientError> = self
+            .endpoints
+            .send_weighted(requests.len() as u32, idempotent, |client| {
+                trace.endpoint(client.base_url(), &requests);
+                client.post("", &requests, None)
+            })
+            .instrument(trace.span().clone())
+            .await;
+        match &result {
+            Ok(response) => trace.finish("ok", Some(response)),
+            Err(_) => trace.finish::<()>("transport_error", None),
+        }
+        result
+    }
+
+    async fn _request(
+        &self,
+        req: &JsonRpcRequest,
         ttl: Option<u64>,
-    ) -> Result<JsonRpcResult<T>, JsonRpcError> {
+        attempt: u32,
+    ) -> Result<JsonRpcResult<Value>, ClientError> {
         // Build cache headers if TTL is provided
         let headers = ttl.map(|ttl_seconds| {
             let mut headers = std::collections::HashMap::new();
@@ -96,31 +334,59 @@ impl<C: Client + Clone> JsonRpcClient<C> {
             headers
         });
 
-        let result: JsonRpcResult<T> = self.client.post("", &req, headers).await?;
-        Ok(result)
+        let trace = RequestTrace::new(self.trace, &req.method, 1, attempt);
+        let result: Result<JsonRpcResult<Value>, ClientError> = self
+            .endpoints
+            .send(is_idempotent(&req.method), |client| {
+                trace.endpoint(client.base_url(), req);
+                client.post("", req, headers.clone())
+            })
+            .instrument(trace.span().clone())
+            .await;
+        match &result {
+            Ok(response @ JsonRpcResult::Value(_)) => trace.finish("ok", Some(response)),
+            Ok(response @ JsonRpcResult::Error(_)) => trace.finish("rpc_error", Some(response)),
+            Err(_) => trace.finish::<()>("transport_error", None),
+        }
+        result
     }
 }
 
-impl JsonRpcClient<reef_client::ReqwestClient> {
+/// A node answers a batch with an array, or a single error when it rejects the whole batch
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum BatchResponse {
+    Results(Vec<JsonRpcResult<Value>>),
+    Error { error: JsonRpcError },
+}
+
+fn serialization_error(error: serde_json::Error) -> JsonRpcError {
+    ClientError::SerializationError(error.to_string()).into()
+}
+
+impl JsonRpcClient<ReqwestClient> {
     pub fn new_reqwest(url: String) -> Self {
-        use reef_client::ReqwestClient;
-        let reqwest_client = reqwest::Client::new();
-        let client = ReqwestClient::new(url, reqwest_client);
-        Self { client }
+        Self::new(ReqwestClient::new_with_url(url))
+    }
+
+    /// Route over several nodes of the same chain, see `EndpointPool::new_reqwest`
+    pub fn new_reqwest_endpoints(
+        endpoints: Vec<EndpointConfig>,
+        strategy: RoutingStrategy,
+        rate_limit: Option<RateLimitConfig>,
+        config: &ClientConfig,
+    ) -> Result<Self, ClientError> {
+        Ok(Self::new_with_pool(
+            EndpointPool::new_reqwest(endpoints, strategy, config)?.with_rate_limit(rate_limit),
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde::{Deserialize, Serialize};
     use serde_json::json;
 
-    #[derive(Debug, Serialize, Deserialize, PartialEq)]
-    struct TestResponse {
-        value: String,
-    }
-
     #[test]
     fn test_jsonrpc_client_creation() {
         let client = JsonRpcClient::new_reqwest("https://api.example.com".to_string());
@@ -169,4 +435,491 @@ mod tests {
         assert!(debug_str1.contains("JsonRpcClient"));
         assert!(debug_str2.contains("JsonRpcClient"));
     }
+
+    async fn mock_node(status: u16, expected_requests: u64) -> wiremock::MockServer {
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let response = if status == 200 {
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "jsonrpc": "2.0", "id": 1, "result": server.uri() }))
+        } else {
+            ResponseTemplate::new(status)
+        };
+        Mock::given(method("POST"))
+            .respond_with(response)
+            .expect(expected_requests)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_failover_on_unhealthy_endpoints() {
+        let down = mock_node(503, 1).await;
+        let limited = mock_node(429, 1).await;
+        let healthy = mock_node(200, 1).await;
+
+        let client = JsonRpcClient::new_reqwest_endpoints(
+            vec![
+                EndpointConfig::new(&down.uri(), 1),
+                EndpointConfig::new(&limited.uri(), 1),
+                EndpointConfig::new(&healthy.uri(), 1),
+            ],
+            RoutingStrategy::LowestLatency,
+            None,
+            &ClientConfig::default(),
+        )
+        .unwrap();
+
+        let result: String = client.call("eth_blockNumber", json!([])).await.unwrap();
+        assert_eq!(result, healthy.uri());
+    }
+
+    #[tokio::test]
+    async fn test_failover_skips_open_circuit() {
+        use crate::endpoint::CircuitBreaker;
+        use std::time::Duration;
+
+        // after the first failure the endpoint is skipped for the cooldown
+        let down = mock_node(500, 1).await;
+        let healthy = mock_node(200, 3).await;
+        let endpoints = vec![
+            (ReqwestClient::new_with_url(down.uri()), 1),
+            (ReqwestClient::new_with_url(healthy.uri()), 0),
+        ];
+        let client = JsonRpcClient::new_with_pool(
+            EndpointPool::new(endpoints, RoutingStrategy::RoundRobin).with_circuit_breaker(
+                CircuitBreaker {
+                    failure_threshold: 1,
+                    cooldown: Duration::from_secs(60),
+                },
+            ),
+        );
+
+        for _ in 0..3 {
+            let result: String = client.call("eth_blockNumber", json!([])).await.unwrap();
+            assert_eq!(result, healthy.uri());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_failover_on_request_error() {
+        let bad_request = mock_node(400, 1).await;
+        let healthy = mock_node(200, 0).await;
+
+        let client = JsonRpcClient::new_reqwest_endpoints(
+            vec![
+                EndpointConfig::new(&bad_request.uri(), 1),
+                EndpointConfig::new(&healthy.uri(), 0),
+            ],
+            RoutingStrategy::RoundRobin,
+            None,
+            &ClientConfig::default(),
+        )
+        .unwrap();
+
+        let result = client.call::<String>("eth_blockNumber", json!([])).await;
+        assert_eq!(result.unwrap_err().message, "HTTP error: 400");
+    }
+
+    /// Node that answers with `failure` for the first `failures` requests, then succeeds
+    async fn flaky_node(
+        failure: wiremock::ResponseTemplate,
+        failures: u64,
+    ) -> wiremock::MockServer {
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(failure)
+            .up_to_n_times(failures)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "jsonrpc": "2.0", "id": 1, "result": "0x1" })),
+            )
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn rpc_error_response(code: i32, message: &str) -> wiremock::ResponseTemplate {
+        wiremock::ResponseTemplate::new(200).set_body_json(
+            json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": code, "message": message } }),
+        )
+    }
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 2,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_secs(1),
+        }
+    }
+
+    async fn received(server: &wiremock::MockServer) -> usize {
+        server.received_requests().await.unwrap().len()
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_errors() {
+        let server = flaky_node(wiremock::ResponseTemplate::new(502), 2).await;
+        let client = JsonRpcClient::new_reqwest(server.uri()).with_retry_policy(fast_retry());
+
+        let result: String = client.call("eth_blockNumber", json!([])).await.unwrap();
+        assert_eq!(result, "0x1");
+        assert_eq!(received(&server).await, 3);
+
+        let server = flaky_node(rpc_error_response(-32005, "limit exceeded"), 1).await;
+        let client = JsonRpcClient::new_reqwest(server.uri()).with_retry_policy(fast_retry());
+        let result: String = client.call("eth_getBalance", json!([])).await.unwrap();
+        assert_eq!(result, "0x1");
+    }
+
+    #[tokio::test]
+    async fn test_retry_honors_retry_after() {
+        let limited = wiremock::ResponseTemplate::new(429).insert_header("Retry-After", "1");
+        let server = flaky_node(limited, 1).await;
+        let client = JsonRpcClient::new_reqwest(server.uri()).with_retry_policy(fast_retry());
+
+        let started = std::time::Instant::now();
+        let result: String = client.call("eth_blockNumber", json!([])).await.unwrap();
+        assert_eq!(result, "0x1");
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_deterministic_errors() {
+        let server = flaky_node(rpc_error_response(3, "execution reverted"), 1).await;
+        let client = JsonRpcClient::new_reqwest(server.uri()).with_retry_policy(fast_retry());
+
+        let error = client
+            .call::<String>("eth_call", json!([]))
+            .await
+            .unwrap_err();
+        assert_eq!(error.message, "execution reverted");
+        assert_eq!(received(&server).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction_is_not_blindly_retried() {
+        let server = flaky_node(wiremock::ResponseTemplate::new(502), 1).await;
+        let client = JsonRpcClient::new_reqwest(server.uri()).with_retry_policy(fast_retry());
+
+        let result = client
+            .call::<String>("eth_sendRawTransaction", json!(["0x00"]))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(received(&server).await, 1);
+
+        // a rate-limited send never reached the node and is safe to repeat
+        let server = flaky_node(wiremock::ResponseTemplate::new(429), 1).await;
+        let client = JsonRpcClient::new_reqwest(server.uri()).with_retry_policy(fast_retry());
+        let result: String = client
+            .call("eth_sendRawTransaction", json!(["0x00"]))
+            .await
+            .unwrap();
+        assert_eq!(result, "0x1");
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction_timeout_does_not_fail_over() {
+        use std::time::Duration;
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let slow = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+            .mount(&slow)
+            .await;
+        let backup = mock_node(200, 0).await;
+
+        let config = ClientConfig {
+            timeout: Duration::from_millis(200),
+            ..Default::default()
+        };
+        let client = JsonRpcClient::new_reqwest_endpoints(
+            vec![
+                EndpointConfig::new(&slow.uri(), 1),
+                EndpointConfig::new(&backup.uri(), 0),
+            ],
+            RoutingStrategy::RoundRobin,
+            None,
+            &config,
+        )
+        .unwrap()
+        .with_retry_policy(fast_retry());
+
+        let result = client
+            .call::<String>("eth_sendRawTransaction", json!(["0x00"]))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(received(&slow).await, 1);
+        assert_eq!(received(&backup).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_batch_counts_against_rate_limit() {
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "jsonrpc": "2.0", "id": 1, "result": "0x1" },
+                { "jsonrpc": "2.0", "id": 2, "result": "0x2" },
+                { "jsonrpc": "2.0", "id": 3, "result": "0x3" },
+            ])))
+            .mount(&server)
+            .await;
+        let client = JsonRpcClient::new_reqwest_endpoints(
+            vec![EndpointConfig::new(&server.uri(), 1)],
+            RoutingStrategy::RoundRobin,
+            Some(RateLimitConfig {
+                rps: 100.0,
+                burst: 3,
+                max_in_flight: Some(1),
+            }),
+            &ClientConfig::default(),
+        )
+        .unwrap();
+        let calls: Vec<CallTuple> = (0..3)
+            .map(|_| ("eth_blockNumber".to_string(), json!([])))
+            .collect();
+
+        client.batch_call::<String>(calls.clone()).await.unwrap();
+        client.batch_call::<String>(calls).await.unwrap();
+
+        let stats = client.endpoints().queue_stats()[0].unwrap();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.throttled, 1);
+        assert!(stats.max_wait >= std::time::Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn test_cache() {
+        let server = echo_id_node().await;
+        let client =
+            JsonRpcClient::new_reqwest(server.uri()).with_cache(JsonRpcCache::new("ethereum", 100));
+
+        let results =
+            futures::future::join_all((0..3).map(|_| client.call::<u64>("eth_chainId", json!([]))))
+                .await;
+        let chain_id = *results[0].as_ref().unwrap();
+        assert!(results.iter().all(|x| *x.as_ref().unwrap() == chain_id));
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].headers.contains_key("cache-control"));
+
+        // only the uncached call reaches the node
+        let calls: Vec<CallTuple> = vec![
+            ("eth_chainId".to_string(), json!([])),
+            (
+                "eth_getTransactionCount".to_string(),
+                json!(["0x0", "pending"]),
+            ),
+        ];
+        let results: Vec<u64> = client
+            .batch_call(calls)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(results[0], chain_id);
+        assert_ne!(results[1], chain_id);
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        let body: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body.as_array().unwrap().len(), 1);
+
+        // sends are never cached
+        for _ in 0..2 {
+            client
+                .call::<u64>("eth_sendRawTransaction", json!(["0x00"]))
+                .await
+                .unwrap();
+        }
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+
+        // fresh reads skip the cached chain id
+        let fresh: u64 = client.call_fresh("eth_chainId", json!([])).await.unwrap();
+        assert_ne!(fresh, chain_id);
+        assert_eq!(server.received_requests().await.unwrap().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_batch_chunks_matched_by_id() {
+        use wiremock::{matchers::method, Mock, MockServer, Request, ResponseTemplate};
+
+        // answers in reverse order, failing id 3
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(|request: &Request| {
+                let calls: Vec<Value> = serde_json::from_slice(&request.body).unwrap();
+                let responses: Vec<Value> = calls
+                    .iter()
+                    .rev()
+                    .map(|x| match x["id"].as_u64().unwrap() {
+                        3 => json!({ "jsonrpc": "2.0", "id": 3, "error": { "code": 3, "message": "execution reverted" } }),
+                        id => json!({ "jsonrpc": "2.0", "id": id, "result": id * 10 }),
+                    })
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(responses)
+            })
+            .mount(&server)
+            .await;
+        let client = JsonRpcClient::new_reqwest(server.uri()).with_batch_config(BatchConfig {
+            max_size: 2,
+            max_concurrent: 2,
+        });
+        let calls: Vec<CallTuple> = (0..5)
+            .map(|_| ("eth_call".to_string(), json!([])))
+            .collect();
+
+        let results = client.batch_call::<u64>(calls).await.unwrap();
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+        assert_eq!(results.len(), 5);
+        assert_eq!(results[0].as_ref().unwrap(), &10);
+        assert_eq!(results[1].as_ref().unwrap(), &20);
+        assert_eq!(
+            results[2].as_ref().unwrap_err().message,
+            "execution reverted"
+        );
+        assert_eq!(results[3].as_ref().unwrap(), &40);
+        assert_eq!(results[4].as_ref().unwrap(), &50);
+    }
+
+    #[tokio::test]
+    async fn test_batch_rejected_as_a_whole() {
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": -32600, "message": "batch too large" }
+            })))
+            .mount(&server)
+            .await;
+        let client = JsonRpcClient::new_reqwest(server.uri());
+        let calls: Vec<CallTuple> = (0..2)
+            .map(|_| ("eth_blockNumber".to_string(), json!([])))
+            .collect();
+
+        let results = client.batch_call::<String>(calls).await.unwrap();
+        assert!(results
+            .iter()
+            .all(|x| x.as_ref().unwrap_err().message == "batch too large"));
+
+        let duplicated = vec![
+            JsonRpcRequest::new(1, "eth_blockNumber", json!([])),
+            JsonRpcRequest::new(1, "eth_chainId", json!([])),
+        ];
+        let error = client
+            .batch_request::<String>(duplicated)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code, ERROR_INVALID_REQUEST);
+    }
+
+    /// Node answering every call with its request id
+    async fn echo_id_node() -> wiremock::MockServer {
+        use wiremock::{matchers::method, Mock, MockServer, Request, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(|request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                let response =
+                    |x: &Value| json!({ "jsonrpc": "2.0", "id": x["id"], "result": x["id"] });
+                ResponseTemplate::new(200).set_body_json(match &body {
+                    Value::Array(calls) => Value::Array(calls.iter().map(response).collect()),
+                    call => response(call),
+                })
+            })
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_request_ids_are_unique() {
+        let server = echo_id_node().await;
+        let client = JsonRpcClient::new_reqwest(server.uri());
+        let cloned = client.clone();
+
+        let mut ids: Vec<u64> = futures::future::join_all((0..10).map(|i| {
+            let client = if i % 2 == 0 { &client } else { &cloned };
+            client.call::<u64>("eth_blockNumber", json!([]))
+        }))
+        .await
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+        let calls: Vec<CallTuple> = (0..3)
+            .map(|_| ("eth_blockNumber".to_string(), json!([])))
+            .collect();
+        ids.extend(
+            client
+                .batch_call::<u64>(calls)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(Result::unwrap),
+        );
+
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 13);
+    }
+
+    #[tokio::test]
+    async fn test_log_bodies_redacts_url() {
+        use std::sync::Mutex;
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let server = echo_id_node().await;
+        let client =
+            JsonRpcClient::new_reqwest(format!("{}/v2/SecretApiKey0123456789", server.uri()))
+                .with_trace_config(TraceConfig {
+                    log_bodies: true,
+                    ..Default::default()
+                });
+        client.call::<u64>("eth_chainId", json!([])).await.unwrap();
+
+        let logs = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("method=\"eth_chainId\""));
+        assert!(logs.contains("host=\"127.0.0.1\""));
+        assert!(logs.contains("outcome=\"ok\""));
+        assert!(logs.contains("/v2/***"));
+        assert!(logs.contains(r#""method":"eth_chainId""#));
+        assert!(!logs.contains("SecretApiKey"));
+    }
 }
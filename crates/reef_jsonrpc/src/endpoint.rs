@@ -0,0 +1,509 @@
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reef_client::{ClientAuth, ClientConfig, ClientError, ReqwestClient};
+use serde::{Deserialize, Serialize};
+
+use crate::rate_limit::{QueueStats, RateLimitConfig, RateLimiter};
+
+/// Consecutive failures before an endpoint is taken out of rotation
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+/// How long an open circuit keeps the endpoint out of rotation
+pub const DEFAULT_CIRCUIT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Weight given to new latency samples in the moving average
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingStrategy {
+    /// Weighted round-robin, a weight of 0 makes the endpoint a backup only
+    #[default]
+    RoundRobin,
+    /// Endpoint with the lowest observed latency first
+    LowestLatency,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointConfig {
+    pub url: String,
+    pub weight: u32,
+    /// Credentials for this endpoint only, `None` falls back to the client config's auth
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<ClientAuth>,
+}
+
+impl EndpointConfig {
+    pub fn new(url: &str, weight: u32) -> Self {
+        Self {
+            url: url.to_string(),
+            weight,
+            auth: None,
+        }
+    }
+
+    pub fn with_auth(mut self, auth: Option<ClientAuth>) -> Self {
+        self.auth = auth;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBreaker {
+    pub failure_threshold: u32,
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            cooldown: DEFAULT_CIRCUIT_COOLDOWN,
+        }
+    }
+}
+
+/// Errors that say nothing about the request itself, so another endpoint may succeed.
+/// A request with side effects only moves on when the node provably rejected it,
+/// after a timeout or a 502 the first node may already have processed it
+pub fn is_failover_error(error: &ClientError, idempotent: bool) -> bool {
+    match error {
+        ClientError::NetworkError(_) | ClientError::Timeout(_) => idempotent,
+        ClientError::HttpError { status, .. } => {
+            matches!(status, 429 | 503) || (idempotent && *status >= 500)
+        }
+        ClientError::RequestFailed(_) | ClientError::SerializationError(_) => false,
+    }
+}
+
+#[derive(Debug, Default)]
+struct EndpointHealth {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    latency: Option<Duration>,
+}
+
+struct Endpoint<C> {
+    client: C,
+    weight: u32,
+    health: Mutex<EndpointHealth>,
+    limiter: Option<RateLimiter>,
+}
+
+impl<C> Endpoint<C> {
+    fn is_available(&self, now: Instant) -> bool {
+        let health = self.health.lock().unwrap();
+        health.open_until.is_none_or(|until| until <= now)
+    }
+
+    fn latency(&self) -> Option<Duration> {
+        self.health.lock().unwrap().latency
+    }
+
+    fn record_success(&self, latency: Duration) {
+        let mut health = self.health.lock().unwrap();
+        health.consecutive_failures = 0;
+        health.open_until = None;
+        health.latency = Some(match health.latency {
+            Some(average) => {
+                average.mul_f64(1.0 - LATENCY_SMOOTHING) + latency.mul_f64(LATENCY_SMOOTHING)
+            }
+            None => latency,
+        });
+    }
+
+    fn record_failure(&self, breaker: &CircuitBreaker, now: Instant) {
+        let mut health = self.health.lock().unwrap();
+        health.consecutive_failures += 1;
+        // a half-open endpoint that fails again is reopened right away
+        if health.consecutive_failures >= breaker.failure_threshold {
+            health.open_until = Some(now + breaker.cooldown);
+        }
+    }
+}
+
+/// Set of interchangeable endpoints for one chain
+pub struct EndpointPool<C> {
+    endpoints: Vec<Endpoint<C>>,
+    strategy: RoutingStrategy,
+    breaker: CircuitBreaker,
+    cursor: AtomicUsize,
+}
+
+impl<C: fmt::Debug> fmt::Debug for EndpointPool<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EndpointPool")
+            .field(
+                "endpoints",
+                &self.endpoints.iter().map(|x| &x.client).collect::<Vec<_>>(),
+            )
+            .field("strategy", &self.strategy)
+            .finish()
+    }
+}
+
+impl<C> EndpointPool<C> {
+    pub fn new(endpoints: Vec<(C, u32)>, strategy: RoutingStrategy) -> Self {
+        assert!(
+            !endpoints.is_empty(),
+            "EndpointPool requires at least one endpoint"
+        );
+        Self {
+            endpoints: endpoints
+                .into_iter()
+                .map(|(client, weight)| Endpoint {
+                    client,
+                    weight,
+                    health: Mutex::new(EndpointHealth::default()),
+                    limiter: None,
+                })
+                .collect(),
+            strategy,
+            breaker: CircuitBreaker::default(),
+            cursor: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn single(client: C) -> Self {
+        Self::new(vec![(client, 1)], RoutingStrategy::RoundRobin)
+    }
+
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.breaker = breaker;
+        self
+    }
+
+    /// Apply the same limit to every endpoint, each one gets its own bucket
+    pub fn with_rate_limit(mut self, config: Option<RateLimitConfig>) -> Self {
+        for endpoint in &mut self.endpoints {
+            endpoint.limiter = config.map(RateLimiter::new);
+        }
+        self
+    }
+
+    /// Queue-wait statistics of each endpoint's limiter, in configured order
+    pub fn queue_stats(&self) -> Vec<Option<QueueStats>> {
+        self.endpoints
+            .iter()
+            .map(|x| x.limiter.as_ref().map(|x| x.stats()))
+            .collect()
+    }
+
+    /// Endpoint tried first when everything is healthy
+    pub fn primary(&self) -> &C {
+        &self.endpoints[0].client
+    }
+
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    /// Order to try endpoints in: available ones by strategy, open circuits only as a last resort
+    fn route(&self, now: Instant) -> Vec<usize> {
+        let mut order: Vec<usize> = match self.strategy {
+            RoutingStrategy::RoundRobin => self.round_robin_order(),
+            RoutingStrategy::LowestLatency => {
+                let mut order: Vec<usize> = (0..self.endpoints.len()).collect();
+                // endpoints without samples are probed first
+                order.sort_by_key(|&i| {
+                    (
+                        self.endpoints[i].latency().unwrap_or_default(),
+                        u32::MAX - self.endpoints[i].weight,
+                    )
+                });
+                order
+            }
+        };
+        order.sort_by_key(|&i| !self.endpoints[i].is_available(now));
+        order
+    }
+
+    fn round_robin_order(&self) -> Vec<usize> {
+        let count = self.endpoints.len();
+        let total_weight: usize = self.endpoints.iter().map(|x| x.weight as usize).sum();
+        if total_weight == 0 {
+            return (0..count).collect();
+        }
+
+        let mut slot = self.cursor.fetch_add(1, Ordering::Relaxed) % total_weight;
+        let first = self
+            .endpoints
+            .iter()
+            .position(|x| {
+                let weight = x.weight as usize;
+                if slot < weight {
+                    return true;
+                }
+                slot -= weight;
+                false
+            })
+            .unwrap_or(0);
+
+        // the remaining endpoints follow in configured order, weight 0 backups last
+        let mut order: Vec<usize> = (0..count).map(|i| (first + i) % count).collect();
+        order[1..].sort_by_key(|&i| (self.endpoints[i].weight == 0, (i + count - first) % count));
+        order
+    }
+
+    /// Send through the pool, failing over to the next endpoint on network, 5xx and 429 errors,
+    /// see `is_failover_error` for requests that are not `idempotent`
+    pub async fn send<'a, F, Fut, R>(
+        &'a self,
+        idempotent: bool,
+        request: F,
+    ) -> Result<R, ClientError>
+    where
+        F: Fn(&'a C) -> Fut,
+        Fut: Future<Output = Result<R, ClientError>>,
+    {
+        self.send_weighted(1, idempotent, request).await
+    }
+
+    /// Like `send`, taking `cost` rate limit tokens, e.g. the number of calls in a batch
+    pub async fn send_weighted<'a, F, Fut, R>(
+        &'a self,
+        cost: u32,
+        idempotent: bool,
+        request: F,
+    ) -> Result<R, ClientError>
+    where
+        F: Fn(&'a C) -> Fut,
+        Fut: Future<Output = Result<R, ClientError>>,
+    {
+        let mut last_error = None;
+
+        for index in self.route(Instant::now()) {
+            let endpoint = &self.endpoints[index];
+            let _permit = match &endpoint.limiter {
+                Some(limiter) => Some(limiter.acquire(cost).await),
+                None => None,
+            };
+            // latency is measured from the node, queue wait is excluded
+            let started = Instant::now();
+            match request(&endpoint.client).await {
+                Ok(response) => {
+                    endpoint.record_success(started.elapsed());
+                    return Ok(response);
+                }
+                Err(error) => {
+                    // an unhealthy node counts against its circuit either way
+                    if is_failover_error(&error, true) {
+                        endpoint.record_failure(&self.breaker, Instant::now());
+                    }
+                    if !is_failover_error(&error, idempotent) {
+                        return Err(error);
+                    }
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| ClientError::RequestFailed("No endpoint available".to_string())))
+    }
+}
+
+impl EndpointPool<ReqwestClient> {
+    /// Endpoints without their own auth share one connection pool built from `config`,
+    /// the others get a client that only sends their credentials
+    pub fn new_reqwest(
+        endpoints: Vec<EndpointConfig>,
+        strategy: RoutingStrategy,
+        config: &ClientConfig,
+    ) -> Result<Self, ClientError> {
+        let shared = config.build()?;
+        let endpoints = endpoints
+            .into_iter()
+            .map(|x| {
+                let client = match x.auth {
+                    Some(auth) => ReqwestClient::new_with_config(
+                        x.url,
+                        &config.clone().with_auth(Some(auth)),
+                    )?,
+                    None => ReqwestClient::new_with_client(x.url, shared.clone(), config),
+                };
+                Ok((client, x.weight))
+            })
+            .collect::<Result<Vec<_>, ClientError>>()?;
+        Ok(Self::new(endpoints, strategy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(weights: &[u32], strategy: RoutingStrategy) -> EndpointPool<usize> {
+        EndpointPool::new(weights.iter().copied().enumerate().collect(), strategy)
+    }
+
+    #[test]
+    fn test_is_failover_error() {
+        let http = |status| ClientError::HttpError {
+            status,
+            retry_after: None,
+        };
+        assert!(is_failover_error(
+            &ClientError::NetworkError("reset".to_string()),
+            true
+        ));
+        assert!(is_failover_error(&http(429), true));
+        assert!(is_failover_error(&http(502), true));
+        assert!(!is_failover_error(&http(400), true));
+        assert!(!is_failover_error(
+            &ClientError::SerializationError("eof".to_string()),
+            true
+        ));
+
+        // only a provable rejection moves a send on to the next node
+        assert!(!is_failover_error(
+            &ClientError::Timeout("30s".to_string()),
+            false
+        ));
+        assert!(!is_failover_error(&http(502), false));
+        assert!(is_failover_error(&http(429), false));
+        assert!(is_failover_error(&http(503), false));
+    }
+
+    #[test]
+    fn test_weighted_round_robin() {
+        let pool = pool(&[2, 1, 0], RoutingStrategy::RoundRobin);
+        let now = Instant::now();
+
+        let firsts: Vec<usize> = (0..6).map(|_| pool.route(now)[0]).collect();
+        assert_eq!(firsts, vec![0, 0, 1, 0, 0, 1]);
+
+        // backups are only used as a fallback
+        pool.route(now);
+        pool.route(now);
+        assert_eq!(pool.route(now), vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn test_lowest_latency() {
+        let pool = pool(&[1, 1, 1], RoutingStrategy::LowestLatency);
+        pool.endpoints[0].record_success(Duration::from_millis(300));
+        pool.endpoints[1].record_success(Duration::from_millis(50));
+
+        // endpoint 2 has not been measured yet
+        assert_eq!(pool.route(Instant::now()), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        let pool =
+            pool(&[1, 1], RoutingStrategy::LowestLatency).with_circuit_breaker(CircuitBreaker {
+                failure_threshold: 2,
+                cooldown: Duration::from_secs(10),
+            });
+        let now = Instant::now();
+
+        pool.endpoints[0].record_failure(&pool.breaker, now);
+        assert_eq!(pool.route(now), vec![0, 1]);
+
+        pool.endpoints[0].record_failure(&pool.breaker, now);
+        assert_eq!(pool.route(now), vec![1, 0]);
+
+        // half-open after the cooldown
+        assert_eq!(pool.route(now + Duration::from_secs(10)), vec![0, 1]);
+        pool.endpoints[0].record_success(Duration::from_millis(1));
+        assert!(pool.endpoints[0].is_available(now));
+    }
+
+    #[tokio::test]
+    async fn test_send_failover() {
+        let pool = pool(&[1, 1, 1], RoutingStrategy::LowestLatency);
+
+        let result = pool
+            .send(true, |&index| async move {
+                match index {
+                    0 => Err(ClientError::HttpError {
+                        status: 503,
+                        retry_after: None,
+                    }),
+                    _ => Ok(index),
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 1);
+
+        let result: Result<usize, _> = pool
+            .send(true, |_| async {
+                Err(ClientError::HttpError {
+                    status: 400,
+                    retry_after: None,
+                })
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(ClientError::HttpError { status: 400, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_non_idempotent_timeout_does_not_fail_over() {
+        let pool = pool(&[1, 0], RoutingStrategy::RoundRobin);
+        let calls = AtomicUsize::new(0);
+
+        let result: Result<usize, _> = pool
+            .send(false, |&index| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                async move {
+                    match index {
+                        0 => Err(ClientError::Timeout("30s".to_string())),
+                        _ => Ok(index),
+                    }
+                }
+            })
+            .await;
+        assert!(matches!(result, Err(ClientError::Timeout(_))));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            pool.endpoints[0]
+                .health
+                .lock()
+                .unwrap()
+                .consecutive_failures,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_weighted_rate_limit() {
+        let pool =
+            pool(&[1, 1], RoutingStrategy::RoundRobin).with_rate_limit(Some(RateLimitConfig {
+                rps: 100.0,
+                burst: 5,
+                max_in_flight: None,
+            }));
+
+        let result = pool
+            .send_weighted(5, true, |&index| async move { Ok(index) })
+            .await;
+        assert_eq!(result.unwrap(), 0);
+        pool.send(true, |&index| async move { Ok(index) })
+            .await
+            .unwrap();
+        pool.send(true, |&index| async move { Ok(index) })
+            .await
+            .unwrap();
+
+        // endpoint 0 ran out of tokens after the batch, endpoint 1 did not
+        let stats = pool.queue_stats();
+        let first = stats[0].unwrap();
+        assert_eq!(first.requests, 2);
+        assert_eq!(first.throttled, 1);
+        assert!(first.max_wait >= Duration::from_millis(5));
+        assert_eq!(stats[1].unwrap().throttled, 0);
+
+        assert_eq!(pool.with_rate_limit(None).queue_stats(), vec![None, None]);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::endpoint::{EndpointConfig, EndpointPool, RoutingStrategy};
+use crate::rate_limit::RateLimitConfig;
+use async_trait::async_trait;
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// `Client` that routes REST requests over an `EndpointPool`.
+/// GETs fail over on any transient error, POSTs may broadcast a transaction
+/// so they only move on when the node rejected them, see `is_failover_error`
+#[derive(Debug, Clone)]
+pub struct FailoverClient<C: Client + Clone = ReqwestClient> {
+    endpoints: Arc<EndpointPool<C>>,
+}
+
+impl<C: Client + Clone> FailoverClient<C> {
+    pub fn new(endpoints: EndpointPool<C>) -> Self {
+        Self {
+            endpoints: Arc::new(endpoints),
+        }
+    }
+
+    pub fn endpoints(&self) -> &EndpointPool<C> {
+        &self.endpoints
+    }
+}
+
+impl FailoverClient<ReqwestClient> {
+    pub fn new_reqwest_endpoints(
+        endpoints: Vec<EndpointConfig>,
+        strategy: RoutingStrategy,
+        rate_limit: Option<RateLimitConfig>,
+        config: &ClientConfig,
+    ) -> Result<Self, ClientError> {
+        Ok(Self::new(
+            EndpointPool::new_reqwest(endpoints, strategy, config)?.with_rate_limit(rate_limit),
+        ))
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> Client for FailoverClient<C> {
+    fn base_url(&self) -> &str {
+        self.endpoints.primary().base_url()
+    }
+
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.endpoints.send(true, |client| client.get(path)).await
+    }
+
+    async fn post<T, R>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        self.endpoints
+            .send(false, |client| client.post(path, body, headers.clone()))
+            .await
+    }
+
+    async fn post_bytes<R>(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.endpoints
+            .send(false, |client| {
+                client.post_bytes(path, body.clone(), content_type)
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::ClientAuth;
+    use serde_json::{json, Value};
+    use wiremock::matchers::{header, header_exists, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn rest_node(http_method: &str, status: u16, expected_requests: u64) -> MockServer {
+        let server = MockServer::start().await;
+        let response = ResponseTemplate::new(status).set_body_json(json!(server.uri()));
+        Mock::given(method(http_method))
+            .respond_with(response)
+            .expect(expected_requests)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_get_fails_over_with_endpoint_auth() {
+        let down = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("TRON-PRO-API-KEY", "secret"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&down)
+            .await;
+        let backup = MockServer::start().await;
+        Mock::given(header_exists("TRON-PRO-API-KEY"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(0)
+            .mount(&backup)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(backup.uri())))
+            .expect(1)
+            .mount(&backup)
+            .await;
+
+        let auth = ClientAuth::ApiKeyHeader {
+            name: "TRON-PRO-API-KEY".to_string(),
+            value: "secret".to_string(),
+        };
+        let client = FailoverClient::new_reqwest_endpoints(
+            vec![
+                EndpointConfig::new(&down.uri(), 1).with_auth(Some(auth)),
+                EndpointConfig::new(&backup.uri(), 0),
+            ],
+            RoutingStrategy::RoundRobin,
+            None,
+            &ClientConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(client.base_url(), down.uri());
+        let result: String = client.get("/wallet/getnowblock").await.unwrap();
+        assert_eq!(result, backup.uri());
+    }
+
+    #[tokio::test]
+    async fn test_post_does_not_fail_over_after_server_error() {
+        let down = rest_node("POST", 500, 1).await;
+        let backup = rest_node("POST", 200, 0).await;
+        let client = FailoverClient::new_reqwest_endpoints(
+            vec![
+                EndpointConfig::new(&down.uri(), 1),
+                EndpointConfig::new(&backup.uri(), 0),
+            ],
+            RoutingStrategy::RoundRobin,
+            None,
+            &ClientConfig::default(),
+        )
+        .unwrap();
+
+        let result = client
+            .post::<Value, String>("/wallet/broadcasttransaction", &json!({}), None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(ClientError::HttpError { status: 500, .. })
+        ));
+    }
+}
@@ -1,5 +1,19 @@
+pub mod cache;
 pub mod client;
+pub mod endpoint;
+pub mod failover;
+pub mod rate_limit;
+pub mod retry;
+pub mod trace;
 pub mod types;
+pub mod ws;
 
-pub use client::JsonRpcClient;
+pub use cache::{CachePolicy, CacheStore, CacheTtl, JsonRpcCache, RedisCache};
+pub use client::{BatchConfig, JsonRpcClient};
+pub use endpoint::{EndpointConfig, EndpointPool, RoutingStrategy};
+pub use failover::FailoverClient;
+pub use rate_limit::{QueueStats, RateLimitConfig};
+pub use retry::RetryPolicy;
+pub use trace::{redact_url, TraceConfig};
 pub use types::*;
+pub use ws::{Subscription, WsClient};
@@ -0,0 +1,207 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Sustained requests per second, a batch counts once per element
+    pub rps: f64,
+    /// Requests that may be sent at once after an idle period
+    pub burst: u32,
+    /// Concurrent HTTP requests, `None` for unlimited
+    pub max_in_flight: Option<usize>,
+}
+
+impl RateLimitConfig {
+    pub fn new(rps: f64) -> Self {
+        Self {
+            rps,
+            burst: rps.ceil().max(1.0) as u32,
+            max_in_flight: None,
+        }
+    }
+}
+
+/// Time spent waiting on our own limiter, as opposed to waiting on the node
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueueStats {
+    pub requests: u64,
+    /// Requests that had to wait for tokens or an in-flight slot
+    pub throttled: u64,
+    pub total_wait: Duration,
+    pub max_wait: Duration,
+}
+
+impl QueueStats {
+    pub fn average_wait(&self) -> Duration {
+        match self.requests {
+            0 => Duration::ZERO,
+            requests => self.total_wait / requests as u32,
+        }
+    }
+
+    fn record(&mut self, waited: Duration) {
+        self.requests += 1;
+        if !waited.is_zero() {
+            self.throttled += 1;
+        }
+        self.total_wait += waited;
+        self.max_wait = self.max_wait.max(waited);
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Held for the duration of a request, frees the in-flight slot on drop
+#[derive(Debug)]
+pub struct RateLimitPermit {
+    pub waited: Duration,
+    _in_flight: Option<OwnedSemaphorePermit>,
+}
+
+/// Token bucket with an optional cap on in-flight requests
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    bucket: Mutex<Bucket>,
+    in_flight: Option<Arc<Semaphore>>,
+    stats: Mutex<QueueStats>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            bucket: Mutex::new(Bucket {
+                tokens: config.burst as f64,
+                updated_at: Instant::now(),
+            }),
+            in_flight: config
+                .max_in_flight
+                .map(|x| Arc::new(Semaphore::new(x.max(1)))),
+            stats: Mutex::new(QueueStats::default()),
+        }
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    pub fn stats(&self) -> QueueStats {
+        *self.stats.lock().unwrap()
+    }
+
+    /// Take `cost` tokens, going into debt so callers are served in arrival order
+    fn reserve(&self, cost: u32, now: Instant) -> Duration {
+        if self.config.rps <= 0.0 {
+            return Duration::ZERO;
+        }
+        let mut bucket = self.bucket.lock().unwrap();
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        bucket.tokens =
+            (bucket.tokens + elapsed.as_secs_f64() * self.config.rps).min(self.config.burst as f64);
+        bucket.updated_at = now;
+        bucket.tokens -= cost as f64;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.config.rps)
+        }
+    }
+
+    pub async fn acquire(&self, cost: u32) -> RateLimitPermit {
+        let started = Instant::now();
+        let delay = self.reserve(cost, started);
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        let in_flight = match &self.in_flight {
+            Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        let waited = started.elapsed();
+        // sub-millisecond waits are scheduling noise, not throttling
+        let waited = if waited < Duration::from_millis(1) {
+            Duration::ZERO
+        } else {
+            waited
+        };
+        self.stats.lock().unwrap().record(waited);
+        RateLimitPermit {
+            waited,
+            _in_flight: in_flight,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            rps: 10.0,
+            burst: 5,
+            max_in_flight: None,
+        });
+        let now = Instant::now();
+
+        for _ in 0..5 {
+            assert_eq!(limiter.reserve(1, now), Duration::ZERO);
+        }
+        assert_eq!(limiter.reserve(1, now), Duration::from_millis(100));
+        // a batch of 10 waits for all of its tokens
+        assert_eq!(limiter.reserve(10, now), Duration::from_millis(1100));
+
+        // refilled, capped at burst
+        let later = now + Duration::from_secs(60);
+        assert_eq!(limiter.reserve(5, later), Duration::ZERO);
+        assert_eq!(limiter.reserve(1, later), Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_records_queue_wait() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            rps: 100.0,
+            burst: 1,
+            max_in_flight: None,
+        });
+
+        assert_eq!(limiter.acquire(1).await.waited, Duration::ZERO);
+        let permit = limiter.acquire(2).await;
+        assert!(permit.waited >= Duration::from_millis(15));
+
+        let stats = limiter.stats();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.throttled, 1);
+        assert_eq!(stats.max_wait, permit.waited);
+    }
+
+    #[tokio::test]
+    async fn test_max_in_flight() {
+        let limiter = Arc::new(RateLimiter::new(RateLimitConfig {
+            rps: 0.0,
+            burst: 0,
+            max_in_flight: Some(1),
+        }));
+
+        let permit = limiter.acquire(1).await;
+        let waiter = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire(1).await.waited })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(permit);
+
+        assert!(waiter.await.unwrap() >= Duration::from_millis(15));
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reef_client::ClientError;
+
+use crate::types::{
+    JsonRpcError, ERROR_INVALID_PARAMS, ERROR_INVALID_REQUEST, ERROR_METHOD_NOT_FOUND,
+};
+
+/// `limit exceeded`, returned by geth-style nodes and most providers when throttling
+pub const ERROR_LIMIT_EXCEEDED: i32 = -32005;
+/// `execution reverted` with revert data
+pub const ERROR_EXECUTION_REVERTED: i32 = 3;
+pub const ERROR_PARSE_ERROR: i32 = -32700;
+
+/// Methods with side effects, retried only when the node provably rejected the request
+pub const NON_IDEMPOTENT_METHODS: &[&str] = &[
+    "eth_sendRawTransaction",
+    "eth_sendTransaction",
+    "sendTransaction",
+];
+
+pub fn is_idempotent(method: &str) -> bool {
+    !NON_IDEMPOTENT_METHODS.contains(&method)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Transport failure, the node may or may not have processed the request
+    Transient { retry_after: Option<Duration> },
+    /// The node refused the request before processing it, e.g. rate limiting
+    Rejected { retry_after: Option<Duration> },
+    /// Sending the same request again gives the same answer
+    Deterministic,
+}
+
+pub fn classify_client_error(error: &ClientError) -> ErrorKind {
+    match error {
+        ClientError::NetworkError(_) | ClientError::Timeout(_) => {
+            ErrorKind::Transient { retry_after: None }
+        }
+        ClientError::HttpError {
+            status,
+            retry_after,
+        } => {
+            let retry_after = retry_after.map(Duration::from_secs);
+            match status {
+                429 | 503 => ErrorKind::Rejected { retry_after },
+                500..=599 => ErrorKind::Transient { retry_after },
+                _ => ErrorKind::Deterministic,
+            }
+        }
+        ClientError::RequestFailed(_) | ClientError::SerializationError(_) => {
+            ErrorKind::Deterministic
+        }
+    }
+}
+
+pub fn classify_rpc_error(error: &JsonRpcError) -> ErrorKind {
+    let message = error.message.to_lowercase();
+    match error.code {
+        ERROR_EXECUTION_REVERTED
+        | ERROR_INVALID_PARAMS
+        | ERROR_INVALID_REQUEST
+        | ERROR_METHOD_NOT_FOUND
+        | ERROR_PARSE_ERROR => ErrorKind::Deterministic,
+        ERROR_LIMIT_EXCEEDED => ErrorKind::Rejected { retry_after: None },
+        _ if message.contains("rate limit") || message.contains("too many requests") => {
+            ErrorKind::Rejected { retry_after: None }
+        }
+        // load balanced nodes lagging behind the requested block
+        _ if message.contains("header not found") || message.contains("header-not-found") => {
+            ErrorKind::Transient { retry_after: None }
+        }
+        _ => ErrorKind::Deterministic,
+    }
+}
+
+/// Exponential backoff with full jitter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    /// Upper bound for a single wait, a longer `Retry-After` gives up instead
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Random delay in `[0, min(max_delay, base_delay * 2^attempt)]`
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let cap = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let random = RandomState::new().build_hasher().finish();
+        cap.mul_f64((random % 10_000) as f64 / 10_000.0)
+    }
+
+    /// Wait before retry number `attempt + 1`, `None` to give up
+    pub fn next_delay(&self, attempt: u32, kind: ErrorKind, idempotent: bool) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        let retry_after = match kind {
+            ErrorKind::Deterministic => return None,
+            ErrorKind::Transient { .. } if !idempotent => return None,
+            ErrorKind::Transient { retry_after } | ErrorKind::Rejected { retry_after } => {
+                retry_after
+            }
+        };
+        match retry_after {
+            Some(delay) if delay > self.max_delay => None,
+            Some(delay) => Some(delay),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpc_error(code: i32, message: &str) -> JsonRpcError {
+        JsonRpcError {
+            code,
+            message: message.to_string(),
+            data: None,
+        }
+    }
+
+    #[test]
+    fn test_classify_client_error() {
+        assert_eq!(
+            classify_client_error(&ClientError::NetworkError(
+                "connection reset by peer".to_string()
+            )),
+            ErrorKind::Transient { retry_after: None }
+        );
+        assert_eq!(
+            classify_client_error(&ClientError::HttpError {
+                status: 429,
+                retry_after: Some(2)
+            }),
+            ErrorKind::Rejected {
+                retry_after: Some(Duration::from_secs(2))
+            }
+        );
+        assert_eq!(
+            classify_client_error(&ClientError::HttpError {
+                status: 502,
+                retry_after: None
+            }),
+            ErrorKind::Transient { retry_after: None }
+        );
+        assert_eq!(
+            classify_client_error(&ClientError::HttpError {
+                status: 401,
+                retry_after: None
+            }),
+            ErrorKind::Deterministic
+        );
+    }
+
+    #[test]
+    fn test_classify_rpc_error() {
+        assert_eq!(
+            classify_rpc_error(&rpc_error(-32005, "limit exceeded")),
+            ErrorKind::Rejected { retry_after: None }
+        );
+        assert_eq!(
+            classify_rpc_error(&rpc_error(-32000, "header not found")),
+            ErrorKind::Transient { retry_after: None }
+        );
+        assert_eq!(
+            classify_rpc_error(&rpc_error(-32000, "Too Many Requests")),
+            ErrorKind::Rejected { retry_after: None }
+        );
+        assert_eq!(
+            classify_rpc_error(&rpc_error(3, "execution reverted")),
+            ErrorKind::Deterministic
+        );
+        assert_eq!(
+            classify_rpc_error(&rpc_error(-32602, "invalid params")),
+            ErrorKind::Deterministic
+        );
+        assert_eq!(
+            classify_rpc_error(&rpc_error(-32000, "nonce too low")),
+            ErrorKind::Deterministic
+        );
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+
+        for attempt in 0..10 {
+            let cap = Duration::from_millis(100 * 2u64.pow(attempt)).min(policy.max_delay);
+            assert!(policy.backoff(attempt) <= cap);
+        }
+    }
+
+    #[test]
+    fn test_next_delay() {
+        let policy = RetryPolicy::default();
+        let transient = ErrorKind::Transient { retry_after: None };
+        let rejected = ErrorKind::Rejected {
+            retry_after: Some(Duration::from_secs(1)),
+        };
+
+        assert!(policy.next_delay(0, transient, true).is_some());
+        assert_eq!(policy.next_delay(2, transient, true), None);
+        assert_eq!(policy.next_delay(0, ErrorKind::Deterministic, true), None);
+
+        // a send may have reached the node, only explicit rejections are safe to repeat
+        assert_eq!(policy.next_delay(0, transient, false), None);
+        assert_eq!(
+            policy.next_delay(0, rejected, false),
+            Some(Duration::from_secs(1))
+        );
+
+        let too_long = ErrorKind::Rejected {
+            retry_after: Some(Duration::from_secs(60)),
+        };
+        assert_eq!(policy.next_delay(0, too_long, true), None);
+        assert_eq!(RetryPolicy::none().next_delay(0, rejected, true), None);
+    }
+
+    #[test]
+    fn test_is_idempotent() {
+        assert!(is_idempotent("eth_call"));
+        assert!(!is_idempotent("eth_sendRawTransaction"));
+        assert!(!is_idempotent("sendTransaction"));
+    }
+}
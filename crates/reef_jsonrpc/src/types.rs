@@ -33,6 +33,9 @@ impl JsonRpcRequest {
 pub struct JsonRpcError {
     pub code: i32,
     pub message: String,
+    /// Extra error payload, e.g. the revert data of a failed `eth_call`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
 }
 
 impl Display for JsonRpcError {
@@ -72,20 +75,9 @@ impl<T> JsonRpcResult<T> {
 pub struct JsonRpcResults<T>(pub Vec<JsonRpcResult<T>>);
 
 impl<T> JsonRpcResults<T> {
-    pub fn extract(self) -> Vec<T> {
-        let mut extracted = Vec::new();
-        for (i, result) in self.0.into_iter().enumerate() {
-            match result {
-                JsonRpcResult::Value(response) => {
-                    extracted.push(response.result);
-                }
-                JsonRpcResult::Error(error) => {
-                    eprintln!("Batch call error for request {}: {:?}", i, error);
-                    // Continue processing other results
-                }
-            }
-        }
-        extracted
+    /// One result per request, failed items stay in place so indexes line up
+    pub fn extract(self) -> Vec<Result<T, JsonRpcError>> {
+        self.0.into_iter().map(JsonRpcResult::take).collect()
     }
 }
 
@@ -130,11 +122,25 @@ mod tests {
         let error = JsonRpcError {
             code: ERROR_INVALID_REQUEST,
             message: "Invalid request".to_string(),
+            data: None,
         };
 
         assert_eq!(error.to_string(), "Invalid request (-32600)");
     }
 
+    #[test]
+    fn test_jsonrpc_error_data() {
+        let response: JsonRpcResult<String> = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": { "code": 3, "message": "execution reverted", "data": "0x556f1830" }
+        }))
+        .unwrap();
+
+        let error = response.take().unwrap_err();
+        assert_eq!(error.data, Some(json!("0x556f1830")));
+    }
+
     #[test]
     fn test_jsonrpc_result_take_success() {
         let response = JsonRpcResponse {
@@ -153,6 +159,7 @@ mod tests {
             error: JsonRpcError {
                 code: ERROR_METHOD_NOT_FOUND,
                 message: "Method not found".to_string(),
+                data: None,
             },
         };
         let result = JsonRpcResult::<String>::Error(error_response);
@@ -174,6 +181,7 @@ mod tests {
                 error: JsonRpcError {
                     code: -1,
                     message: "error".to_string(),
+                    data: None,
                 },
             }),
             JsonRpcResult::Value(JsonRpcResponse {
@@ -185,9 +193,10 @@ mod tests {
         let json_rpc_results = JsonRpcResults(results);
         let extracted = json_rpc_results.extract();
 
-        assert_eq!(extracted.len(), 2);
-        assert_eq!(extracted[0], "value1");
-        assert_eq!(extracted[1], "value2");
+        assert_eq!(extracted.len(), 3);
+        assert_eq!(extracted[0].as_ref().unwrap(), "value1");
+        assert_eq!(extracted[1].as_ref().unwrap_err().message, "error");
+        assert_eq!(extracted[2].as_ref().unwrap(), "value2");
     }
 
     #[test]
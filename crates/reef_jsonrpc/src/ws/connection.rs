@@ -0,0 +1,434 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::pin;
+use std::time::Duration;
+
+use futures::{SinkExt, StreamExt};
+use reef_client::ClientError;
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::types::JsonRpcError;
+
+const MIN_RECONNECT_DELAY: Duration = Duration::from_millis(100);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+pub(crate) enum Command {
+    /// Raw JSON-RPC message, single or batch, answered per id
+    Request {
+        message: String,
+        replies: Vec<(u64, oneshot::Sender<Value>)>,
+    },
+    Subscribe {
+        id: u64,
+        request: SubscribeRequest,
+        notifications: mpsc::UnboundedSender<Value>,
+        reply: oneshot::Sender<Result<(), JsonRpcError>>,
+    },
+    Unsubscribe {
+        id: u64,
+    },
+    /// Request ids whose caller gave up, a late response is dropped
+    Cancel {
+        ids: Vec<u64>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SubscribeRequest {
+    pub method: String,
+    pub params: Value,
+    pub unsubscribe_method: String,
+    /// Subscriptions the node removes after the first notification, e.g. `signatureSubscribe`
+    pub once: bool,
+}
+
+struct Subscription {
+    request: SubscribeRequest,
+    notifications: mpsc::UnboundedSender<Value>,
+    /// Answered once the first subscribe succeeds, later resubscribes are silent
+    reply: Option<oneshot::Sender<Result<(), JsonRpcError>>>,
+    server_id: Option<String>,
+}
+
+/// State of one connection task, subscriptions outlive the socket they were made on
+#[derive(Default)]
+struct State {
+    pending: HashMap<u64, oneshot::Sender<Value>>,
+    subscriptions: HashMap<u64, Subscription>,
+    /// Subscribe request id to subscription id
+    subscribing: HashMap<u64, u64>,
+    /// Server subscription id to subscription id
+    server_ids: HashMap<String, u64>,
+    /// Subscribe request id to unsubscribe method, for subscriptions dropped before the ack
+    cancelled: HashMap<u64, String>,
+    next_id: u64,
+}
+
+/// Subscription ids are numbers on Solana and hex strings on EVM nodes
+fn server_id(value: &Value) -> Option<String> {
+    match value {
+        Value::String(x) => Some(x.clone()),
+        Value::Number(x) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+impl State {
+    /// Ids for internal requests, kept apart from the client's own counter
+    fn internal_id(&mut self) -> u64 {
+        self.next_id += 1;
+        u64::MAX - self.next_id
+    }
+
+    fn subscribe_message(&mut self, id: u64) -> Option<String> {
+        let request_id = self.internal_id();
+        let subscription = self.subscriptions.get(&id)?;
+        let message = json!({
+            "jsonrpc": "2.0",
+            "id": request_id,
+            "method": subscription.request.method,
+            "params": subscription.request.params,
+        });
+        self.subscribing.insert(request_id, id);
+        Some(message.to_string())
+    }
+
+    fn unsubscribe_message(&mut self, id: u64) -> Option<String> {
+        let subscription = self.subscriptions.remove(&id)?;
+        let Some(server_id) = subscription.server_id else {
+            // still waiting for the ack, unsubscribe once the server id arrives
+            let requests: Vec<u64> = self
+                .subscribing
+                .iter()
+                .filter(|(_, x)| **x == id)
+                .map(|(request_id, _)| *request_id)
+                .collect();
+            for request_id in requests {
+                self.subscribing.remove(&request_id);
+                self.cancelled
+                    .insert(request_id, subscription.request.unsubscribe_method.clone());
+            }
+            return None;
+        };
+        self.server_ids.remove(&server_id);
+        Some(self.unsubscribe_server_id(&subscription.request.unsubscribe_method, server_id))
+    }
+
+    fn unsubscribe_server_id(&mut self, method: &str, server_id: String) -> String {
+        let message = json!({
+            "jsonrpc": "2.0",
+            "id": self.internal_id(),
+            "method": method,
+            "params": [server_id.parse::<u64>().map(Value::from).unwrap_or(Value::String(server_id))],
+        });
+        message.to_string()
+    }
+
+    /// Subscribes or drops subscriptions while there is no socket, calls fail right away
+    fn offline(&mut self, command: Command) {
+        match command {
+            Command::Request { .. } => {}
+            Command::Subscribe {
+                id,
+                request,
+                notifications,
+                reply,
+            } => {
+                self.subscriptions.insert(
+                    id,
+                    Subscription {
+                        request,
+                        notifications,
+                        reply: Some(reply),
+                        server_id: None,
+                    },
+                );
+            }
+            Command::Unsubscribe { id } => {
+                self.subscriptions.remove(&id);
+            }
+            Command::Cancel { ids } => self.cancel(&ids),
+        }
+    }
+
+    fn cancel(&mut self, ids: &[u64]) {
+        for id in ids {
+            self.pending.remove(id);
+        }
+    }
+
+    /// Returns the messages to send in reply, e.g. unsubscribes for late acks
+    fn handle_text(&mut self, text: &str) -> Vec<String> {
+        match serde_json::from_str::<Value>(text) {
+            Ok(Value::Array(messages)) => messages
+                .into_iter()
+                .filter_map(|x| self.handle(x))
+                .collect(),
+            Ok(message) => self.handle(message).into_iter().collect(),
+            Err(error) => {
+                tracing::warn!("invalid websocket message: {}", error);
+                vec![]
+            }
+        }
+    }
+
+    fn handle(&mut self, message: Value) -> Option<String> {
+        if let Some(id) = message.get("id").and_then(Value::as_u64) {
+            if let Some(reply) = self.pending.remove(&id) {
+                let _ = reply.send(message);
+            } else if let Some(subscription_id) = self.subscribing.remove(&id) {
+                self.handle_subscribed(subscription_id, message);
+            } else if let Some(method) = self.cancelled.remove(&id) {
+                let server_id = message.get("result").and_then(server_id)?;
+                return Some(self.unsubscribe_server_id(&method, server_id));
+            }
+            return None;
+        }
+
+        // `eth_subscription`, `accountNotification`, `slotNotification`, ...
+        let params = message.get("params")?;
+        let subscription_id = params
+            .get("subscription")
+            .and_then(server_id)
+            .and_then(|x| self.server_ids.get(&x).copied())?;
+        let result = params.get("result").cloned().unwrap_or(Value::Null);
+        let subscription = self.subscriptions.get(&subscription_id)?;
+        let closed = subscription.notifications.send(result).is_err();
+        if subscription.request.once {
+            // the node already removed it
+            if let Some(subscription) = self.subscriptions.remove(&subscription_id) {
+                subscription.server_id.map(|x| self.server_ids.remove(&x));
+            }
+        } else if closed {
+            return self.unsubscribe_message(subscription_id);
+        }
+        None
+    }
+
+    fn handle_subscribed(&mut self, id: u64, message: Value) {
+        let Some(subscription) = self.subscriptions.get_mut(&id) else {
+            return;
+        };
+        match message.get("result").and_then(server_id) {
+            Some(server_id) => {
+                subscription.server_id = Some(server_id.clone());
+                if let Some(reply) = subscription.reply.take() {
+                    let _ = reply.send(Ok(()));
+                }
+                self.server_ids.insert(server_id, id);
+            }
+            None => {
+                let error = message
+                    .get("error")
+                    .and_then(|x| serde_json::from_value::<JsonRpcError>(x.clone()).ok())
+                    .unwrap_or_else(|| JsonRpcError {
+                        code: crate::types::ERROR_INTERNAL_ERROR,
+                        message: "Invalid subscribe response".to_string(),
+                        data: None,
+                    });
+                tracing::warn!(method = %subscription.request.method, "subscribe failed: {}", error);
+                // dropping the sender ends the stream
+                if let Some(subscription) = self.subscriptions.remove(&id) {
+                    if let Some(reply) = subscription.reply {
+                        let _ = reply.send(Err(error));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Pending calls fail, subscriptions wait for the next connection
+    fn disconnected(&mut self) {
+        self.pending.clear();
+        self.subscribing.clear();
+        self.server_ids.clear();
+        self.cancelled.clear();
+        for subscription in self.subscriptions.values_mut() {
+            subscription.server_id = None;
+        }
+    }
+}
+
+/// Waits for `future` while applying commands offline, `None` once every handle is dropped
+async fn offline<F: Future>(
+    future: F,
+    state: &mut State,
+    commands: &mut mpsc::UnboundedReceiver<Command>,
+) -> Option<F::Output> {
+    let mut future = pin!(future);
+    loop {
+        tokio::select! {
+            output = &mut future => return Some(output),
+            command = commands.recv() => state.offline(command?),
+        }
+    }
+}
+
+/// Owns the socket, reconnecting with backoff until every handle is dropped
+pub(crate) async fn run(
+    url: String,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    connected: oneshot::Sender<Result<(), ClientError>>,
+) {
+    let mut state = State::default();
+    let mut connected = Some(connected);
+    let mut delay = MIN_RECONNECT_DELAY;
+
+    loop {
+        let Some(result) = offline(connect_async(url.as_str()), &mut state, &mut commands).await
+        else {
+            return;
+        };
+        let socket = match result {
+            Ok((socket, _)) => socket,
+            Err(error) => {
+                if let Some(connected) = connected.take() {
+                    let _ = connected.send(Err(ClientError::NetworkError(error.to_string())));
+                    return;
+                }
+                tracing::warn!("websocket reconnect failed: {}", error);
+                if offline(tokio::time::sleep(delay), &mut state, &mut commands)
+                    .await
+                    .is_none()
+                {
+                    return;
+                }
+                delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+                continue;
+            }
+        };
+        if let Some(connected) = connected.take() {
+            let _ = connected.send(Ok(()));
+        }
+        delay = MIN_RECONNECT_DELAY;
+        let (mut sink, mut source) = socket.split();
+
+        let ids: Vec<u64> = state.subscriptions.keys().copied().collect();
+        let mut messages: Vec<String> = ids
+            .into_iter()
+            .filter_map(|id| state.subscribe_message(id))
+            .collect();
+
+        'connection: loop {
+            for message in messages.drain(..) {
+                if sink.send(Message::text(message)).await.is_err() {
+                    break 'connection;
+                }
+            }
+
+            tokio::select! {
+                command = commands.recv() => match command {
+                    None => return,
+                    Some(Command::Request { message, replies }) => {
+                        state.pending.extend(replies);
+                        messages.push(message);
+                    }
+                    Some(Command::Subscribe { id, request, notifications, reply }) => {
+                        state.subscriptions.insert(id, Subscription {
+                            request,
+                            notifications,
+                            reply: Some(reply),
+                            server_id: None,
+                        });
+                        messages.extend(state.subscribe_message(id));
+                    }
+                    Some(Command::Unsubscribe { id }) => {
+                        messages.extend(state.unsubscribe_message(id));
+                    }
+                    Some(Command::Cancel { ids }) => state.cancel(&ids),
+                },
+                message = source.next() => match message {
+                    Some(Ok(Message::Text(text))) => messages.extend(state.handle_text(&text)),
+                    Some(Ok(Message::Binary(data))) => {
+                        messages.extend(state.handle_text(&String::from_utf8_lossy(&data)))
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break 'connection,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+
+        tracing::warn!("websocket disconnected, reconnecting");
+        state.disconnected();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    fn subscribe(state: &mut State, id: u64) -> u64 {
+        let (notifications, _) = mpsc::unbounded_channel();
+        let (reply, _) = oneshot::channel();
+        state.offline(Command::Subscribe {
+            id,
+            request: SubscribeRequest {
+                method: "eth_subscribe".to_string(),
+                params: json!(["newHeads"]),
+                unsubscribe_method: "eth_unsubscribe".to_string(),
+                once: false,
+            },
+            notifications,
+            reply,
+        });
+        let message: Value = serde_json::from_str(&state.subscribe_message(id).unwrap()).unwrap();
+        message["id"].as_u64().unwrap()
+    }
+
+    #[test]
+    fn test_unsubscribe_after_late_ack() {
+        let mut state = State::default();
+        let request_id = subscribe(&mut state, 1);
+
+        // dropped before the node answered
+        assert_eq!(state.unsubscribe_message(1), None);
+        let ack = json!({ "jsonrpc": "2.0", "id": request_id, "result": "0xabc" });
+        let messages = state.handle_text(&ack.to_string());
+        assert_eq!(messages.len(), 1);
+        let message: Value = serde_json::from_str(&messages[0]).unwrap();
+        assert_eq!(message["method"], "eth_unsubscribe");
+        assert_eq!(message["params"], json!(["0xabc"]));
+        assert!(state.cancelled.is_empty());
+        assert!(state.server_ids.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_pending_request() {
+        let mut state = State::default();
+        let (reply, mut receiver) = oneshot::channel();
+        state.pending.insert(7, reply);
+
+        state.offline(Command::Cancel { ids: vec![7] });
+        assert!(state.pending.is_empty());
+        assert!(receiver.try_recv().is_err());
+        // a late response is ignored
+        let response = json!({ "jsonrpc": "2.0", "id": 7, "result": "0x1" });
+        assert!(state.handle_text(&response.to_string()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_stops_while_disconnected() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let (connected, result) = oneshot::channel();
+        let task = tokio::spawn(run(url, receiver, connected));
+
+        // accept one connection, then close it and stop listening
+        let (stream, _) = listener.accept().await.unwrap();
+        let socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+        result.await.unwrap().unwrap();
+        drop(socket);
+        drop(listener);
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        drop(commands);
+        tokio::time::timeout(Duration::from_secs(1), task)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+}
@@ -0,0 +1,392 @@
+mod connection;
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::Stream;
+use reef_client::{Client, ClientError};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::types::JsonRpcError;
+use connection::{Command, SubscribeRequest};
+
+pub const DEFAULT_WS_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// JSON-RPC over a WebSocket, reconnecting and resubscribing in the background.
+///
+/// Implements [`Client`] so it can back a [`crate::JsonRpcClient`] like the HTTP transport.
+#[derive(Debug, Clone)]
+pub struct WsClient {
+    url: String,
+    commands: mpsc::UnboundedSender<Command>,
+    next_subscription: Arc<AtomicU64>,
+    timeout: Duration,
+}
+
+impl WsClient {
+    /// Fails if the first connection cannot be made, later drops are retried
+    pub async fn connect(url: impl Into<String>) -> Result<Self, ClientError> {
+        let url = url.into();
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let (connected, result) = oneshot::channel();
+        tokio::spawn(connection::run(url.clone(), receiver, connected));
+        result
+            .await
+            .map_err(|_| ClientError::NetworkError("Connection task stopped".to_string()))??;
+
+        Ok(Self {
+            url,
+            commands,
+            next_subscription: Arc::new(AtomicU64::new(1)),
+            timeout: DEFAULT_WS_TIMEOUT,
+        })
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub async fn subscribe<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+        unsubscribe_method: &str,
+    ) -> Result<Subscription<T>, JsonRpcError> {
+        self.subscribe_request(SubscribeRequest {
+            method: method.to_string(),
+            params,
+            unsubscribe_method: unsubscribe_method.to_string(),
+            once: false,
+        })
+        .await
+    }
+
+    async fn subscribe_request<T: DeserializeOwned>(
+        &self,
+        request: SubscribeRequest,
+    ) -> Result<Subscription<T>, JsonRpcError> {
+        let id = self.next_subscription.fetch_add(1, Ordering::Relaxed);
+        let (notifications, receiver) = mpsc::unbounded_channel();
+        let (reply, result) = oneshot::channel();
+        self.commands
+            .send(Command::Subscribe {
+                id,
+                request,
+                notifications,
+                reply,
+            })
+            .map_err(|_| JsonRpcError::from(closed()))?;
+
+        match tokio::time::timeout(self.timeout, result).await {
+            Ok(Ok(Ok(()))) => Ok(Subscription {
+                id,
+                commands: self.commands.clone(),
+                receiver,
+                _marker: PhantomData,
+            }),
+            Ok(Ok(Err(error))) => Err(error),
+            Ok(Err(_)) => Err(closed().into()),
+            Err(_) => {
+                let _ = self.commands.send(Command::Unsubscribe { id });
+                Err(ClientError::Timeout(format!("{} timed out", self.url)).into())
+            }
+        }
+    }
+
+    /// `eth_subscribe` new block headers
+    pub async fn new_heads(&self) -> Result<Subscription<Value>, JsonRpcError> {
+        self.subscribe("eth_subscribe", json!(["newHeads"]), "eth_unsubscribe")
+            .await
+    }
+
+    /// `eth_subscribe` logs matching `filter`, e.g. `{"address": ..., "topics": [...]}`
+    pub async fn logs(&self, filter: Value) -> Result<Subscription<Value>, JsonRpcError> {
+        self.subscribe("eth_subscribe", json!(["logs", filter]), "eth_unsubscribe")
+            .await
+    }
+
+    /// Solana account changes, `config` e.g. `{"encoding": "jsonParsed", "commitment": "confirmed"}`
+    pub async fn account_subscribe(
+        &self,
+        pubkey: &str,
+        config: Value,
+    ) -> Result<Subscription<Value>, JsonRpcError> {
+        self.subscribe(
+            "accountSubscribe",
+            json!([pubkey, config]),
+            "accountUnsubscribe",
+        )
+        .await
+    }
+
+    /// Solana signature status, the stream ends after the first notification
+    pub async fn signature_subscribe(
+        &self,
+        signature: &str,
+        config: Value,
+    ) -> Result<Subscription<Value>, JsonRpcError> {
+        self.subscribe_request(SubscribeRequest {
+            method: "signatureSubscribe".to_string(),
+            params: json!([signature, config]),
+            unsubscribe_method: "signatureUnsubscribe".to_string(),
+            once: true,
+        })
+        .await
+    }
+
+    pub async fn slot_subscribe(&self) -> Result<Subscription<Value>, JsonRpcError> {
+        self.subscribe("slotSubscribe", json!([]), "slotUnsubscribe")
+            .await
+    }
+}
+
+fn closed() -> ClientError {
+    ClientError::NetworkError("WebSocket connection closed".to_string())
+}
+
+/// Request ids of a single call or a batch
+fn request_ids(body: &Value) -> Result<Vec<u64>, ClientError> {
+    let id = |x: &Value| {
+        x.get("id")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| ClientError::RequestFailed("Request without numeric id".to_string()))
+    };
+    match body {
+        Value::Array(requests) => requests.iter().map(id).collect(),
+        request => Ok(vec![id(request)?]),
+    }
+}
+
+#[async_trait]
+impl Client for WsClient {
+    fn base_url(&self) -> &str {
+        &self.url
+    }
+
+    async fn get<R>(&self, _path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        Err(ClientError::RequestFailed(
+            "GET is not supported over WebSocket".to_string(),
+        ))
+    }
+
+    async fn post<T, R>(
+        &self,
+        _path: &str,
+        body: &T,
+        _headers: Option<HashMap<String, String>>,
+    ) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        let body = serde_json::to_value(body)
+            .map_err(|e| ClientError::SerializationError(e.to_string()))?;
+        let ids = request_ids(&body)?;
+
+        let (replies, receivers): (Vec<_>, Vec<_>) = ids
+            .iter()
+            .map(|id| {
+                let (sender, receiver) = oneshot::channel();
+                ((*id, sender), receiver)
+            })
+            .unzip();
+        self.commands
+            .send(Command::Request {
+                message: body.to_string(),
+                replies,
+            })
+            .map_err(|_| closed())?;
+
+        let responses = match tokio::time::timeout(
+            self.timeout,
+            futures::future::try_join_all(receivers),
+        )
+        .await
+        {
+            Ok(responses) => responses.map_err(|_| closed())?,
+            Err(_) => {
+                let _ = self.commands.send(Command::Cancel { ids });
+                return Err(ClientError::Timeout(format!("{} timed out", self.url)));
+            }
+        };
+
+        let response = match body {
+            Value::Array(_) => Value::Array(responses),
+            _ => responses.into_iter().next().unwrap_or(Value::Null),
+        };
+        serde_json::from_value(response).map_err(|e| ClientError::SerializationError(e.to_string()))
+    }
+}
+
+/// Notifications of one subscription, unsubscribes on drop.
+///
+/// Survives reconnects, the stream only ends if the node rejects the resubscribe
+/// or the subscription is one-shot.
+#[derive(Debug)]
+pub struct Subscription<T> {
+    id: u64,
+    commands: mpsc::UnboundedSender<Command>,
+    receiver: mpsc::UnboundedReceiver<Value>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> Stream for Subscription<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        loop {
+            match self.receiver.poll_recv(cx) {
+                Poll::Ready(Some(value)) => match serde_json::from_value(value) {
+                    Ok(item) => return Poll::Ready(Some(item)),
+                    Err(error) => tracing::warn!("invalid notification: {}", error),
+                },
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        let _ = self.commands.send(Command::Unsubscribe { id: self.id });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::JsonRpcClient;
+    use futures::{SinkExt, StreamExt};
+    use std::sync::atomic::AtomicUsize;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// Answers calls with their id, acknowledges subscriptions with `sub-<connection>` and
+    /// pushes two notifications. The first connection is dropped after the first notification.
+    async fn mock_node() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let connection = counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                    while let Some(Ok(Message::Text(text))) = socket.next().await {
+                        let request: Value = serde_json::from_str(&text).unwrap();
+                        let reply = |x: &Value| match x["method"].as_str().unwrap() {
+                            "eth_subscribe" | "slotSubscribe" => {
+                                json!({ "jsonrpc": "2.0", "id": x["id"], "result": format!("sub-{}", connection) })
+                            }
+                            _ => json!({ "jsonrpc": "2.0", "id": x["id"], "result": x["id"] }),
+                        };
+                        let response = match &request {
+                            Value::Array(calls) => Value::Array(calls.iter().map(reply).collect()),
+                            call => reply(call),
+                        };
+                        socket
+                            .send(Message::text(response.to_string()))
+                            .await
+                            .unwrap();
+
+                        if request["method"] == "eth_subscribe" {
+                            for block in 0..2 {
+                                let notification = json!({
+                                    "jsonrpc": "2.0",
+                                    "method": "eth_subscription",
+                                    "params": {
+                                        "subscription": format!("sub-{}", connection),
+                                        "result": { "connection": connection, "block": block },
+                                    },
+                                });
+                                socket
+                                    .send(Message::text(notification.to_string()))
+                                    .await
+                                    .unwrap();
+                                if connection == 0 {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                });
+            }
+        });
+        (url, connections)
+    }
+
+    #[tokio::test]
+    async fn test_call_over_websocket() {
+        let (url, _) = mock_node().await;
+        let client = JsonRpcClient::new(WsClient::connect(url).await.unwrap());
+
+        let id: u64 = client.call("eth_blockNumber", json!([])).await.unwrap();
+        assert!(id > 0);
+
+        let ids = client
+            .batch_call::<u64>(vec![
+                ("eth_chainId".to_string(), json!([])),
+                ("eth_gasPrice".to_string(), json!([])),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.iter().all(|x| x.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_connect_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let error = WsClient::connect(url).await.unwrap_err();
+        assert!(matches!(error, ClientError::NetworkError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_subscription_resubscribes_after_reconnect() {
+        let (url, connections) = mock_node().await;
+        let client = WsClient::connect(url).await.unwrap();
+        let mut heads = client.new_heads().await.unwrap();
+
+        let first = heads.next().await.unwrap();
+        assert_eq!(first, json!({ "connection": 0, "block": 0 }));
+
+        // the node dropped the socket, the next notification comes from the resubscription
+        let next = tokio::time::timeout(Duration::from_secs(5), heads.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(next, json!({ "connection": 1, "block": 0 }));
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+
+        let next = heads.next().await.unwrap();
+        assert_eq!(next, json!({ "connection": 1, "block": 1 }));
+    }
+
+    #[test]
+    fn test_request_ids() {
+        assert_eq!(request_ids(&json!({ "id": 7 })).unwrap(), vec![7]);
+        assert_eq!(
+            request_ids(&json!([{ "id": 1 }, { "id": 2 }])).unwrap(),
+            vec![1, 2]
+        );
+        assert!(request_ids(&json!({ "method": "eth_chainId" })).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+/// Move 地址为 32 字节，长格式为 `0x` 加 64 位十六进制
+pub const ADDRESS_HEX_LENGTH: usize = 64;
+
+fn parse_hex(address: &str) -> Result<&str, String> {
+    let hex = address
+        .strip_prefix("0x")
+        .ok_or_else(|| format!("Invalid address {}: missing 0x prefix", address))?;
+    if hex.is_empty() || hex.len() > ADDRESS_HEX_LENGTH {
+        return Err(format!("Invalid address {}: bad length", address));
+    }
+    if !hex.chars().all(|x| x.is_ascii_hexdigit()) {
+        return Err(format!("Invalid address {}: not hex", address));
+    }
+    Ok(hex)
+}
+
+/// Sui 只接受长格式地址
+pub fn validate_sui_address(address: &str) -> Result<(), String> {
+    let hex = parse_hex(address)?;
+    if hex.len() != ADDRESS_HEX_LENGTH {
+        return Err(format!("Invalid address {}: bad length", address));
+    }
+    Ok(())
+}
+
+/// Aptos 还接受 `0x1` 这类短格式
+pub fn validate_aptos_address(address: &str) -> Result<(), String> {
+    parse_hex(address).map(|_| ())
+}
+
+/// 补齐为小写长格式，例如 `0x1` -> `0x000...001`
+pub fn normalize_address(address: &str) -> Result<String, String> {
+    let hex = parse_hex(address)?;
+    Ok(format!(
+        "0x{:0>width$}",
+        hex.to_lowercase(),
+        width = ADDRESS_HEX_LENGTH
+    ))
+}
+
+/// 币种类型形如 `0x2::sui::SUI`，泛型参数不参与校验
+pub fn is_coin_type(coin_type: &str) -> bool {
+    let base = coin_type.split('<').next().unwrap_or_default();
+    let parts: Vec<&str> = base.split("::").collect();
+    parts.len() == 3
+        && parse_hex(parts[0]).is_ok()
+        && parts[1..]
+            .iter()
+            .all(|x| !x.is_empty() && x.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+}
+
+/// 币种类型中的发行地址
+pub fn coin_type_address(coin_type: &str) -> Option<&str> {
+    coin_type
+        .split("::")
+        .next()
+        .filter(|x| parse_hex(x).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUI_ADDRESS: &str = "0x5f9a4a3b6e7c1d2e8f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c9d8e";
+
+    #[test]
+    fn test_validate_address() {
+        assert!(validate_sui_address(SUI_ADDRESS).is_ok());
+        assert!(validate_sui_address("0x2").is_err());
+        assert!(validate_sui_address(&SUI_ADDRESS[2..]).is_err());
+        assert!(validate_aptos_address("0x1").is_ok());
+        assert!(validate_aptos_address(SUI_ADDRESS).is_ok());
+        assert!(validate_aptos_address("0xzz").is_err());
+        assert!(validate_aptos_address(&format!("{}00", SUI_ADDRESS)).is_err());
+    }
+
+    #[test]
+    fn test_normalize_address() {
+        assert_eq!(
+            normalize_address("0xA").unwrap(),
+            format!("0x{}a", "0".repeat(63))
+        );
+        assert_eq!(normalize_address(SUI_ADDRESS).unwrap(), SUI_ADDRESS);
+    }
+
+    #[test]
+    fn test_coin_type() {
+        assert!(is_coin_type("0x2::sui::SUI"));
+        assert!(is_coin_type("0x1::aptos_coin::AptosCoin"));
+        assert!(is_coin_type(
+            "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>"
+        ));
+        assert!(!is_coin_type("0x2::sui"));
+        assert!(!is_coin_type("sui::SUI::x"));
+        assert_eq!(coin_type_address("0x2::sui::SUI"), Some("0x2"));
+        assert_eq!(coin_type_address("SUI"), None);
+    }
+}
@@ -0,0 +1,409 @@
+use crate::address::{coin_type_address, is_coin_type, normalize_address, validate_aptos_address};
+use crate::aptos::models::{
+    AptosCoinInfo, AptosResource, AptosTransaction, AptosViewRequest, FungibleAssetBalance,
+    FungibleAssetBalancesData, GraphQlRequest, GraphQlResponse,
+};
+use crate::aptos::{APTOS_INDEXER_URL, BCS_SIGNED_TRANSACTION};
+use num_bigint::BigUint;
+use primitives::{Chain, TransactionStatus, TransactionUpdate};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use serde::Deserialize;
+use serde_json::json;
+use std::error::Error;
+
+/// indexer 单页数量与最大页数
+const INDEXER_PAGE_SIZE: usize = 100;
+const INDEXER_MAX_PAGES: usize = 10;
+
+const FUNGIBLE_ASSET_BALANCES_QUERY: &str = r#"query($owner: String!, $limit: Int!, $offset: Int!) {
+  current_fungible_asset_balances(
+    where: { owner_address: { _eq: $owner }, amount: { _gt: "0" } }
+    order_by: { asset_type: asc }
+    limit: $limit
+    offset: $offset
+  ) { asset_type amount metadata { name symbol decimals } }
+}"#;
+
+/// 交易 hash 为 `0x` 加 64 位十六进制
+fn validate_hash(hash: &str) -> Result<(), String> {
+    match hash.strip_prefix("0x") {
+        Some(hex) if hex.len() == 64 && hex.chars().all(|x| x.is_ascii_hexdigit()) => Ok(()),
+        _ => Err(format!("Invalid transaction hash: {}", hash)),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ResourceResponse<T> {
+    data: T,
+}
+
+/// Aptos 全节点 REST 客户端
+#[derive(Debug, Clone)]
+pub struct AptosClient<C: Client + Clone = ReqwestClient> {
+    client: C,
+    /// 没有 indexer 时只能读取 CoinStore，看不到 fungible store 中的代币
+    indexer: Option<C>,
+}
+
+impl AptosClient {
+    pub fn new(url: String) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url))
+            .with_indexer(ReqwestClient::new_with_url(APTOS_INDEXER_URL.to_string()))
+    }
+
+    /// 节点凭据不会发送给 indexer
+    pub fn new_with_config(url: String, config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(
+            Self::new_with_client(ReqwestClient::new_with_config(url, config)?)
+                .with_indexer(ReqwestClient::new_with_url(APTOS_INDEXER_URL.to_string())),
+        )
+    }
+}
+
+impl<C: Client + Clone> AptosClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C) -> Self {
+        Self {
+            client,
+            indexer: None,
+        }
+    }
+
+    pub fn with_indexer(mut self, indexer: C) -> Self {
+        self.indexer = Some(indexer);
+        self
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        Chain::Aptos
+    }
+
+    pub fn validate_address(&self, address: &str) -> Result<(), String> {
+        validate_aptos_address(address)
+    }
+
+    /// 账户不存在时返回空列表
+    pub async fn get_account_resources(
+        &self,
+        address: &str,
+    ) -> Result<Vec<AptosResource>, Box<dyn Error + Send + Sync>> {
+        validate_aptos_address(address)?;
+        let path = format!("/v1/accounts/{}/resources", address);
+        match self.client.get(&path).await {
+            Ok(resources) => Ok(resources),
+            Err(ClientError::HttpError { status: 404, .. }) => Ok(vec![]),
+            Err(e) => Err(format!("Failed to get account resources: {}", e).into()),
+        }
+    }
+
+    /// `0x1::coin::balance` 同时统计 CoinStore 与迁移后的 fungible asset
+    pub async fn get_balance(
+        &self,
+        address: &str,
+        coin_type: &str,
+    ) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+        let request = AptosViewRequest {
+            function: "0x1::coin::balance".to_string(),
+            type_arguments: vec![coin_type.to_string()],
+            arguments: vec![json!(address)],
+        };
+        let response: Vec<String> = self
+            .client
+            .post("/v1/view", &request, None)
+            .await
+            .map_err(|e| format!("Failed to get balance: {}", e))?;
+        let balance = response.first().ok_or("Empty view response")?;
+        Ok(balance
+            .parse()
+            .map_err(|e| format!("Invalid balance {}: {}", balance, e))?)
+    }
+
+    /// 账户 CoinStore 中的所有币种余额
+    pub async fn get_coin_balances(
+        &self,
+        address: &str,
+    ) -> Result<Vec<(String, BigUint)>, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .get_account_resources(address)
+            .await?
+            .iter()
+            .filter_map(|x| {
+                let coin_type = x.coin_store_type()?;
+                let value = x.coin_store_value()?.parse().ok()?;
+                Some((coin_type.to_string(), value))
+            })
+            .collect())
+    }
+
+    /// `0x1::primary_fungible_store::balance`，`metadata` 为 FA 元数据对象地址
+    pub async fn get_fungible_asset_balance(
+        &self,
+        address: &str,
+        metadata: &str,
+    ) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+        let request = AptosViewRequest {
+            function: "0x1::primary_fungible_store::balance".to_string(),
+            type_arguments: vec!["0x1::fungible_asset::Metadata".to_string()],
+            arguments: vec![json!(address), json!(metadata)],
+        };
+        let response: Vec<String> = self
+            .client
+            .post("/v1/view", &request, None)
+            .await
+            .map_err(|e| format!("Failed to get fungible asset balance: {}", e))?;
+        let balance = response.first().ok_or("Empty view response")?;
+        Ok(balance
+            .parse()
+            .map_err(|e| format!("Invalid balance {}: {}", balance, e))?)
+    }
+
+    /// indexer 中的非零余额，包括 CoinStore 与 fungible store，未配置 indexer 时返回 `None`
+    pub async fn get_fungible_asset_balances(
+        &self,
+        address: &str,
+    ) -> Result<Option<Vec<FungibleAssetBalance>>, Box<dyn Error + Send + Sync>> {
+        let Some(indexer) = &self.indexer else {
+            return Ok(None);
+        };
+        // indexer 中的地址为小写长格式
+        let owner = normalize_address(address)?;
+        let mut balances = Vec::new();
+        for page in 0..INDEXER_MAX_PAGES {
+            let request = GraphQlRequest {
+                query: FUNGIBLE_ASSET_BALANCES_QUERY.to_string(),
+                variables: json!({
+                    "owner": owner,
+                    "limit": INDEXER_PAGE_SIZE,
+                    "offset": page * INDEXER_PAGE_SIZE,
+                }),
+            };
+            let response: GraphQlResponse<FungibleAssetBalancesData> = indexer
+                .post("", &request, None)
+                .await
+                .map_err(|e| format!("Failed to get fungible asset balances: {}", e))?;
+            if let Some(error) = response.errors.first() {
+                return Err(
+                    format!("Failed to get fungible asset balances: {}", error.message).into(),
+                );
+            }
+            let items = response
+                .data
+                .ok_or("Empty indexer response")?
+                .current_fungible_asset_balances;
+            let count = items.len();
+            balances.extend(items);
+            if count < INDEXER_PAGE_SIZE {
+                break;
+            }
+        }
+        Ok(Some(balances))
+    }
+
+    /// FA 元数据对象上的 `0x1::fungible_asset::Metadata`
+    pub async fn get_fungible_asset_metadata(
+        &self,
+        metadata: &str,
+    ) -> Result<AptosCoinInfo, Box<dyn Error + Send + Sync>> {
+        validate_aptos_address(metadata)?;
+        let path = format!(
+            "/v1/accounts/{}/resource/0x1::fungible_asset::Metadata",
+            metadata
+        );
+        let response: ResourceResponse<AptosCoinInfo> = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get fungible asset metadata: {}", e))?;
+        Ok(response.data)
+    }
+
+    /// 发行地址下的 `0x1::coin::CoinInfo<T>`
+    pub async fn get_coin_info(
+        &self,
+        coin_type: &str,
+    ) -> Result<AptosCoinInfo, Box<dyn Error + Send + Sync>> {
+        let address = coin_type_address(coin_type)
+            // 泛型参数不经 `is_coin_type` 校验，路径中不能出现其他字符
+            .filter(|_| {
+                is_coin_type(coin_type)
+                    && coin_type
+                        .chars()
+                        .all(|x| x.is_ascii_alphanumeric() || "_:<>, ".contains(x))
+            })
+            .ok_or_else(|| format!("Invalid coin type: {}", coin_type))?;
+        let path = format!(
+            "/v1/accounts/{}/resource/0x1::coin::CoinInfo<{}>",
+            address, coin_type
+        );
+        let response: ResourceResponse<AptosCoinInfo> = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get coin info: {}", e))?;
+        Ok(response.data)
+    }
+
+    /// 模拟执行，签名须为无效签名，否则节点拒绝
+    pub async fn simulate_transaction(
+        &self,
+        signed_transaction: Vec<u8>,
+    ) -> Result<AptosTransaction, Box<dyn Error + Send + Sync>> {
+        let response: Vec<AptosTransaction> = self
+            .client
+            .post_bytes(
+                "/v1/transactions/simulate",
+                signed_transaction,
+                BCS_SIGNED_TRANSACTION,
+            )
+            .await
+            .map_err(|e| format!("Failed to simulate transaction: {}", e))?;
+        Ok(response
+            .into_iter()
+            .next()
+            .ok_or("Empty simulation response")?)
+    }
+
+    /// 提交 BCS 编码的已签名交易，返回交易 hash
+    pub async fn broadcast_transaction(
+        &self,
+        signed_transaction: Vec<u8>,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let response: AptosTransaction = self
+            .client
+            .post_bytes(
+                "/v1/transactions",
+                signed_transaction,
+                BCS_SIGNED_TRANSACTION,
+            )
+            .await
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        Ok(response.hash)
+    }
+
+    pub async fn get_transaction(
+        &self,
+        hash: &str,
+    ) -> Result<Option<AptosTransaction>, Box<dyn Error + Send + Sync>> {
+        validate_hash(hash)?;
+        let path = format!("/v1/transactions/by_hash/{}", hash);
+        match self.client.get(&path).await {
+            Ok(transaction) => Ok(Some(transaction)),
+            // 未进入内存池或已过期
+            Err(ClientError::HttpError { status: 404, .. }) => Ok(None),
+            Err(e) => Err(format!("Failed to get transaction: {}", e).into()),
+        }
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        hash: &str,
+    ) -> Result<TransactionUpdate, Box<dyn Error + Send + Sync>> {
+        let Some(transaction) = self
+            .get_transaction(hash)
+            .await?
+            .filter(|x| !x.is_pending())
+        else {
+            return Ok(TransactionUpdate {
+                hash: hash.to_string(),
+                status: TransactionStatus::Pending,
+                block_number: None,
+                confirmations: 0,
+            });
+        };
+        Ok(TransactionUpdate {
+            hash: transaction.hash,
+            status: if transaction.success == Some(true) {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Failed
+            },
+            // Aptos 按账本版本号排序交易
+            block_number: transaction.version.and_then(|x| x.parse().ok()),
+            confirmations: 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+
+    const HASH: &str = "0x3c7a4f6e0ad8a0b0c8f2c7a1f5d3b2e4a6c8d0e2f4a6b8c0d2e4f6a8b0c2d4e6";
+
+    #[tokio::test]
+    async fn test_simulate_and_broadcast() {
+        let transaction = vec![0x01, 0x02, 0x03];
+        let mock = MockClient::new()
+            .with_post_bytes(
+                "/v1/transactions/simulate",
+                &transaction,
+                json!([{
+                    "type": "user_transaction",
+                    "hash": HASH,
+                    "version": "0",
+                    "success": true,
+                    "vm_status": "Executed successfully",
+                    "gas_used": "12",
+                    "gas_unit_price": "100"
+                }]),
+            )
+            .with_post_bytes(
+                "/v1/transactions",
+                &transaction,
+                json!({ "type": "pending_transaction", "hash": HASH }),
+            );
+        let client = AptosClient::new_with_client(mock);
+
+        let simulation = client
+            .simulate_transaction(transaction.clone())
+            .await
+            .unwrap();
+        assert_eq!(simulation.success, Some(true));
+        assert_eq!(simulation.fee(), 1200);
+
+        assert_eq!(
+            client.broadcast_transaction(transaction).await.unwrap(),
+            HASH
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_status() {
+        let mock = MockClient::new().with_get(
+            &format!("/v1/transactions/by_hash/{}", HASH),
+            json!({
+                "type": "user_transaction",
+                "hash": HASH,
+                "version": "1928374655",
+                "success": false,
+                "vm_status": "Move abort in 0x1::coin: EINSUFFICIENT_BALANCE(0x10006)"
+            }),
+        );
+        let client = AptosClient::new_with_client(mock);
+
+        let update = client.get_transaction_status(HASH).await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Failed));
+        assert_eq!(update.block_number, Some(1928374655));
+
+        let update = client
+            .get_transaction_status(&HASH.replace('e', "f"))
+            .await
+            .unwrap();
+        assert!(matches!(update.status, TransactionStatus::Pending));
+    }
+
+    #[tokio::test]
+    async fn test_validate_path_params() {
+        let client = AptosClient::new_with_client(MockClient::new());
+        assert!(client.get_transaction("0x0").await.is_err());
+        assert!(client
+            .get_transaction(&format!("{}/../x", &HASH[..60]))
+            .await
+            .is_err());
+        assert!(client.get_account_resources("0x1/../x").await.is_err());
+        assert!(client.get_coin_info("0x1::coin::X>/../y").await.is_err());
+        assert!(client.get_coin_info("0x1::coin::X<../y>").await.is_err());
+        assert!(client.get_fungible_asset_metadata("0xa?x=1").await.is_err());
+    }
+}
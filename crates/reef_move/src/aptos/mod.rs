@@ -0,0 +1,15 @@
+pub mod client;
+pub mod models;
+pub mod provider;
+
+pub use client::AptosClient;
+pub use models::*;
+
+pub const APTOS_COIN_TYPE: &str = "0x1::aptos_coin::AptosCoin";
+/// APT 迁移后对应的 fungible asset 元数据地址
+pub const APTOS_FA_ADDRESS: &str = "0xa";
+pub const APTOS_DECIMALS: u8 = 8;
+/// Aptos Labs 的 indexer，用于列出账户的全部 fungible asset 余额
+pub const APTOS_INDEXER_URL: &str = "https://api.mainnet.aptoslabs.com/v1/graphql";
+/// 提交 BCS 编码的已签名交易时的 Content-Type
+pub const BCS_SIGNED_TRANSACTION: &str = "application/x.aptos.signed_transaction+bcs";
@@ -0,0 +1,119 @@
+use num_bigint::BigUint;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+const COIN_STORE_PREFIX: &str = "0x1::coin::CoinStore<";
+
+/// `/v1/accounts/{address}/resources`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AptosResource {
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    pub data: Value,
+}
+
+impl AptosResource {
+    /// `0x1::coin::CoinStore<T>` 中的币种 `T`
+    pub fn coin_store_type(&self) -> Option<&str> {
+        self.resource_type
+            .strip_prefix(COIN_STORE_PREFIX)?
+            .strip_suffix('>')
+    }
+
+    pub fn coin_store_value(&self) -> Option<&str> {
+        self.data["coin"]["value"].as_str()
+    }
+}
+
+/// `0x1::coin::CoinInfo<T>` 资源，`0x1::fungible_asset::Metadata` 的字段相同
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AptosCoinInfo {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// `POST /v1/view`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AptosViewRequest {
+    pub function: String,
+    pub type_arguments: Vec<String>,
+    pub arguments: Vec<Value>,
+}
+
+/// 交易查询、提交与模拟的返回
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AptosTransaction {
+    /// `pending_transaction`、`user_transaction` 等
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub hash: String,
+    pub version: Option<String>,
+    pub success: Option<bool>,
+    pub vm_status: Option<String>,
+    pub gas_used: Option<String>,
+    pub gas_unit_price: Option<String>,
+}
+
+impl AptosTransaction {
+    pub fn is_pending(&self) -> bool {
+        self.transaction_type == "pending_transaction"
+    }
+
+    /// gas_used * gas_unit_price，单位 octa
+    pub fn fee(&self) -> u64 {
+        let parse = |x: &Option<String>| {
+            x.as_deref()
+                .and_then(|x| x.parse::<u64>().ok())
+                .unwrap_or_default()
+        };
+        parse(&self.gas_used).saturating_mul(parse(&self.gas_unit_price))
+    }
+}
+
+/// indexer GraphQL 请求
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphQlRequest {
+    pub query: String,
+    pub variables: Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphQlError {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphQlResponse<T> {
+    pub data: Option<T>,
+    #[serde(default)]
+    pub errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FungibleAssetBalancesData {
+    pub current_fungible_asset_balances: Vec<FungibleAssetBalance>,
+}
+
+/// CoinStore 与 fungible store 的合并余额，`asset_type` 为币种类型或 FA 元数据地址
+#[derive(Debug, Clone, Deserialize)]
+pub struct FungibleAssetBalance {
+    pub asset_type: String,
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub amount: BigUint,
+    pub metadata: Option<AptosCoinInfo>,
+}
+
+/// numeric 字段可能是数字或字符串
+fn deserialize_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigUint, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Number(u64),
+        String(String),
+    }
+    match Amount::deserialize(deserializer)? {
+        Amount::Number(value) => Ok(BigUint::from(value)),
+        Amount::String(value) => value.parse().map_err(serde::de::Error::custom),
+    }
+}
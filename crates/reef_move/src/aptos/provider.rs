@@ -0,0 +1,346 @@
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{
+    Asset, AssetBalance, AssetType, Balance, Chain, ChainBalances, ChainNft, ChainProvider,
+    ChainState, ChainToken, ChainTraits, ChainTransactions, ChainUtxo,
+};
+use reef_client::Client;
+use std::error::Error;
+
+use crate::address::{is_coin_type, normalize_address, validate_aptos_address};
+use crate::aptos::{
+    client::AptosClient, AptosCoinInfo, FungibleAssetBalance, APTOS_COIN_TYPE, APTOS_DECIMALS,
+    APTOS_FA_ADDRESS,
+};
+
+/// APT 的 Coin 类型与迁移后的 FA 地址都由 `get_balance_coin` 统计
+fn is_native_asset(asset_type: &str) -> bool {
+    asset_type == APTOS_COIN_TYPE
+        || normalize_address(asset_type).ok() == normalize_address(APTOS_FA_ADDRESS).ok()
+}
+
+impl<C: Client + Clone> AptosClient<C> {
+    /// 币种类型读取 CoinInfo，其余按 FA 元数据地址处理
+    async fn get_asset_info(
+        &self,
+        token_address: &str,
+    ) -> Result<AptosCoinInfo, Box<dyn Error + Send + Sync>> {
+        if is_coin_type(token_address) {
+            self.get_coin_info(token_address).await
+        } else {
+            self.get_fungible_asset_metadata(token_address).await
+        }
+    }
+
+    /// 非零代币余额，跳过没有 CoinInfo 或 FA 元数据的币种
+    async fn map_token_balances(&self, balances: Vec<(String, BigUint)>) -> Vec<AssetBalance> {
+        let mut assets = Vec::new();
+        for (token_address, amount) in balances {
+            if amount == BigUint::ZERO {
+                continue;
+            }
+            if let Ok(info) = self.get_asset_info(&token_address).await {
+                assets.push(AssetBalance::new_token(
+                    Chain::Aptos,
+                    Some(token_address),
+                    amount,
+                    info.decimals,
+                ));
+            }
+        }
+        assets
+    }
+
+    /// indexer 已返回元数据，无需再逐个查询
+    fn map_fungible_asset_balances(balances: Vec<FungibleAssetBalance>) -> Vec<AssetBalance> {
+        balances
+            .into_iter()
+            .filter(|x| x.amount > BigUint::ZERO && !is_native_asset(&x.asset_type))
+            .filter_map(|x| {
+                Some(AssetBalance::new_token(
+                    Chain::Aptos,
+                    Some(x.asset_type),
+                    x.amount,
+                    x.metadata?.decimals,
+                ))
+            })
+            .collect()
+    }
+}
+
+impl<C: Client + Clone> ChainTraits for AptosClient<C> {}
+
+impl<C: Client + Clone> ChainProvider for AptosClient<C> {
+    fn get_chain(&self) -> Chain {
+        self.get_chain()
+    }
+
+    fn verify_address(&self, address: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.validate_address(&address)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for AptosClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let balance = self.get_balance(&address, APTOS_COIN_TYPE).await?;
+        Ok(AssetBalance {
+            chain: Chain::Aptos,
+            contract_address: None,
+            is_active: Some(balance > BigUint::ZERO),
+            balance: Balance::coin_balance(balance, APTOS_DECIMALS),
+        })
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let mut balances = Vec::new();
+        for token_address in token_addresses {
+            let balance = if is_coin_type(&token_address) {
+                self.get_balance(&address, &token_address).await?
+            } else {
+                self.get_fungible_asset_balance(&address, &token_address)
+                    .await?
+            };
+            balances.push((token_address, balance));
+        }
+        Ok(self.map_token_balances(balances).await)
+    }
+
+    async fn get_assets_balances(
+        &self,
+        address: String,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        if let Some(balances) = self.get_fungible_asset_balances(&address).await? {
+            return Ok(Self::map_fungible_asset_balances(balances));
+        }
+        // 没有 indexer 时只能列出 CoinStore
+        let balances = self
+            .get_coin_balances(&address)
+            .await?
+            .into_iter()
+            .filter(|(coin_type, _)| coin_type != APTOS_COIN_TYPE)
+            .collect();
+        Ok(self.map_token_balances(balances).await)
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for AptosClient<C> {
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let info = self.get_asset_info(&token_address).await?;
+        Ok(Asset::new(
+            info.name,
+            info.symbol,
+            info.decimals as i32,
+            Chain::Aptos,
+            Some(token_address),
+            AssetType::TOKEN,
+        ))
+    }
+
+    /// 币种类型或 FA 元数据地址
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        is_coin_type(token_address) || validate_aptos_address(token_address).is_ok()
+    }
+
+    async fn get_tokens_data(
+        &self,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<Asset>, Box<dyn Error + Sync + Send>> {
+        let mut assets = Vec::new();
+        for token_address in token_addresses {
+            // 跳过获取失败的代币
+            if let Ok(asset) = self.get_token_data(token_address).await {
+                assets.push(asset);
+            }
+        }
+        Ok(assets)
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainState for AptosClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainTransactions for AptosClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainNft for AptosClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainUtxo for AptosClient<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::{json, Value};
+
+    const ADDRESS: &str = "0x7e2a4f1c9b3d5e6f8a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f";
+    const USDT: &str =
+        "0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::USDT";
+    const UNKNOWN: &str = "0x1234::meme::MEME";
+    const USDC_FA: &str = "0xbae207659db88bea0cbead6da0ed00aac12edcdda169e591cd41c94180b46f3b";
+
+    fn client() -> AptosClient<MockClient> {
+        let mock = MockClient::new()
+            .with_post(
+                "/v1/view",
+                json!({
+                    "function": "0x1::coin::balance",
+                    "type_arguments": [APTOS_COIN_TYPE],
+                    "arguments": [ADDRESS]
+                }),
+                json!(["250000000"]),
+            )
+            .with_post(
+                "/v1/view",
+                json!({
+                    "function": "0x1::primary_fungible_store::balance",
+                    "type_arguments": ["0x1::fungible_asset::Metadata"],
+                    "arguments": [ADDRESS, USDC_FA]
+                }),
+                json!(["42000000"]),
+            )
+            .with_get(
+                &format!("/v1/accounts/{}/resource/0x1::fungible_asset::Metadata", USDC_FA),
+                json!({
+                    "type": "0x1::fungible_asset::Metadata",
+                    "data": { "name": "USDC", "symbol": "USDC", "decimals": 6, "icon_uri": "", "project_uri": "" }
+                }),
+            )
+            .with_get(
+                &format!("/v1/accounts/{}/resources", ADDRESS),
+                json!([
+                    { "type": "0x1::account::Account", "data": { "sequence_number": "3" } },
+                    { "type": format!("0x1::coin::CoinStore<{}>", APTOS_COIN_TYPE), "data": { "coin": { "value": "250000000" }, "frozen": false } },
+                    { "type": format!("0x1::coin::CoinStore<{}>", USDT), "data": { "coin": { "value": "1000000" }, "frozen": false } },
+                    { "type": format!("0x1::coin::CoinStore<{}>", UNKNOWN), "data": { "coin": { "value": "5" }, "frozen": false } }
+                ]),
+            )
+            .with_get(
+                &format!(
+                    "/v1/accounts/{}/resource/0x1::coin::CoinInfo<{}>",
+                    USDT.split("::").next().unwrap(),
+                    USDT
+                ),
+                json!({
+                    "type": format!("0x1::coin::CoinInfo<{}>", USDT),
+                    "data": { "name": "Tether USD", "symbol": "USDt", "decimals": 6, "supply": { "vec": [] } }
+                }),
+            );
+        AptosClient::new_with_client(mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let balance = client()
+            .get_balance_coin(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balance.chain, Chain::Aptos);
+        assert_eq!(balance.balance.amount, "250000000");
+        assert_eq!(balance.balance.decimals, 8);
+        assert_eq!(balance.is_active, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_assets_balances() {
+        let balances = client()
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].contract_address.as_deref(), Some(USDT));
+        assert_eq!(balances[0].balance.decimals, 6);
+        assert_eq!(balances[0].balance.amount, "1000000");
+
+        let balances = AptosClient::new_with_client(MockClient::new())
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert!(balances.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_assets_balances_indexer() {
+        let indexer = MockClient::new().with_post(
+            "",
+            Value::Null,
+            json!({
+                "data": {
+                    "current_fungible_asset_balances": [
+                        { "asset_type": APTOS_COIN_TYPE, "amount": 250000000, "metadata": { "name": "Aptos Coin", "symbol": "APT", "decimals": 8 } },
+                        { "asset_type": normalize_address(APTOS_FA_ADDRESS).unwrap(), "amount": "100", "metadata": { "name": "Aptos Coin", "symbol": "APT", "decimals": 8 } },
+                        { "asset_type": USDT, "amount": 1000000, "metadata": { "name": "Tether USD", "symbol": "USDt", "decimals": 6 } },
+                        { "asset_type": USDC_FA, "amount": "42000000", "metadata": { "name": "USDC", "symbol": "USDC", "decimals": 6 } },
+                        { "asset_type": UNKNOWN, "amount": "5", "metadata": null }
+                    ]
+                }
+            }),
+        );
+        let balances = client()
+            .with_indexer(indexer)
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].contract_address.as_deref(), Some(USDT));
+        assert_eq!(balances[1].contract_address.as_deref(), Some(USDC_FA));
+        assert_eq!(balances[1].balance.amount, "42000000");
+        assert_eq!(balances[1].balance.decimals, 6);
+
+        let indexer = MockClient::new().with_post(
+            "",
+            Value::Null,
+            json!({ "data": null, "errors": [{ "message": "rate limited" }] }),
+        );
+        assert!(client()
+            .with_indexer(indexer)
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_tokens_fungible_asset() {
+        let client = client();
+        assert!(client.get_is_token_address(USDC_FA));
+        assert!(client.get_is_token_address(USDT));
+        assert!(!client.get_is_token_address("USDC"));
+
+        let balances = client
+            .get_balance_tokens(ADDRESS.to_string(), vec![USDC_FA.to_string()])
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].contract_address.as_deref(), Some(USDC_FA));
+        assert_eq!(balances[0].balance.amount, "42000000");
+        assert_eq!(balances[0].balance.decimals, 6);
+
+        let asset = client.get_token_data(USDC_FA.to_string()).await.unwrap();
+        assert_eq!(asset.symbol, "USDC");
+        assert_eq!(asset.decimals, 6);
+    }
+
+    #[tokio::test]
+    async fn test_get_token_data() {
+        let client = client();
+        let asset = client.get_token_data(USDT.to_string()).await.unwrap();
+        assert_eq!(asset.name, "Tether USD");
+        assert_eq!(asset.symbol, "USDt");
+        assert_eq!(asset.asset_type, AssetType::TOKEN);
+        assert!(client.get_token_data(UNKNOWN.to_string()).await.is_err());
+    }
+}
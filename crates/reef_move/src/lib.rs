@@ -0,0 +1,7 @@
+pub mod address;
+pub mod aptos;
+pub mod sui;
+
+// Re-export for convenience
+pub use aptos::AptosClient;
+pub use sui::SuiClient;
@@ -0,0 +1,229 @@
+use crate::address::validate_sui_address;
+use crate::sui::models::{SuiBalance, SuiCoinMetadata, SuiDryRunResult, SuiTransactionBlock};
+use primitives::{Chain, TransactionStatus, TransactionUpdate};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use reef_jsonrpc::{
+    EndpointConfig, JsonRpcCache, JsonRpcClient, RateLimitConfig, RoutingStrategy,
+    ERROR_INVALID_PARAMS,
+};
+use serde_json::json;
+use std::error::Error;
+
+/// Sui JSON-RPC 客户端
+pub struct SuiClient<C: Client + Clone = ReqwestClient> {
+    client: JsonRpcClient<C>,
+}
+
+impl SuiClient {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: JsonRpcClient::new_reqwest(url),
+        }
+    }
+
+    /// 多节点故障转移
+    pub fn new_with_endpoints(
+        endpoints: Vec<EndpointConfig>,
+        strategy: RoutingStrategy,
+        rate_limit: Option<RateLimitConfig>,
+        config: &ClientConfig,
+    ) -> Result<Self, ClientError> {
+        Ok(Self {
+            client: JsonRpcClient::new_reqwest_endpoints(endpoints, strategy, rate_limit, config)?,
+        })
+    }
+}
+
+impl<C: Client + Clone> SuiClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C) -> Self {
+        Self {
+            client: JsonRpcClient::new(client),
+        }
+    }
+
+    pub fn with_cache(mut self, cache: JsonRpcCache) -> Self {
+        self.client = self.client.with_cache(cache);
+        self
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        Chain::Sui
+    }
+
+    pub fn validate_address(&self, address: &str) -> Result<(), String> {
+        validate_sui_address(address)
+    }
+
+    /// 地址持有的所有币种
+    pub async fn get_all_balances(
+        &self,
+        owner: &str,
+    ) -> Result<Vec<SuiBalance>, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .client
+            .call("suix_getAllBalances", json!([owner]))
+            .await
+            .map_err(|e| format!("Failed to get balances: {}", e))?)
+    }
+
+    pub async fn get_balance(
+        &self,
+        owner: &str,
+        coin_type: &str,
+    ) -> Result<SuiBalance, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .client
+            .call("suix_getBalance", json!([owner, coin_type]))
+            .await
+            .map_err(|e| format!("Failed to get balance: {}", e))?)
+    }
+
+    /// 未注册元数据的币种返回 None
+    pub async fn get_coin_metadata(
+        &self,
+        coin_type: &str,
+    ) -> Result<Option<SuiCoinMetadata>, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .client
+            .call("suix_getCoinMetadata", json!([coin_type]))
+            .await
+            .map_err(|e| format!("Failed to get coin metadata: {}", e))?)
+    }
+
+    /// 模拟执行 base64 编码的 BCS `TransactionData`，无需签名
+    pub async fn dry_run_transaction(
+        &self,
+        tx_bytes: &str,
+    ) -> Result<SuiDryRunResult, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .client
+            .call("sui_dryRunTransactionBlock", json!([tx_bytes]))
+            .await
+            .map_err(|e| format!("Failed to dry run transaction: {}", e))?)
+    }
+
+    /// 广播已签名交易，返回交易 digest，执行失败的交易同样上链并扣除 gas
+    pub async fn broadcast_transaction(
+        &self,
+        tx_bytes: &str,
+        signatures: Vec<String>,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let params = json!([tx_bytes, signatures, { "showEffects": true }]);
+        let response: SuiTransactionBlock = self
+            .client
+            .call("sui_executeTransactionBlock", params)
+            .await
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        Ok(response.digest)
+    }
+
+    pub async fn get_transaction(
+        &self,
+        digest: &str,
+    ) -> Result<Option<SuiTransactionBlock>, Box<dyn Error + Send + Sync>> {
+        let params = json!([digest, { "showEffects": true }]);
+        match self.client.call("sui_getTransactionBlock", params).await {
+            Ok(transaction) => Ok(Some(transaction)),
+            // 尚未执行的交易返回 "Could not find the referenced transaction"
+            Err(e) if e.code == ERROR_INVALID_PARAMS => Ok(None),
+            Err(e) => Err(format!("Failed to get transaction: {}", e).into()),
+        }
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        digest: &str,
+    ) -> Result<TransactionUpdate, Box<dyn Error + Send + Sync>> {
+        let transaction = self.get_transaction(digest).await?;
+        let Some((transaction, effects)) =
+            transaction.and_then(|x| x.effects.clone().map(|effects| (x, effects)))
+        else {
+            return Ok(TransactionUpdate {
+                hash: digest.to_string(),
+                status: TransactionStatus::Pending,
+                block_number: None,
+                confirmations: 0,
+            });
+        };
+        Ok(TransactionUpdate {
+            hash: transaction.digest,
+            status: if effects.status.is_success() {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Failed
+            },
+            block_number: transaction.checkpoint.and_then(|x| x.parse().ok()),
+            confirmations: 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+
+    const DIGEST: &str = "5Tf3nM9ELkLWkbJCzMfKzYzGqPXQqNmSa7L8aXgZsCsm";
+
+    fn effects(status: &str) -> serde_json::Value {
+        json!({
+            "status": { "status": status },
+            "gasUsed": {
+                "computationCost": "750000",
+                "storageCost": "1976000",
+                "storageRebate": "978120",
+                "nonRefundableStorageFee": "9880"
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_and_broadcast() {
+        let mock = MockClient::new()
+            .with_method_response(
+                "sui_dryRunTransactionBlock",
+                json!({ "effects": effects("success") }),
+            )
+            .with_response(
+                "sui_executeTransactionBlock",
+                json!(["AAAC", ["AGJh"], { "showEffects": true }]),
+                json!({ "digest": DIGEST, "effects": effects("success") }),
+            );
+        let client = SuiClient::new_with_client(mock);
+
+        let result = client.dry_run_transaction("AAAC").await.unwrap();
+        assert!(result.effects.status.is_success());
+        assert_eq!(result.effects.gas_used.fee(), 1747880);
+
+        let digest = client
+            .broadcast_transaction("AAAC", vec!["AGJh".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(digest, DIGEST);
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_status() {
+        let mock = MockClient::new()
+            .with_response(
+                "sui_getTransactionBlock",
+                json!([DIGEST, { "showEffects": true }]),
+                json!({ "digest": DIGEST, "checkpoint": "98765432", "effects": effects("failure") }),
+            )
+            .with_error(
+                "sui_getTransactionBlock",
+                serde_json::Value::Null,
+                ERROR_INVALID_PARAMS,
+                "Could not find the referenced transaction",
+            );
+        let client = SuiClient::new_with_client(mock);
+
+        let update = client.get_transaction_status(DIGEST).await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Failed));
+        assert_eq!(update.block_number, Some(98765432));
+
+        let update = client.get_transaction_status("unknown").await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Pending));
+    }
+}
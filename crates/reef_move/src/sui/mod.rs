@@ -0,0 +1,9 @@
+pub mod client;
+pub mod models;
+pub mod provider;
+
+pub use client::SuiClient;
+pub use models::*;
+
+pub const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+pub const SUI_DECIMALS: u8 = 9;
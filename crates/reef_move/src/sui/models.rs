@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// `suix_getAllBalances` / `suix_getBalance`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiBalance {
+    pub coin_type: String,
+    #[serde(default)]
+    pub coin_object_count: u64,
+    pub total_balance: String,
+}
+
+/// `suix_getCoinMetadata`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiCoinMetadata {
+    pub decimals: u8,
+    pub name: String,
+    pub symbol: String,
+    #[serde(default)]
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiTransactionBlock {
+    pub digest: String,
+    pub checkpoint: Option<String>,
+    pub effects: Option<SuiEffects>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiEffects {
+    pub status: SuiExecutionStatus,
+    pub gas_used: SuiGasCostSummary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuiExecutionStatus {
+    /// `success` 或 `failure`
+    pub status: String,
+    pub error: Option<String>,
+}
+
+impl SuiExecutionStatus {
+    pub fn is_success(&self) -> bool {
+        self.status == "success"
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiGasCostSummary {
+    pub computation_cost: String,
+    pub storage_cost: String,
+    pub storage_rebate: String,
+}
+
+impl SuiGasCostSummary {
+    /// 实际扣除的 gas，存储返还可能大于开销，此时为 0
+    pub fn fee(&self) -> u64 {
+        let parse = |x: &str| x.parse::<u64>().unwrap_or_default();
+        (parse(&self.computation_cost) + parse(&self.storage_cost))
+            .saturating_sub(parse(&self.storage_rebate))
+    }
+}
+
+/// `sui_dryRunTransactionBlock`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuiDryRunResult {
+    pub effects: SuiEffects,
+}
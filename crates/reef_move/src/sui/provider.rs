@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{
+    Asset, AssetBalance, AssetType, Balance, Chain, ChainBalances, ChainNft, ChainProvider,
+    ChainState, ChainToken, ChainTraits, ChainTransactions, ChainUtxo,
+};
+use reef_client::Client;
+use std::error::Error;
+
+use crate::address::is_coin_type;
+use crate::sui::{client::SuiClient, SuiBalance, SUI_COIN_TYPE, SUI_DECIMALS};
+
+fn parse_balance(balance: &SuiBalance) -> BigUint {
+    balance.total_balance.parse().unwrap_or_default()
+}
+
+impl<C: Client + Clone> SuiClient<C> {
+    /// 非零代币余额，跳过没有元数据的币种
+    async fn map_token_balances(&self, balances: Vec<SuiBalance>) -> Vec<AssetBalance> {
+        let mut assets = Vec::new();
+        for balance in balances {
+            let amount = parse_balance(&balance);
+            if amount == BigUint::ZERO {
+                continue;
+            }
+            if let Ok(Some(metadata)) = self.get_coin_metadata(&balance.coin_type).await {
+                assets.push(AssetBalance::new_token(
+                    Chain::Sui,
+                    Some(balance.coin_type),
+                    amount,
+                    metadata.decimals,
+                ));
+            }
+        }
+        assets
+    }
+}
+
+impl<C: Client + Clone> ChainTraits for SuiClient<C> {}
+
+impl<C: Client + Clone> ChainProvider for SuiClient<C> {
+    fn get_chain(&self) -> Chain {
+        self.get_chain()
+    }
+
+    fn verify_address(&self, address: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.validate_address(&address)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for SuiClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let balance = self.get_balance(&address, SUI_COIN_TYPE).await?;
+        Ok(AssetBalance {
+            chain: Chain::Sui,
+            contract_address: None,
+            balance: Balance::coin_balance(parse_balance(&balance), SUI_DECIMALS),
+            is_active: Some(balance.coin_object_count > 0),
+        })
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let mut balances = Vec::new();
+        for coin_type in token_addresses {
+            balances.push(self.get_balance(&address, &coin_type).await?);
+        }
+        Ok(self.map_token_balances(balances).await)
+    }
+
+    async fn get_assets_balances(
+        &self,
+        address: String,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        let balances = self
+            .get_all_balances(&address)
+            .await?
+            .into_iter()
+            .filter(|x| x.coin_type != SUI_COIN_TYPE)
+            .collect();
+        Ok(self.map_token_balances(balances).await)
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for SuiClient<C> {
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let metadata = self
+            .get_coin_metadata(&token_address)
+            .await?
+            .ok_or_else(|| format!("Coin metadata not found: {}", token_address))?;
+        Ok(Asset::new(
+            metadata.name,
+            metadata.symbol,
+            metadata.decimals as i32,
+            Chain::Sui,
+            Some(token_address),
+            AssetType::TOKEN,
+        ))
+    }
+
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        is_coin_type(token_address)
+    }
+
+    async fn get_tokens_data(
+        &self,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<Asset>, Box<dyn Error + Sync + Send>> {
+        let mut assets = Vec::new();
+        for token_address in token_addresses {
+            // 跳过获取失败的代币
+            if let Ok(asset) = self.get_token_data(token_address).await {
+                assets.push(asset);
+            }
+        }
+        Ok(assets)
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainState for SuiClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainTransactions for SuiClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainNft for SuiClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainUtxo for SuiClient<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "0x5f9a4a3b6e7c1d2e8f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c9d8e";
+    const USDC: &str =
+        "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC";
+    const UNKNOWN: &str = "0x1234::meme::MEME";
+
+    fn client() -> SuiClient<MockClient> {
+        let mock = MockClient::new()
+            .with_response(
+                "suix_getBalance",
+                json!([ADDRESS, SUI_COIN_TYPE]),
+                json!({ "coinType": SUI_COIN_TYPE, "coinObjectCount": 2, "totalBalance": "1500000000", "lockedBalance": {} }),
+            )
+            .with_response(
+                "suix_getAllBalances",
+                json!([ADDRESS]),
+                json!([
+                    { "coinType": SUI_COIN_TYPE, "coinObjectCount": 2, "totalBalance": "1500000000", "lockedBalance": {} },
+                    { "coinType": USDC, "coinObjectCount": 1, "totalBalance": "25000000", "lockedBalance": {} },
+                    { "coinType": UNKNOWN, "coinObjectCount": 1, "totalBalance": "7", "lockedBalance": {} }
+                ]),
+            )
+            .with_response(
+                "suix_getCoinMetadata",
+                json!([USDC]),
+                json!({ "decimals": 6, "name": "USDC", "symbol": "USDC", "description": "USDC is a US dollar-backed stablecoin", "iconUrl": null, "id": "0x69b7" }),
+            )
+            .with_response("suix_getCoinMetadata", json!([UNKNOWN]), json!(null));
+        SuiClient::new_with_client(mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let balance = client()
+            .get_balance_coin(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balance.chain, Chain::Sui);
+        assert_eq!(balance.balance.amount, "1500000000");
+        assert_eq!(balance.balance.decimals, 9);
+        assert_eq!(balance.is_active, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_assets_balances() {
+        let balances = client()
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].contract_address.as_deref(), Some(USDC));
+        assert_eq!(balances[0].balance.decimals, 6);
+        assert_eq!(balances[0].balance.amount, "25000000");
+    }
+
+    #[tokio::test]
+    async fn test_get_token_data() {
+        let client = client();
+        let asset = client.get_token_data(USDC.to_string()).await.unwrap();
+        assert_eq!(asset.symbol, "USDC");
+        assert_eq!(asset.decimals, 6);
+        assert_eq!(asset.asset_type, AssetType::TOKEN);
+        assert!(client.get_token_data(UNKNOWN.to_string()).await.is_err());
+        assert!(client.get_is_token_address(USDC));
+        assert!(!client.get_is_token_address(ADDRESS));
+    }
+}
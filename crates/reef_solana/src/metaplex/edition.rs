@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use crate::metaplex::Key;
+use crate::{pubkey::Pubkey, METAPLEX_PROGRAM};
+use base64::{engine::general_purpose, Engine as _};
+use borsh::BorshDeserialize;
+
+/// Master edition / print edition account of a Metaplex NFT
+pub struct Edition;
+
+impl Edition {
+    pub fn find_pda(mint: Pubkey) -> Option<(Pubkey, u8)> {
+        let mpl_id = Pubkey::from_str(METAPLEX_PROGRAM).unwrap();
+        let seeds = &[
+            "metadata".as_bytes(),
+            mpl_id.as_ref(),
+            mint.as_ref(),
+            "edition".as_bytes(),
+        ];
+        Pubkey::try_find_program_address(seeds, &mpl_id)
+    }
+}
+
+/// Decode the account discriminator of a base64 encoded edition account
+pub fn decode_edition_key(base64_str: &str) -> Option<Key> {
+    let data = general_purpose::STANDARD.decode(base64_str).ok()?;
+    Key::try_from_slice(data.get(..1)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_edition_pda() {
+        let mint = Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+        let (pda, _) = Edition::find_pda(mint).unwrap();
+
+        assert_eq!(
+            pda.to_string(),
+            "A7FGB2kzjpDPRLMeqRLgW9XZ3JQ2RYRL4w5kUZv64ZB"
+        );
+    }
+
+    #[test]
+    fn test_decode_edition_key() {
+        let master = general_purpose::STANDARD.encode([6u8, 0, 0, 0]);
+        assert_eq!(decode_edition_key(&master), Some(Key::MasterEditionV2));
+        assert!(decode_edition_key(&master).unwrap().is_edition());
+
+        let metadata = general_purpose::STANDARD.encode([4u8]);
+        assert!(!decode_edition_key(&metadata).unwrap().is_edition());
+
+        assert_eq!(decode_edition_key(""), None);
+    }
+}
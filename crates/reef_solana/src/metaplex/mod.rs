@@ -1,8 +1,9 @@
 // Taken from https://github.com/metaplex-foundation/mpl-token-metadata/blob/main/programs/token-metadata/program/src/state/metadata.rs
-mod collection;
-mod data;
-mod uses;
+pub mod collection;
+pub mod data;
+pub mod uses;
 
+pub mod edition;
 pub mod metadata;
 use crate::metaplex::metadata::Metadata;
 use base64::{engine::general_purpose, Engine as _};
@@ -27,6 +28,16 @@ pub enum Key {
     HolderDelegate,
 }
 
+impl Key {
+    /// 主版本或印刷版本账户，存在即表示该 mint 是 NFT
+    pub fn is_edition(&self) -> bool {
+        matches!(
+            self,
+            Key::EditionV1 | Key::MasterEditionV1 | Key::MasterEditionV2
+        )
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
 pub enum TokenStandard {
     NonFungible,                    // This is a master edition
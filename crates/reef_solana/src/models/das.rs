@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+pub const DAS_COLLECTION_GROUP: &str = "collection";
+
+/// DAS `getAssetsByOwner` 分页结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DasAssetList {
+    pub total: u32,
+    pub limit: u32,
+    pub page: u32,
+    pub items: Vec<DasAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DasAsset {
+    pub id: String,
+    pub interface: String,
+    pub content: Option<DasContent>,
+    #[serde(default)]
+    pub grouping: Vec<DasGrouping>,
+    pub compression: Option<DasCompression>,
+    pub ownership: DasOwnership,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DasContent {
+    pub json_uri: Option<String>,
+    pub metadata: Option<DasMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DasMetadata {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DasGrouping {
+    pub group_key: String,
+    pub group_value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DasCompression {
+    pub compressed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DasOwnership {
+    pub owner: String,
+}
+
+impl DasAsset {
+    /// 压缩 NFT 只能通过 DAS 查询
+    pub fn is_compressed(&self) -> bool {
+        self.compression.as_ref().is_some_and(|x| x.compressed)
+    }
+
+    pub fn collection(&self) -> Option<&str> {
+        self.grouping
+            .iter()
+            .find(|x| x.group_key == DAS_COLLECTION_GROUP)
+            .map(|x| x.group_value.as_str())
+    }
+
+    pub fn metadata(&self) -> Option<&DasMetadata> {
+        self.content.as_ref()?.metadata.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_das_asset_list_deserialize() {
+        let list: DasAssetList = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/testdata/das_get_assets_by_owner.json"
+        )))
+        .unwrap();
+
+        assert_eq!(list.items.len(), 2);
+        let compressed = &list.items[0];
+        assert!(compressed.is_compressed());
+        assert_eq!(
+            compressed.collection(),
+            Some("BUjZjAS2vbbb65g7Z1Ca9ZRVYoJscURG5L3AkVvHP9ac")
+        );
+        assert_eq!(
+            compressed.metadata().and_then(|x| x.name.clone()),
+            Some("Drip #1".to_string())
+        );
+        assert!(!list.items[1].is_compressed());
+        assert_eq!(list.items[1].collection(), None);
+    }
+}
@@ -1,11 +1,15 @@
 pub mod balances;
+pub mod das;
 pub mod rpc;
+pub mod simulation;
 pub mod token;
 pub mod token_account;
 pub mod value;
 
 pub use balances::*;
+pub use das::{DasAsset, DasAssetList};
 pub use rpc::{Info, Parsed, ValueData, ValueResult};
+pub use simulation::{SimulateTransactionResult, SimulatedAccount, SimulationBalanceChanges};
 pub use token_account::{
     TokenAccountData as TokenAccountDataStruct, TokenAccountInfo as TokenAccountInfoStruct,
 };
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::token::TokenBalanceChange;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateTransactionResult {
+    pub err: Option<Value>,
+    pub logs: Option<Vec<String>>,
+    pub accounts: Option<Vec<Option<SimulatedAccount>>>,
+    pub units_consumed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedAccount {
+    pub lamports: u64,
+    pub owner: String,
+    /// [base64 数据, "base64"]
+    pub data: Vec<String>,
+}
+
+/// 模拟交易后签名者钱包的余额变化
+#[derive(Debug, Clone)]
+pub struct SimulationBalanceChanges {
+    pub signer: String,
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    /// SOL 变化 (contract_address 为 None) 以及按 mint 汇总的代币变化
+    pub changes: Vec<TokenBalanceChange>,
+}
+
+impl SimulationBalanceChanges {
+    pub fn is_success(&self) -> bool {
+        self.err.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_transaction_result_deserialize() {
+        let json = r#"{
+            "err": null,
+            "logs": ["Program 11111111111111111111111111111111 invoke [1]"],
+            "accounts": [
+                {"lamports": 1000, "owner": "11111111111111111111111111111111", "data": ["", "base64"], "executable": false, "rentEpoch": 0},
+                null
+            ],
+            "unitsConsumed": 150
+        }"#;
+
+        let result: SimulateTransactionResult = serde_json::from_str(json).unwrap();
+        assert!(result.err.is_none());
+        assert_eq!(result.units_consumed, Some(150));
+
+        let accounts = result.accounts.unwrap();
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].as_ref().unwrap().lamports, 1000);
+        assert!(accounts[1].is_none());
+    }
+}
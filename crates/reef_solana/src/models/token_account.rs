@@ -143,10 +143,10 @@ mod tests {
             },
         };
 
-        assert_eq!(info.is_native, true);
+        assert!(info.is_native);
         assert_eq!(info.mint, "So11111111111111111111111111111111111111112");
 
         info.is_native = false;
-        assert_eq!(info.is_native, false);
+        assert!(!info.is_native);
     }
 }
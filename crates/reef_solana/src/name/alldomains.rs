@@ -0,0 +1,319 @@
+use std::error::Error;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use primitives::{Chain, NameProvider, NameRecord, NameResolver};
+
+use crate::hash::{hashv, Hash};
+use crate::name::{get_accounts_data, read_pubkey, read_string, DEFAULT_PUBKEY};
+use crate::pubkey::Pubkey;
+use crate::rpc::client::SolanaClient;
+
+pub const ANS_PROGRAM: &str = "ALTNSZ46uaAUU7XUV6awvdorLGqAsPwa9shm7h4uP2FK";
+/// 主域名（main domain）所在程序
+pub const ANS_TLD_HOUSE_PROGRAM: &str = "TLDHkysf5pCnKsVA4gXpNvmy7psXLPEu4LAdDJthT9S";
+pub const ANS_HASH_PREFIX: &str = "ALT Name Service";
+/// 所有顶级域名的根
+pub const ANS_ORIGIN_TLD: &str = "ANS";
+pub const ANS_TLDS: &[&str] = &[
+    "abc",
+    "bonk",
+    "poor",
+    "superteam",
+    "monke",
+    "ser",
+    "wen",
+    "gm",
+    "ape",
+    "whale",
+    "bet",
+];
+
+/// discriminator + parent_name + owner + nclass + expires_at
+const NAME_RECORD_OWNER_OFFSET: usize = 40;
+const NAME_RECORD_EXPIRES_AT_OFFSET: usize = 104;
+
+/// AllDomains 域名账户头
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameRecordHeader {
+    pub parent_name: Pubkey,
+    pub owner: Pubkey,
+    pub expires_at: u64,
+}
+
+impl NameRecordHeader {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let expires_at =
+            data.get(NAME_RECORD_EXPIRES_AT_OFFSET..NAME_RECORD_EXPIRES_AT_OFFSET + 8)?;
+        Some(Self {
+            parent_name: read_pubkey(data, 8)?,
+            owner: read_pubkey(data, NAME_RECORD_OWNER_OFFSET)?,
+            expires_at: u64::from_le_bytes(expires_at.try_into().ok()?),
+        })
+    }
+
+    /// expires_at 为 0 表示永久有效
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at != 0 && self.expires_at < now
+    }
+}
+
+pub fn hashed_name(name: &str) -> Hash {
+    hashv(&[ANS_HASH_PREFIX.as_bytes(), name.as_bytes()])
+}
+
+pub fn name_account_key(
+    hashed_name: &Hash,
+    class: Option<&Pubkey>,
+    parent: Option<&Pubkey>,
+) -> Pubkey {
+    let seeds = &[
+        hashed_name.as_ref(),
+        class.unwrap_or(&DEFAULT_PUBKEY).as_ref(),
+        parent.unwrap_or(&DEFAULT_PUBKEY).as_ref(),
+    ];
+    let program = Pubkey::from_str(ANS_PROGRAM).unwrap();
+    Pubkey::try_find_program_address(seeds, &program).unwrap().0
+}
+
+/// 顶级域名账户，例如 `.abc`
+pub fn tld_key(tld: &str) -> Pubkey {
+    let origin = name_account_key(&hashed_name(ANS_ORIGIN_TLD), None, None);
+    name_account_key(&hashed_name(&format!(".{}", tld)), None, Some(&origin))
+}
+
+/// `miester.abc`，子域名挂在上一级域名下
+pub fn domain_key(name: &str) -> Result<Pubkey, Box<dyn Error + Send + Sync>> {
+    let mut labels = name.rsplit('.');
+    let tld = labels
+        .next()
+        .filter(|x| !x.is_empty())
+        .ok_or_else(|| format!("Invalid name: {}", name))?;
+
+    let mut key = tld_key(tld);
+    let mut depth = 0;
+    for label in labels {
+        if label.is_empty() {
+            return Err(format!("Invalid name: {}", name).into());
+        }
+        key = name_account_key(&hashed_name(label), None, Some(&key));
+        depth += 1;
+    }
+    if depth == 0 {
+        return Err(format!("Invalid name: {}", name).into());
+    }
+    Ok(key)
+}
+
+pub fn main_domain_key(owner: &Pubkey) -> Pubkey {
+    let program = Pubkey::from_str(ANS_TLD_HOUSE_PROGRAM).unwrap();
+    let seeds = &["main_domain".as_bytes(), owner.as_ref()];
+    Pubkey::try_find_program_address(seeds, &program).unwrap().0
+}
+
+/// 主域名账户：discriminator + name_account + tld + domain
+pub fn decode_main_domain(data: &[u8]) -> Option<String> {
+    let (tld, offset) = read_string(data, 8 + 32)?;
+    let (domain, _) = read_string(data, offset)?;
+    let tld = tld.trim_start_matches('.');
+    (!domain.is_empty() && !tld.is_empty()).then(|| format!("{}.{}", domain, tld))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs())
+        .unwrap_or_default()
+}
+
+pub struct AllDomainsResolver {
+    client: SolanaClient,
+}
+
+impl AllDomainsResolver {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            client: SolanaClient::new(rpc_url),
+        }
+    }
+
+    /// 主域名，未与正向解析结果校验
+    pub async fn get_name(&self, address: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let owner = Pubkey::from_str(address)?;
+        get_accounts_data(&self.client, &[main_domain_key(&owner)])
+            .await?
+            .into_iter()
+            .next()
+            .flatten()
+            .and_then(|x| decode_main_domain(&x))
+            .ok_or_else(|| format!("{} has no main domain", address).into())
+    }
+}
+
+#[async_trait]
+impl NameResolver for AllDomainsResolver {
+    fn provider(&self) -> NameProvider {
+        NameProvider::AllDomains
+    }
+
+    fn get_chain(&self) -> Chain {
+        Chain::Solana
+    }
+
+    fn is_supported_name(&self, name: &str) -> bool {
+        let name = name.trim().trim_end_matches('.').to_lowercase();
+        name.rsplit_once('.')
+            .is_some_and(|(_, tld)| ANS_TLDS.contains(&tld))
+    }
+
+    async fn resolve_name(&self, name: String) -> Result<NameRecord, Box<dyn Error + Sync + Send>> {
+        let name = name.trim().trim_end_matches('.').to_lowercase();
+        let key = domain_key(&name)?;
+
+        let header = get_accounts_data(&self.client, &[key])
+            .await?
+            .into_iter()
+            .next()
+            .flatten()
+            .and_then(|x| NameRecordHeader::decode(&x))
+            .filter(|x| x.owner != DEFAULT_PUBKEY && !x.is_expired(now()))
+            .ok_or_else(|| format!("Name not found: {}", name))?;
+
+        Ok(NameRecord {
+            name,
+            chain: self.get_chain(),
+            address: header.owner.to_string(),
+            provider: self.provider(),
+            avatar: None,
+            url: None,
+        })
+    }
+
+    async fn lookup_address(
+        &self,
+        address: String,
+    ) -> Result<NameRecord, Box<dyn Error + Sync + Send>> {
+        let name = self.get_name(&address).await?;
+        let record = self.resolve_name(name).await?;
+        if record.address != address {
+            return Err(format!(
+                "Main domain {} is no longer owned by {}",
+                record.name, address
+            )
+            .into());
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::name::sns::tests::mock_accounts_server;
+    use std::collections::HashMap;
+
+    const OWNER: &str = "HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA";
+    const FORMER_OWNER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    fn header_data(parent: &Pubkey, owner: &Pubkey, expires_at: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 8];
+        data.extend(parent.as_ref());
+        data.extend(owner.as_ref());
+        data.extend(DEFAULT_PUBKEY.as_ref());
+        data.extend(expires_at.to_le_bytes());
+        data.resize(200, 0);
+        data
+    }
+
+    fn main_domain_data(name_account: &Pubkey, tld: &str, domain: &str) -> Vec<u8> {
+        let mut data = vec![0u8; 8];
+        data.extend(name_account.as_ref());
+        for value in [tld, domain] {
+            data.extend((value.len() as u32).to_le_bytes());
+            data.extend(value.as_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_domain_key() {
+        let tld = tld_key("abc");
+        assert_eq!(
+            domain_key("miester.abc").unwrap(),
+            name_account_key(&hashed_name("miester"), None, Some(&tld))
+        );
+        assert_ne!(
+            domain_key("miester.abc").unwrap(),
+            domain_key("miester.bonk").unwrap()
+        );
+        assert!(domain_key("abc").is_err());
+        assert!(domain_key("a..abc").is_err());
+    }
+
+    #[test]
+    fn test_decode_main_domain() {
+        let data = main_domain_data(&DEFAULT_PUBKEY, ".abc", "miester");
+        assert_eq!(decode_main_domain(&data), Some("miester.abc".to_string()));
+        assert_eq!(decode_main_domain(&data[..40]), None);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let header =
+            NameRecordHeader::decode(&header_data(&DEFAULT_PUBKEY, &DEFAULT_PUBKEY, 100)).unwrap();
+        assert!(header.is_expired(101));
+        assert!(!header.is_expired(100));
+
+        let header =
+            NameRecordHeader::decode(&header_data(&DEFAULT_PUBKEY, &DEFAULT_PUBKEY, 0)).unwrap();
+        assert!(!header.is_expired(u64::MAX));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_and_lookup() {
+        let owner = Pubkey::from_str(OWNER).unwrap();
+        let former_owner = Pubkey::from_str(FORMER_OWNER).unwrap();
+        let miester = domain_key("miester.abc").unwrap();
+
+        let server = mock_accounts_server(
+            ANS_PROGRAM,
+            HashMap::from([
+                (miester.to_string(), header_data(&tld_key("abc"), &owner, 0)),
+                (
+                    domain_key("expired.abc").unwrap().to_string(),
+                    header_data(&tld_key("abc"), &owner, 1),
+                ),
+                (
+                    main_domain_key(&owner).to_string(),
+                    main_domain_data(&miester, ".abc", "miester"),
+                ),
+                (
+                    main_domain_key(&former_owner).to_string(),
+                    main_domain_data(&miester, ".abc", "miester"),
+                ),
+            ]),
+        )
+        .await;
+        let resolver = AllDomainsResolver::new(server.uri());
+
+        let record = resolver
+            .resolve_name("Miester.abc".to_string())
+            .await
+            .unwrap();
+        assert_eq!(record.name, "miester.abc");
+        assert_eq!(record.address, OWNER);
+        assert_eq!(record.provider, NameProvider::AllDomains);
+        assert!(resolver
+            .resolve_name("expired.abc".to_string())
+            .await
+            .is_err());
+
+        let record = resolver.lookup_address(OWNER.to_string()).await.unwrap();
+        assert_eq!(record.name, "miester.abc");
+        assert!(resolver
+            .lookup_address(FORMER_OWNER.to_string())
+            .await
+            .is_err());
+    }
+}
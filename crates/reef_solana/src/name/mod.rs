@@ -0,0 +1,70 @@
+pub mod alldomains;
+pub mod sns;
+
+use std::error::Error;
+
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::pubkey::Pubkey;
+use crate::rpc::client::SolanaClient;
+
+pub use alldomains::AllDomainsResolver;
+pub use sns::SnsResolver;
+
+/// 全零地址，用作缺省的 class / parent
+pub const DEFAULT_PUBKEY: Pubkey = Pubkey([0; 32]);
+
+/// 从账户数据指定偏移读取公钥
+pub fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    Pubkey::try_from(data.get(offset..offset + 32)?.to_vec()).ok()
+}
+
+/// borsh 编码的字符串：u32 小端长度 + UTF-8 字节，返回字符串和结束位置
+pub fn read_string(data: &[u8], offset: usize) -> Option<(String, usize)> {
+    let length = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+    let end = offset + 4 + length;
+    let value = String::from_utf8(data.get(offset + 4..end)?.to_vec()).ok()?;
+    Some((value, end))
+}
+
+/// 批量读取账户原始数据，顺序与输入一致
+pub async fn get_accounts_data(
+    client: &SolanaClient,
+    keys: &[Pubkey],
+) -> Result<Vec<Option<Vec<u8>>>, Box<dyn Error + Send + Sync>> {
+    let addresses: Vec<String> = keys.iter().map(|x| x.to_string()).collect();
+    let accounts = client.get_multiple_accounts(&addresses).await?;
+
+    Ok(accounts
+        .into_iter()
+        .map(|account| {
+            let data = account?.data.into_iter().next()?;
+            general_purpose::STANDARD.decode(data).ok()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_string() {
+        let mut data = vec![0u8; 2];
+        data.extend(3u32.to_le_bytes());
+        data.extend(b"abcdef");
+
+        assert_eq!(read_string(&data, 2), Some(("abc".to_string(), 9)));
+        assert_eq!(read_string(&data, 8), None);
+    }
+
+    #[test]
+    fn test_read_pubkey() {
+        let key = Pubkey::from([7u8; 32]);
+        let mut data = vec![1u8];
+        data.extend(key.as_ref());
+
+        assert_eq!(read_pubkey(&data, 1), Some(key));
+        assert_eq!(read_pubkey(&data, 2), None);
+    }
+}
@@ -0,0 +1,471 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use primitives::{Chain, NameProvider, NameRecord, NameResolver};
+
+use crate::hash::{hashv, Hash};
+use crate::name::{get_accounts_data, read_pubkey, read_string, DEFAULT_PUBKEY};
+use crate::pubkey::Pubkey;
+use crate::rpc::client::SolanaClient;
+
+pub const SNS_PROGRAM: &str = "namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX";
+/// .sol 顶级域名账户
+pub const SNS_ROOT_DOMAIN: &str = "58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx";
+pub const SNS_REVERSE_LOOKUP_CLASS: &str = "33m47vH6Eav6jr5Ry86XjhRft2jRBLDnDgPSHoquXi2Z";
+/// 主域名（favourite domain）所在程序
+pub const SNS_NAME_OFFERS_PROGRAM: &str = "85iDfUvr3HJyLM2LcLQCB11RkPTV9sXQL4aKtGCPMHha";
+pub const SNS_HASH_PREFIX: &str = "SPL Name Service";
+pub const SNS_TLD: &str = "sol";
+pub const SNS_RECORD_PIC: &str = "pic";
+pub const SNS_RECORD_URL: &str = "url";
+
+/// 账户头：parent_name + owner + class
+const NAME_REGISTRY_HEADER_LEN: usize = 96;
+
+/// SPL Name Service 账户
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameRegistry {
+    pub parent_name: Pubkey,
+    pub owner: Pubkey,
+    pub class: Pubkey,
+    pub data: Vec<u8>,
+}
+
+impl NameRegistry {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            parent_name: read_pubkey(data, 0)?,
+            owner: read_pubkey(data, 32)?,
+            class: read_pubkey(data, 64)?,
+            data: data.get(NAME_REGISTRY_HEADER_LEN..)?.to_vec(),
+        })
+    }
+
+    /// 反向记录账户数据为 borsh 字符串
+    pub fn reverse_name(&self) -> Option<String> {
+        let (name, _) = read_string(&self.data, 0)?;
+        let name = name.replace('\0', "");
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// v1 记录直接存放补零的 UTF-8 字符串
+    pub fn record_value(&self) -> Option<String> {
+        let value = String::from_utf8(self.data.clone()).ok()?;
+        let value = value.trim_matches(char::from(0)).trim();
+        (!value.is_empty()).then(|| value.to_string())
+    }
+}
+
+pub fn hashed_name(name: &str) -> Hash {
+    hashv(&[SNS_HASH_PREFIX.as_bytes(), name.as_bytes()])
+}
+
+pub fn name_account_key(
+    hashed_name: &Hash,
+    class: Option<&Pubkey>,
+    parent: Option<&Pubkey>,
+) -> Pubkey {
+    let seeds = &[
+        hashed_name.as_ref(),
+        class.unwrap_or(&DEFAULT_PUBKEY).as_ref(),
+        parent.unwrap_or(&DEFAULT_PUBKEY).as_ref(),
+    ];
+    let program = Pubkey::from_str(SNS_PROGRAM).unwrap();
+    Pubkey::try_find_program_address(seeds, &program).unwrap().0
+}
+
+/// `bonfida.sol` / `dex.bonfida.sol`，子域名只支持一级
+pub fn domain_key(name: &str) -> Result<Pubkey, Box<dyn Error + Send + Sync>> {
+    let name = name.strip_suffix(".sol").unwrap_or(name);
+    let root = Pubkey::from_str(SNS_ROOT_DOMAIN).unwrap();
+
+    match name.split('.').collect::<Vec<_>>().as_slice() {
+        [domain] if !domain.is_empty() => {
+            Ok(name_account_key(&hashed_name(domain), None, Some(&root)))
+        }
+        [sub, domain] if !sub.is_empty() && !domain.is_empty() => {
+            let parent = name_account_key(&hashed_name(domain), None, Some(&root));
+            // 子域名以 \0 为前缀
+            Ok(name_account_key(
+                &hashed_name(&format!("\0{}", sub)),
+                None,
+                Some(&parent),
+            ))
+        }
+        _ => Err(format!("Invalid SNS name: {}", name).into()),
+    }
+}
+
+/// v1 记录账户，以 \x01 为前缀挂在域名下
+pub fn record_key(domain_key: &Pubkey, record: &str) -> Pubkey {
+    name_account_key(
+        &hashed_name(&format!("\x01{}", record)),
+        None,
+        Some(domain_key),
+    )
+}
+
+pub fn reverse_key(name_account: &Pubkey, parent: Option<&Pubkey>) -> Pubkey {
+    let class = Pubkey::from_str(SNS_REVERSE_LOOKUP_CLASS).unwrap();
+    name_account_key(
+        &hashed_name(&name_account.to_string()),
+        Some(&class),
+        parent,
+    )
+}
+
+pub fn favourite_domain_key(owner: &Pubkey) -> Pubkey {
+    let program = Pubkey::from_str(SNS_NAME_OFFERS_PROGRAM).unwrap();
+    let seeds = &["favourite_domain".as_bytes(), owner.as_ref()];
+    Pubkey::try_find_program_address(seeds, &program).unwrap().0
+}
+
+pub struct SnsResolver {
+    client: SolanaClient,
+}
+
+impl SnsResolver {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            client: SolanaClient::new(rpc_url),
+        }
+    }
+
+    async fn get_registries(
+        &self,
+        keys: &[Pubkey],
+    ) -> Result<Vec<Option<NameRegistry>>, Box<dyn Error + Send + Sync>> {
+        let accounts = get_accounts_data(&self.client, keys).await?;
+        Ok(accounts
+            .into_iter()
+            .map(|x| x.as_deref().and_then(NameRegistry::decode))
+            .collect())
+    }
+
+    async fn get_reverse_name(
+        &self,
+        name_account: &Pubkey,
+        parent: Option<&Pubkey>,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let registries = self
+            .get_registries(&[reverse_key(name_account, parent)])
+            .await?;
+        registries
+            .into_iter()
+            .next()
+            .flatten()
+            .and_then(|x| x.reverse_name())
+            .ok_or_else(|| format!("No reverse record for {}", name_account).into())
+    }
+
+    /// 主域名，未与正向解析结果校验
+    pub async fn get_name(&self, address: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let owner = Pubkey::from_str(address)?;
+        let accounts = get_accounts_data(&self.client, &[favourite_domain_key(&owner)]).await?;
+        // tag(u8) + name_account
+        let name_account = accounts
+            .into_iter()
+            .next()
+            .flatten()
+            .and_then(|x| read_pubkey(&x, 1))
+            .ok_or_else(|| format!("{} has no primary domain", address))?;
+
+        let registry = self
+            .get_registries(std::slice::from_ref(&name_account))
+            .await?
+            .into_iter()
+            .next()
+            .flatten()
+            .ok_or_else(|| format!("{} has no primary domain", address))?;
+
+        let root = Pubkey::from_str(SNS_ROOT_DOMAIN).unwrap();
+        if registry.parent_name == root {
+            let name = self.get_reverse_name(&name_account, None).await?;
+            return Ok(format!("{}.{}", name, SNS_TLD));
+        }
+
+        let parent = registry.parent_name;
+        let (sub, domain) = tokio::try_join!(
+            self.get_reverse_name(&name_account, Some(&parent)),
+            self.get_reverse_name(&parent, None),
+        )?;
+        Ok(format!("{}.{}.{}", sub, domain, SNS_TLD))
+    }
+}
+
+#[async_trait]
+impl NameResolver for SnsResolver {
+    fn provider(&self) -> NameProvider {
+        NameProvider::Sns
+    }
+
+    fn get_chain(&self) -> Chain {
+        Chain::Solana
+    }
+
+    fn is_supported_name(&self, name: &str) -> bool {
+        let name = name.trim().trim_end_matches('.').to_lowercase();
+        name.rsplit_once('.').is_some_and(|(_, tld)| tld == SNS_TLD)
+    }
+
+    async fn resolve_name(&self, name: String) -> Result<NameRecord, Box<dyn Error + Sync + Send>> {
+        let name = name.trim().trim_end_matches('.').to_lowercase();
+        let key = domain_key(&name)?;
+
+        let keys = [
+            key.clone(),
+            record_key(&key, SNS_RECORD_PIC),
+            record_key(&key, SNS_RECORD_URL),
+        ];
+        let mut registries = self.get_registries(&keys).await?.into_iter();
+
+        let owner = registries
+            .next()
+            .flatten()
+            .map(|x| x.owner)
+            .filter(|x| *x != DEFAULT_PUBKEY)
+            .ok_or_else(|| format!("Name not found: {}", name))?;
+        let avatar = registries.next().flatten().and_then(|x| x.record_value());
+        let url = registries.next().flatten().and_then(|x| x.record_value());
+
+        Ok(NameRecord {
+            name,
+            chain: self.get_chain(),
+            address: owner.to_string(),
+            provider: self.provider(),
+            avatar,
+            url,
+        })
+    }
+
+    async fn lookup_address(
+        &self,
+        address: String,
+    ) -> Result<NameRecord, Box<dyn Error + Sync + Send>> {
+        let name = self.get_name(&address).await?;
+        // 域名转让后主域名记录可能过期，需要校验当前持有人
+        let record = self.resolve_name(name).await?;
+        if record.address != address {
+            return Err(format!(
+                "Primary domain {} is no longer owned by {}",
+                record.name, address
+            )
+            .into());
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use base64::{engine::general_purpose, Engine as _};
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    const OWNER: &str = "HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA";
+    const FORMER_OWNER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    pub(crate) fn registry_data(
+        parent: &Pubkey,
+        owner: &Pubkey,
+        class: &Pubkey,
+        data: &[u8],
+    ) -> Vec<u8> {
+        [parent.as_ref(), owner.as_ref(), class.as_ref(), data].concat()
+    }
+
+    fn reverse_data(name: &str) -> Vec<u8> {
+        [(name.len() as u32).to_le_bytes().as_ref(), name.as_bytes()].concat()
+    }
+
+    /// 按地址返回账户数据的 getMultipleAccounts 模拟节点
+    pub(crate) async fn mock_accounts_server(
+        program: &str,
+        accounts: HashMap<String, Vec<u8>>,
+    ) -> MockServer {
+        let server = MockServer::start().await;
+        let program = program.to_string();
+
+        let handle = move |request: &Value| -> Value {
+            assert_eq!(request["method"], "getMultipleAccounts");
+            let value: Vec<Value> = request["params"][0]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|key| match accounts.get(key.as_str().unwrap()) {
+                    Some(data) => {
+                        json!({ "data": [general_purpose::STANDARD.encode(data), "base64"], "owner": program })
+                    }
+                    None => Value::Null,
+                })
+                .collect();
+            json!({ "jsonrpc": "2.0", "id": request["id"], "result": { "value": value } })
+        };
+
+        Mock::given(method("POST"))
+            .respond_with(move |request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                let response = match body.as_array() {
+                    Some(batch) => Value::Array(batch.iter().map(&handle).collect()),
+                    None => handle(&body),
+                };
+                ResponseTemplate::new(200).set_body_json(response)
+            })
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    async fn mock_sns_server() -> MockServer {
+        let root = Pubkey::from_str(SNS_ROOT_DOMAIN).unwrap();
+        let reverse_class = Pubkey::from_str(SNS_REVERSE_LOOKUP_CLASS).unwrap();
+        let owner = Pubkey::from_str(OWNER).unwrap();
+        let former_owner = Pubkey::from_str(FORMER_OWNER).unwrap();
+
+        let bonfida = domain_key("bonfida.sol").unwrap();
+        let dex = domain_key("dex.bonfida.sol").unwrap();
+
+        let mut pic = b"https://example.com/bonfida.png".to_vec();
+        pic.resize(64, 0);
+
+        let accounts = HashMap::from([
+            (
+                bonfida.to_string(),
+                registry_data(&root, &owner, &DEFAULT_PUBKEY, &[]),
+            ),
+            (
+                dex.to_string(),
+                registry_data(&bonfida, &owner, &DEFAULT_PUBKEY, &[]),
+            ),
+            (
+                record_key(&bonfida, SNS_RECORD_PIC).to_string(),
+                registry_data(&bonfida, &owner, &DEFAULT_PUBKEY, &pic),
+            ),
+            (
+                reverse_key(&bonfida, None).to_string(),
+                registry_data(
+                    &DEFAULT_PUBKEY,
+                    &DEFAULT_PUBKEY,
+                    &reverse_class,
+                    &reverse_data("bonfida"),
+                ),
+            ),
+            (
+                reverse_key(&dex, Some(&bonfida)).to_string(),
+                registry_data(
+                    &bonfida,
+                    &DEFAULT_PUBKEY,
+                    &reverse_class,
+                    &reverse_data("\0dex"),
+                ),
+            ),
+            (
+                favourite_domain_key(&owner).to_string(),
+                [&[1u8], dex.as_ref()].concat(),
+            ),
+            (
+                favourite_domain_key(&former_owner).to_string(),
+                [&[1u8], bonfida.as_ref()].concat(),
+            ),
+        ]);
+
+        mock_accounts_server(SNS_PROGRAM, accounts).await
+    }
+
+    #[test]
+    fn test_domain_key() {
+        assert_eq!(
+            domain_key("bonfida.sol").unwrap().to_string(),
+            "Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb"
+        );
+        assert_eq!(
+            domain_key("bonfida").unwrap(),
+            domain_key("bonfida.sol").unwrap()
+        );
+        assert_eq!(
+            domain_key("dex.bonfida.sol").unwrap().to_string(),
+            "HoFfFXqFHAC8RP3duuQNzag1ieUwJRBv1HtRNiWFq4Qu"
+        );
+        assert!(domain_key("a.b.c.sol").is_err());
+        assert!(domain_key(".sol").is_err());
+    }
+
+    #[test]
+    fn test_decode_name_registry() {
+        let root = Pubkey::from_str(SNS_ROOT_DOMAIN).unwrap();
+        let owner = Pubkey::from_str(OWNER).unwrap();
+        let registry =
+            NameRegistry::decode(&registry_data(&root, &owner, &DEFAULT_PUBKEY, b"url\0\0"))
+                .unwrap();
+
+        assert_eq!(registry.parent_name, root);
+        assert_eq!(registry.owner, owner);
+        assert_eq!(registry.class, DEFAULT_PUBKEY);
+        assert_eq!(registry.record_value(), Some("url".to_string()));
+        assert_eq!(NameRegistry::decode(&[0u8; 95]), None);
+    }
+
+    #[test]
+    fn test_is_supported_name() {
+        let resolver = SnsResolver::new("http://localhost".to_string());
+
+        assert!(resolver.is_supported_name("bonfida.sol"));
+        assert!(resolver.is_supported_name("Dex.Bonfida.SOL"));
+        assert!(!resolver.is_supported_name("vitalik.eth"));
+        assert!(!resolver.is_supported_name("sol"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_name() {
+        let server = mock_sns_server().await;
+        let resolver = SnsResolver::new(server.uri());
+
+        let record = resolver
+            .resolve_name("Bonfida.sol".to_string())
+            .await
+            .unwrap();
+        assert_eq!(record.name, "bonfida.sol");
+        assert_eq!(record.address, OWNER);
+        assert_eq!(record.provider, NameProvider::Sns);
+        assert_eq!(
+            record.avatar,
+            Some("https://example.com/bonfida.png".to_string())
+        );
+        assert_eq!(record.url, None);
+
+        let record = resolver
+            .resolve_name("dex.bonfida.sol".to_string())
+            .await
+            .unwrap();
+        assert_eq!(record.address, OWNER);
+
+        assert!(resolver
+            .resolve_name("unknown.sol".to_string())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_lookup_address() {
+        let server = mock_sns_server().await;
+        let resolver = SnsResolver::new(server.uri());
+
+        let record = resolver.lookup_address(OWNER.to_string()).await.unwrap();
+        assert_eq!(record.name, "dex.bonfida.sol");
+
+        // 主域名已转让给他人
+        assert!(resolver
+            .lookup_address(FORMER_OWNER.to_string())
+            .await
+            .is_err());
+        assert!(resolver
+            .lookup_address("invalid".to_string())
+            .await
+            .is_err());
+    }
+}
@@ -1,17 +1,14 @@
 use async_trait::async_trait;
-use primitives::{
-     BroadcastOptions, Chain, Transaction, TransactionStateRequest, TransactionStatus,
-    TransactionUpdate,
-};
-use primitives::{ChainProvider, ChainState, ChainToken, ChainTraits, ChainTransactions};
+use primitives::Chain;
+use primitives::{ChainProvider, ChainState, ChainTraits, ChainTransactions, ChainUtxo};
+use reef_client::Client;
 use std::error::Error;
 
 use crate::rpc::client::SolanaClient;
-use base64::prelude::*;
 
-impl ChainTraits for SolanaClient {}
+impl<C: Client + Clone> ChainTraits for SolanaClient<C> {}
 
-impl ChainProvider for SolanaClient {
+impl<C: Client + Clone> ChainProvider for SolanaClient<C> {
     fn get_chain(&self) -> Chain {
         self.get_chain()
     }
@@ -22,7 +19,7 @@ impl ChainProvider for SolanaClient {
 }
 
 #[async_trait]
-impl ChainState for SolanaClient {
+impl<C: Client + Clone> ChainState for SolanaClient<C> {
     // async fn get_chain_id(&self) -> Result<String, Box<dyn Error + Sync + Send>> {
     //     // Solana doesn't have chain IDs like EVM, so we return a static identifier
     //     Ok("solana-mainnet".to_string())
@@ -35,7 +32,7 @@ impl ChainState for SolanaClient {
 }
 
 #[async_trait]
-impl ChainTransactions for SolanaClient {
+impl<C: Client + Clone> ChainTransactions for SolanaClient<C> {
     /*async fn transaction_broadcast(
         &self,
         data: String,
@@ -87,3 +84,6 @@ impl ChainTransactions for SolanaClient {
         Ok(vec![])
     }*/
 }
+
+#[async_trait]
+impl<C: Client + Clone> ChainUtxo for SolanaClient<C> {}
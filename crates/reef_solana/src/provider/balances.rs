@@ -3,6 +3,7 @@ use std::error::Error;
 use async_trait::async_trait;
 use primitives::ChainBalances;
 use primitives::{AssetBalance, Balance, Chain};
+use reef_client::Client;
 use tracing::{error, warn};
 
 use crate::models::TokenAccountInfoStruct;
@@ -47,7 +48,7 @@ fn parse_token_account_balance(
 }
 
 #[async_trait]
-impl ChainBalances for SolanaClient {
+impl<C: Client + Clone> ChainBalances for SolanaClient<C> {
     async fn get_balance_coin(
         &self,
         address: String,
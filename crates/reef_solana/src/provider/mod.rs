@@ -1,4 +1,7 @@
 pub mod accounts;
 pub mod balances;
+pub mod nft;
+pub mod nft_mapper;
+pub mod simulation;
 pub mod token;
 pub mod token_mapper;
\ No newline at end of file
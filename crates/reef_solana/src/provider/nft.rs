@@ -0,0 +1,412 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use primitives::{ChainNft, NFTAsset, NFTCollection};
+use reef_client::Client;
+
+use crate::metaplex::edition::{decode_edition_key, Edition};
+use crate::metaplex::{decode_metadata, metadata::Metadata};
+use crate::models::DasAsset;
+use crate::provider::nft_mapper::{
+    map_das_nft, map_metadata_nft, map_nft_collections, map_nft_mints,
+};
+use crate::pubkey::Pubkey;
+use crate::rpc::client::SolanaClient;
+use crate::{TOKEN_2022_PROGRAM, TOKEN_PROGRAM};
+
+/// DAS 单页最多返回的资产数
+const DAS_PAGE_LIMIT: u32 = 1000;
+
+impl<C: Client + Clone> SolanaClient<C> {
+    /// 批量获取 mint 的元数据，只保留存在 edition 账户（即 NFT）的 mint
+    pub async fn get_nft_metadata(
+        &self,
+        mints: &[String],
+    ) -> Result<Vec<Metadata>, Box<dyn Error + Send + Sync>> {
+        let mut metadata_addresses = Vec::new();
+        let mut edition_addresses = Vec::new();
+        for mint in mints {
+            let mint = Pubkey::from_str(mint)?;
+            let (Some((metadata, _)), Some((edition, _))) =
+                (Metadata::find_pda(mint.clone()), Edition::find_pda(mint))
+            else {
+                continue;
+            };
+            metadata_addresses.push(metadata.to_string());
+            edition_addresses.push(edition.to_string());
+        }
+        if metadata_addresses.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let count = metadata_addresses.len();
+        let accounts = self
+            .get_multiple_accounts(&[metadata_addresses, edition_addresses].concat())
+            .await?;
+        let (metadata_accounts, edition_accounts) = accounts.split_at(count.min(accounts.len()));
+
+        Ok(metadata_accounts
+            .iter()
+            .zip(edition_accounts)
+            .filter_map(|(metadata, edition)| {
+                let key = decode_edition_key(edition.as_ref()?.data.first()?)?;
+                if !key.is_edition() {
+                    return None;
+                }
+                decode_metadata(metadata.as_ref()?.data.first()?).ok()
+            })
+            .collect())
+    }
+
+    /// 通过代币账户查找地址持有的非压缩 NFT
+    pub async fn get_token_account_nfts(
+        &self,
+        owner: &str,
+    ) -> Result<Vec<Metadata>, Box<dyn Error + Send + Sync>> {
+        let (token_accounts, token_2022_accounts) = tokio::try_join!(
+            self.get_token_accounts_by_program(owner, TOKEN_PROGRAM),
+            self.get_token_accounts_by_program(owner, TOKEN_2022_PROGRAM),
+        )?;
+
+        let mints = map_nft_mints(&[token_accounts, token_2022_accounts].concat());
+        self.get_nft_metadata(&mints).await
+    }
+
+    /// 通过 DAS 分页获取地址持有的全部资产，节点不支持 DAS 时返回 `None`
+    pub async fn get_das_assets(
+        &self,
+        owner: &str,
+    ) -> Result<Option<Vec<DasAsset>>, Box<dyn Error + Send + Sync>> {
+        let mut assets = Vec::new();
+        let mut page = 1;
+        loop {
+            let Some(list) = self
+                .get_assets_by_owner(owner, page, DAS_PAGE_LIMIT)
+                .await?
+            else {
+                return Ok(None);
+            };
+            let count = list.items.len();
+            assets.extend(list.items);
+            if count < DAS_PAGE_LIMIT as usize {
+                return Ok(Some(assets));
+            }
+            page += 1;
+        }
+    }
+
+    /// 批量获取合集 mint 的元数据
+    async fn get_collections_metadata(
+        &self,
+        collections: &[String],
+    ) -> Result<HashMap<String, Metadata>, Box<dyn Error + Send + Sync>> {
+        let mut addresses = Vec::new();
+        let mut keys = Vec::new();
+        for collection in collections {
+            let Ok(mint) = Pubkey::from_str(collection) else {
+                continue;
+            };
+            if let Some((address, _)) = Metadata::find_pda(mint) {
+                addresses.push(address.to_string());
+                keys.push(collection.clone());
+            }
+        }
+        if addresses.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let accounts = self.get_multiple_accounts(&addresses).await?;
+        Ok(keys
+            .into_iter()
+            .zip(accounts)
+            .filter_map(|(key, account)| {
+                let metadata = decode_metadata(account?.data.first()?).ok()?;
+                Some((key, metadata))
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainNft for SolanaClient<C> {
+    async fn get_nfts(
+        &self,
+        address: String,
+    ) -> Result<Vec<NFTCollection>, Box<dyn Error + Sync + Send>> {
+        self.verify_address(address.clone())?;
+
+        let (metadata, das_assets) = tokio::try_join!(
+            self.get_token_account_nfts(&address),
+            self.get_das_assets(&address)
+        )?;
+
+        let mut nfts: Vec<NFTAsset> = metadata
+            .iter()
+            .map(|x| map_metadata_nft(self.get_chain(), x, Some(address.clone())))
+            .collect();
+        // 节点不支持 DAS 时只缺少压缩 NFT，非压缩 NFT 已通过代币账户获取
+        if let Some(assets) = das_assets {
+            nfts.extend(
+                assets
+                    .iter()
+                    .filter(|x| x.is_compressed())
+                    .map(|x| map_das_nft(self.get_chain(), x)),
+            );
+        }
+
+        let mut collections: Vec<String> = Vec::new();
+        for nft in &nfts {
+            if nft.contract_address != nft.token_id && !collections.contains(&nft.contract_address)
+            {
+                collections.push(nft.contract_address.clone());
+            }
+        }
+        let collections = self.get_collections_metadata(&collections).await?;
+
+        Ok(map_nft_collections(self.get_chain(), nfts, &collections))
+    }
+
+    async fn get_nft(
+        &self,
+        contract_address: String,
+        token_id: String,
+    ) -> Result<NFTAsset, Box<dyn Error + Sync + Send>> {
+        self.verify_address(contract_address.clone())?;
+        self.verify_address(token_id.clone())?;
+
+        let nft = match self
+            .get_nft_metadata(std::slice::from_ref(&token_id))
+            .await?
+            .first()
+        {
+            Some(metadata) => map_metadata_nft(self.get_chain(), metadata, None),
+            // 压缩 NFT 没有 mint 账户，只能通过 DAS 查询
+            None => map_das_nft(self.get_chain(), &self.get_asset(&token_id).await?),
+        };
+        // 合集地址为已验证的合集，没有合集时为 mint 自身
+        if nft.contract_address != contract_address {
+            return Err(format!(
+                "NFT {} does not belong to collection {}",
+                token_id, contract_address
+            )
+            .into());
+        }
+        Ok(nft)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metaplex::collection::Collection;
+    use crate::metaplex::data::Data;
+    use crate::metaplex::Key;
+    use base64::{prelude::BASE64_STANDARD, Engine};
+    use borsh::to_vec;
+    use primitives::NFTType;
+    use reef_jsonrpc::ERROR_METHOD_NOT_FOUND;
+    use serde_json::{json, Value};
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    const OWNER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const COLLECTION: &str = "BUjZjAS2vbbb65g7Z1Ca9ZRVYoJscURG5L3AkVvHP9ac";
+    const NFT_MINT: &str = "MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5";
+
+    fn metadata_account(mint: &str, name: &str, collection: Option<&str>) -> String {
+        let metadata = Metadata {
+            key: Key::MetadataV1,
+            update_authority: Pubkey::from_str(mint).unwrap(),
+            mint: Pubkey::from_str(mint).unwrap(),
+            data: Data {
+                name: name.to_string(),
+                symbol: "MAD".to_string(),
+                uri: "https://arweave.net/nft.json".to_string(),
+                seller_fee_basis_points: 500,
+                creators: None,
+            },
+            primary_sale_happened: true,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: collection.map(|key| Collection {
+                verified: true,
+                key: Pubkey::from_str(key).unwrap(),
+            }),
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        };
+        BASE64_STANDARD.encode(to_vec(&metadata).unwrap())
+    }
+
+    fn token_account(mint: &str, amount: &str, decimals: u8) -> Value {
+        json!({
+            "pubkey": "TokenAccountAddress123",
+            "account": {
+                "data": {
+                    "parsed": {
+                        "info": {
+                            "isNative": false,
+                            "mint": mint,
+                            "owner": OWNER,
+                            "state": "initialized",
+                            "tokenAmount": { "amount": amount, "decimals": decimals }
+                        },
+                        "type": "account"
+                    },
+                    "program": "spl-token",
+                    "space": 165
+                },
+                "executable": false,
+                "lamports": 2039280,
+                "owner": TOKEN_PROGRAM
+            }
+        })
+    }
+
+    /// 模拟 Solana RPC + DAS 节点，按 method 分发，同时支持单个请求和批量请求。
+    /// `das_error` 为 DAS 方法返回的错误码
+    async fn mock_das_server(das_error: Option<i32>) -> MockServer {
+        let mint = Pubkey::from_str(NFT_MINT).unwrap();
+        let collection = Pubkey::from_str(COLLECTION).unwrap();
+        let accounts: HashMap<String, String> = HashMap::from([
+            (
+                Metadata::find_pda(mint.clone()).unwrap().0.to_string(),
+                metadata_account(NFT_MINT, "Mad Lad #1", Some(COLLECTION)),
+            ),
+            (
+                Edition::find_pda(mint).unwrap().0.to_string(),
+                BASE64_STANDARD.encode([Key::MasterEditionV2 as u8, 0, 0]),
+            ),
+            (
+                Metadata::find_pda(collection).unwrap().0.to_string(),
+                metadata_account(COLLECTION, "Mad Lads", None),
+            ),
+        ]);
+        let assets: Value = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/testdata/das_get_assets_by_owner.json"
+        )))
+        .unwrap();
+
+        let handle = move |request: &Value| -> Value {
+            let params = &request["params"];
+            let result = match request["method"].as_str().unwrap() {
+                "getTokenAccountsByOwner" if params[1]["programId"] == TOKEN_PROGRAM => json!({
+                    "value": [
+                        token_account(NFT_MINT, "1", 0),
+                        token_account(crate::USDC_MINT, "1500000", 6)
+                    ]
+                }),
+                "getTokenAccountsByOwner" => json!({ "value": [] }),
+                "getMultipleAccounts" => {
+                    let value: Vec<Value> = params[0]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .map(|address| match accounts.get(address.as_str().unwrap()) {
+                            Some(data) => json!({ "data": [data, "base64"], "owner": crate::METAPLEX_PROGRAM }),
+                            None => Value::Null,
+                        })
+                        .collect();
+                    json!({ "value": value })
+                }
+                "getAssetsByOwner" | "getAsset" if das_error.is_some() => {
+                    return json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": { "code": das_error, "message": "DAS request failed" }
+                    });
+                }
+                "getAssetsByOwner" => assets.clone(),
+                "getAsset" => assets["items"][0].clone(),
+                method => panic!("unexpected method {}", method),
+            };
+            json!({ "jsonrpc": "2.0", "id": request["id"], "result": result })
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(move |request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                let response = match body.as_array() {
+                    Some(batch) => Value::Array(batch.iter().map(&handle).collect()),
+                    None => handle(&body),
+                };
+                ResponseTemplate::new(200).set_body_json(response)
+            })
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_get_nfts_with_das() {
+        let server = mock_das_server(None).await;
+        let client = SolanaClient::new(server.uri());
+
+        let collections = client.get_nfts(OWNER.to_string()).await.unwrap();
+
+        assert_eq!(collections.len(), 1);
+        let collection = &collections[0];
+        assert_eq!(collection.contract_address, COLLECTION);
+        assert_eq!(collection.name, Some("Mad Lads".to_string()));
+        assert_eq!(collection.symbol, Some("MAD".to_string()));
+        assert_eq!(collection.nfts.len(), 2);
+        assert_eq!(collection.nfts[0].token_id, NFT_MINT);
+        assert_eq!(collection.nfts[0].token_type, NFTType::SPL);
+        assert_eq!(collection.nfts[0].owner, Some(OWNER.to_string()));
+        assert_eq!(collection.nfts[1].token_type, NFTType::CNFT);
+        assert_eq!(collection.nfts[1].name, Some("Drip #1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_nfts_without_das() {
+        let server = mock_das_server(Some(ERROR_METHOD_NOT_FOUND)).await;
+        let client = SolanaClient::new(server.uri());
+
+        let collections = client.get_nfts(OWNER.to_string()).await.unwrap();
+
+        assert_eq!(collections.len(), 1);
+        assert_eq!(collections[0].nfts.len(), 1);
+        assert_eq!(collections[0].nfts[0].token_type, NFTType::SPL);
+    }
+
+    #[tokio::test]
+    async fn test_get_nfts_das_error() {
+        // 除方法不存在外的 DAS 错误不能当作节点不支持 DAS
+        let server = mock_das_server(Some(-32603)).await;
+        let client = SolanaClient::new(server.uri());
+
+        assert!(client.get_nfts(OWNER.to_string()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_nft() {
+        let server = mock_das_server(None).await;
+        let client = SolanaClient::new(server.uri());
+
+        let nft = client
+            .get_nft(COLLECTION.to_string(), NFT_MINT.to_string())
+            .await
+            .unwrap();
+        assert_eq!(nft.token_type, NFTType::SPL);
+        assert_eq!(nft.name, Some("Mad Lad #1".to_string()));
+
+        let compressed = client
+            .get_nft(
+                COLLECTION.to_string(),
+                "JDuAmJDCxCBCqyfsUCArrhBZVdRqPyBUACavnPTrrhjS".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(compressed.token_type, NFTType::CNFT);
+        let result = client
+            .get_nft(OWNER.to_string(), NFT_MINT.to_string())
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+
+use primitives::{Chain, NFTAsset, NFTCollection, NFTType};
+
+use crate::metaplex::metadata::Metadata;
+use crate::models::{DasAsset, TokenAccountInfoStruct};
+
+fn map_non_empty(value: &str) -> Option<String> {
+    let value = value.trim_matches(char::from(0));
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// 数量为 1 且精度为 0 的代币账户视为 NFT 候选
+pub fn map_nft_mints(accounts: &[TokenAccountInfoStruct]) -> Vec<String> {
+    let mut mints: Vec<String> = Vec::new();
+    for account in accounts {
+        let info = &account.account.data.parsed.info;
+        if info.token_amount.amount == "1"
+            && info.token_amount.decimals == 0
+            && !mints.contains(&info.mint)
+        {
+            mints.push(info.mint.clone());
+        }
+    }
+    mints
+}
+
+/// 已验证的合集地址
+pub fn map_metadata_collection(metadata: &Metadata) -> Option<String> {
+    metadata
+        .collection
+        .as_ref()
+        .filter(|x| x.verified)
+        .map(|x| x.key.to_string())
+}
+
+/// 没有合集的 NFT 以自身 mint 作为合集地址
+pub fn map_metadata_nft(chain: Chain, metadata: &Metadata, owner: Option<String>) -> NFTAsset {
+    let mint = metadata.mint.to_string();
+    NFTAsset {
+        chain,
+        contract_address: map_metadata_collection(metadata).unwrap_or(mint.clone()),
+        token_id: mint,
+        token_type: NFTType::SPL,
+        name: map_non_empty(&metadata.data.name),
+        token_uri: map_non_empty(&metadata.data.uri),
+        owner,
+        balance: Some("1".to_string()),
+    }
+}
+
+pub fn map_das_nft(chain: Chain, asset: &DasAsset) -> NFTAsset {
+    let token_type = if asset.is_compressed() {
+        NFTType::CNFT
+    } else {
+        NFTType::SPL
+    };
+    let json_uri = asset.content.as_ref().and_then(|x| x.json_uri.as_deref());
+
+    NFTAsset {
+        chain,
+        contract_address: asset.collection().unwrap_or(&asset.id).to_string(),
+        token_id: asset.id.clone(),
+        token_type,
+        name: asset
+            .metadata()
+            .and_then(|x| x.name.as_deref())
+            .and_then(map_non_empty),
+        token_uri: json_uri.and_then(map_non_empty),
+        owner: Some(asset.ownership.owner.clone()),
+        balance: Some("1".to_string()),
+    }
+}
+
+/// 按合集分组，保持合集首次出现的顺序
+pub fn map_nft_collections(
+    chain: Chain,
+    nfts: Vec<NFTAsset>,
+    collections: &HashMap<String, Metadata>,
+) -> Vec<NFTCollection> {
+    let mut result: Vec<NFTCollection> = Vec::new();
+
+    for nft in nfts {
+        match result
+            .iter_mut()
+            .find(|x| x.contract_address == nft.contract_address)
+        {
+            Some(collection) => collection.nfts.push(nft),
+            None => {
+                let mut collection = NFTCollection::new(chain, nft.contract_address.clone());
+                if let Some(metadata) = collections.get(&nft.contract_address) {
+                    collection.name = map_non_empty(&metadata.data.name);
+                    collection.symbol = map_non_empty(&metadata.data.symbol);
+                } else if nft.contract_address == nft.token_id {
+                    collection.name = nft.name.clone();
+                }
+                collection.nfts.push(nft);
+                result.push(collection);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metaplex::collection::Collection;
+    use crate::metaplex::data::Data;
+    use crate::metaplex::Key;
+    use crate::models::token_account::{
+        ParsedTokenAccount, TokenAccount, TokenAccountData, TokenAccountParsedInfo, TokenAmount,
+    };
+    use crate::models::DasAssetList;
+    use crate::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    const COLLECTION: &str = "BUjZjAS2vbbb65g7Z1Ca9ZRVYoJscURG5L3AkVvHP9ac";
+
+    fn token_account(mint: &str, amount: &str, decimals: u8) -> TokenAccountInfoStruct {
+        TokenAccountInfoStruct {
+            pubkey: "TokenAccountAddress123".to_string(),
+            account: TokenAccount {
+                data: TokenAccountData {
+                    parsed: ParsedTokenAccount {
+                        info: TokenAccountParsedInfo {
+                            is_native: false,
+                            mint: mint.to_string(),
+                            owner: "OwnerAddress123".to_string(),
+                            state: "initialized".to_string(),
+                            token_amount: TokenAmount {
+                                amount: amount.to_string(),
+                                decimals,
+                                ui_amount: None,
+                                ui_amount_string: None,
+                            },
+                        },
+                        account_type: "account".to_string(),
+                    },
+                    program: "spl-token".to_string(),
+                    space: 165,
+                },
+                executable: false,
+                lamports: 2039280,
+                owner: crate::TOKEN_PROGRAM.to_string(),
+                rent_epoch: None,
+            },
+        }
+    }
+
+    fn metadata(mint: &str, name: &str, collection: Option<(&str, bool)>) -> Metadata {
+        Metadata {
+            key: Key::MetadataV1,
+            update_authority: Pubkey::from_str(mint).unwrap(),
+            mint: Pubkey::from_str(mint).unwrap(),
+            data: Data {
+                name: format!("{:\0<32}", name),
+                symbol: "\0".repeat(10),
+                uri: format!("{:\0<200}", "https://arweave.net/nft.json"),
+                seller_fee_basis_points: 500,
+                creators: None,
+            },
+            primary_sale_happened: true,
+            is_mutable: true,
+            edition_nonce: Some(255),
+            token_standard: None,
+            collection: collection.map(|(key, verified)| Collection {
+                verified,
+                key: Pubkey::from_str(key).unwrap(),
+            }),
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        }
+    }
+
+    #[test]
+    fn test_map_nft_mints() {
+        let accounts = vec![
+            token_account("MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5", "1", 0),
+            token_account(crate::USDC_MINT, "1", 6),
+            token_account("8Qi2DrqTzaS4X4HC1Q3aAb3Rt5tqYb6P7GqPBuAsQFW3", "0", 0),
+            token_account("MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5", "1", 0),
+        ];
+
+        assert_eq!(
+            map_nft_mints(&accounts),
+            vec!["MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_map_metadata_nft() {
+        let mint = "MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5";
+
+        let nft = map_metadata_nft(
+            Chain::Solana,
+            &metadata(mint, "Mad Lad #1", Some((COLLECTION, true))),
+            None,
+        );
+        assert_eq!(nft.contract_address, COLLECTION);
+        assert_eq!(nft.token_id, mint);
+        assert_eq!(nft.token_type, NFTType::SPL);
+        assert_eq!(nft.name, Some("Mad Lad #1".to_string()));
+        assert_eq!(
+            nft.token_uri,
+            Some("https://arweave.net/nft.json".to_string())
+        );
+
+        // 未验证的合集不参与分组
+        let nft = map_metadata_nft(
+            Chain::Solana,
+            &metadata(mint, "Fake", Some((COLLECTION, false))),
+            None,
+        );
+        assert_eq!(nft.contract_address, mint);
+    }
+
+    #[test]
+    fn test_map_das_nft() {
+        let list: DasAssetList = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/testdata/das_get_assets_by_owner.json"
+        )))
+        .unwrap();
+
+        let nft = map_das_nft(Chain::Solana, &list.items[0]);
+        assert_eq!(nft.token_type, NFTType::CNFT);
+        assert_eq!(nft.contract_address, COLLECTION);
+        assert_eq!(nft.name, Some("Drip #1".to_string()));
+        assert_eq!(
+            nft.owner,
+            Some("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string())
+        );
+
+        let nft = map_das_nft(Chain::Solana, &list.items[1]);
+        assert_eq!(nft.token_type, NFTType::SPL);
+        assert_eq!(nft.contract_address, nft.token_id);
+    }
+
+    #[test]
+    fn test_map_nft_collections() {
+        let nfts = vec![
+            map_metadata_nft(
+                Chain::Solana,
+                &metadata(
+                    "MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5",
+                    "Mad Lad #1",
+                    Some((COLLECTION, true)),
+                ),
+                None,
+            ),
+            map_metadata_nft(
+                Chain::Solana,
+                &metadata(
+                    "8Qi2DrqTzaS4X4HC1Q3aAb3Rt5tqYb6P7GqPBuAsQFW3",
+                    "Loose",
+                    None,
+                ),
+                None,
+            ),
+            map_metadata_nft(
+                Chain::Solana,
+                &metadata(
+                    "JDuAmJDCxCBCqyfsUCArrhBZVdRqPyBUACavnPTrrhjS",
+                    "Mad Lad #2",
+                    Some((COLLECTION, true)),
+                ),
+                None,
+            ),
+        ];
+        let collections = HashMap::from([(
+            COLLECTION.to_string(),
+            metadata(COLLECTION, "Mad Lads", None),
+        )]);
+
+        let result = map_nft_collections(Chain::Solana, nfts, &collections);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].contract_address, COLLECTION);
+        assert_eq!(result[0].name, Some("Mad Lads".to_string()));
+        assert_eq!(result[0].nfts.len(), 2);
+        assert_eq!(result[1].name, Some("Loose".to_string()));
+    }
+}
@@ -0,0 +1,430 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::str::FromStr;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use num_bigint::BigInt;
+use primitives::Chain;
+use reef_client::Client;
+
+use crate::models::token::TokenBalanceChange;
+use crate::models::{SimulatedAccount, SimulationBalanceChanges, TokenAccountInfoStruct};
+use crate::pubkey::Pubkey;
+use crate::rpc::client::SolanaClient;
+use crate::utils::token_account::{decode_token_account_base64, get_associated_token_address};
+use crate::utils::transaction::{
+    decode_lookup_table_addresses, decode_message_accounts_base64, MessageAccounts,
+};
+use crate::{ADDRESS_LOOKUP_TABLE_PROGRAM, TOKEN_2022_PROGRAM, TOKEN_PROGRAM};
+
+/// 模拟前签名者代币账户的快照
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenAccountSnapshot {
+    pub address: String,
+    pub mint: String,
+    pub amount: u64,
+}
+
+fn map_token_account_snapshot(
+    account: &TokenAccountInfoStruct,
+) -> Result<TokenAccountSnapshot, Box<dyn Error + Send + Sync>> {
+    let info = &account.account.data.parsed.info;
+    let amount = info
+        .token_amount
+        .amount
+        .parse::<u64>()
+        .map_err(|e| format!("Failed to parse token amount: {}", e))?;
+
+    Ok(TokenAccountSnapshot {
+        address: account.pubkey.clone(),
+        mint: info.mint.clone(),
+        amount,
+    })
+}
+
+fn map_post_token_account(account: &Option<SimulatedAccount>) -> Option<(String, u64)> {
+    let account = account.as_ref()?;
+    if account.owner != TOKEN_PROGRAM && account.owner != TOKEN_2022_PROGRAM {
+        return None;
+    }
+    let state = decode_token_account_base64(account.data.first()?).ok()?;
+    Some((state.mint.to_string(), state.amount))
+}
+
+/// 对比模拟前后的账户状态，计算 SOL 和每个 mint 的余额变化
+///
+/// `post_accounts` 与请求的地址顺序一致：第一个为签名者，其余为 `watched` 中的代币账户。
+pub fn map_simulation_balance_changes(
+    pre_lamports: u64,
+    pre_accounts: &[TokenAccountSnapshot],
+    watched: &[String],
+    post_accounts: &[Option<SimulatedAccount>],
+) -> Result<Vec<TokenBalanceChange>, Box<dyn Error + Send + Sync>> {
+    if post_accounts.len() != watched.len() + 1 {
+        return Err("Simulated accounts do not match requested addresses".into());
+    }
+
+    let mut changes = Vec::new();
+
+    let post_lamports = post_accounts[0].as_ref().map(|x| x.lamports).unwrap_or(0);
+    let lamports_delta = BigInt::from(post_lamports) - BigInt::from(pre_lamports);
+    if lamports_delta != BigInt::from(0) {
+        changes.push(TokenBalanceChange {
+            chain: Chain::Solana,
+            contract_address: None,
+            amount: lamports_delta,
+        });
+    }
+
+    let pre_by_address: HashMap<&str, &TokenAccountSnapshot> = pre_accounts
+        .iter()
+        .map(|x| (x.address.as_str(), x))
+        .collect();
+
+    let mut deltas: BTreeMap<String, BigInt> = BTreeMap::new();
+    for (address, post) in watched.iter().zip(post_accounts[1..].iter()) {
+        let pre = pre_by_address.get(address.as_str());
+        let post = map_post_token_account(post);
+
+        let mint = match (&post, pre) {
+            (Some((mint, _)), _) => mint.clone(),
+            (None, Some(pre)) => pre.mint.clone(),
+            (None, None) => continue,
+        };
+        let pre_amount = pre.map(|x| x.amount).unwrap_or(0);
+        let post_amount = post.map(|(_, amount)| amount).unwrap_or(0);
+
+        *deltas.entry(mint).or_default() += BigInt::from(post_amount) - BigInt::from(pre_amount);
+    }
+
+    changes.extend(
+        deltas
+            .into_iter()
+            .filter(|(_, amount)| *amount != BigInt::from(0))
+            .map(|(mint, amount)| TokenBalanceChange {
+                chain: Chain::Solana,
+                contract_address: Some(mint),
+                amount,
+            }),
+    );
+
+    Ok(changes)
+}
+
+/// 交易中引用、但签名者尚未持有的关联代币账户（例如交易会新建的 ATA）
+fn map_new_associated_accounts(
+    signer: &Pubkey,
+    account_keys: &[Pubkey],
+    known: &[TokenAccountSnapshot],
+) -> Vec<String> {
+    let programs = [TOKEN_PROGRAM, TOKEN_2022_PROGRAM]
+        .iter()
+        .filter_map(|x| Pubkey::from_str(x).ok())
+        .collect::<Vec<_>>();
+
+    let mut accounts: Vec<String> = Vec::new();
+    for key in account_keys {
+        for program in &programs {
+            let Some(address) = get_associated_token_address(signer, key, program) else {
+                continue;
+            };
+            if !account_keys.contains(&address) {
+                continue;
+            }
+            let address = address.to_string();
+            if !known.iter().any(|x| x.address == address) && !accounts.contains(&address) {
+                accounts.push(address);
+            }
+        }
+    }
+    accounts
+}
+
+impl<C: Client + Clone> SolanaClient<C> {
+    /// 静态账户加上 v0 消息通过地址查找表加载的账户
+    async fn get_message_account_keys(
+        &self,
+        message: &MessageAccounts,
+    ) -> Result<Vec<Pubkey>, Box<dyn Error + Send + Sync>> {
+        let mut keys = message.account_keys.clone();
+        if message.address_table_lookups.is_empty() {
+            return Ok(keys);
+        }
+
+        let tables: Vec<String> = message
+            .address_table_lookups
+            .iter()
+            .map(|x| x.account_key.to_string())
+            .collect();
+        let accounts = self.get_multiple_accounts(&tables).await?;
+        for (lookup, account) in message.address_table_lookups.iter().zip(accounts) {
+            let account = account
+                .filter(|x| x.owner == ADDRESS_LOOKUP_TABLE_PROGRAM)
+                .ok_or_else(|| format!("Address lookup table {} not found", lookup.account_key))?;
+            let data = account
+                .data
+                .first()
+                .and_then(|x| BASE64_STANDARD.decode(x).ok())
+                .ok_or("Invalid address lookup table data")?;
+            keys.extend(lookup.resolve(&decode_lookup_table_addresses(&data)?)?);
+        }
+        Ok(keys)
+    }
+
+    /// 预览交易对签名者钱包的影响
+    ///
+    /// `transaction` 为 base64 编码的已签名或未签名交易。模拟前的快照不走缓存，
+    /// 与模拟使用同一确认级别和 `minContextSlot`，避免把无关的转账算进变化里。
+    pub async fn simulate_balance_changes(
+        &self,
+        transaction: &str,
+    ) -> Result<SimulationBalanceChanges, Box<dyn Error + Send + Sync>> {
+        let message = decode_message_accounts_base64(transaction)?;
+        let signer = message
+            .fee_payer()
+            .ok_or("Transaction has no signer")?
+            .clone();
+        let signer_address = signer.to_string();
+        let account_keys = self.get_message_account_keys(&message).await?;
+
+        let slot = self.get_confirmed_slot().await?;
+        let (lamports, token_accounts, token_2022_accounts) = tokio::try_join!(
+            self.get_balance_at(&signer_address, slot),
+            self.get_token_accounts_by_program_at(&signer_address, TOKEN_PROGRAM, slot),
+            self.get_token_accounts_by_program_at(&signer_address, TOKEN_2022_PROGRAM, slot),
+        )?;
+
+        let snapshots = token_accounts
+            .iter()
+            .chain(token_2022_accounts.iter())
+            .map(map_token_account_snapshot)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut watched: Vec<String> = snapshots.iter().map(|x| x.address.clone()).collect();
+        watched.extend(map_new_associated_accounts(
+            &signer,
+            &account_keys,
+            &snapshots,
+        ));
+
+        let mut addresses = vec![signer_address.clone()];
+        addresses.extend(watched.iter().cloned());
+
+        let result = self
+            .simulate_transaction(transaction, addresses, slot)
+            .await?;
+        let post_accounts = result.accounts.unwrap_or_default();
+        let changes = if result.err.is_none() {
+            map_simulation_balance_changes(lamports, &snapshots, &watched, &post_accounts)?
+        } else {
+            vec![]
+        };
+
+        Ok(SimulationBalanceChanges {
+            signer: signer_address,
+            err: result.err,
+            logs: result.logs.unwrap_or_default(),
+            units_consumed: result.units_consumed,
+            changes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::USDC_MINT;
+
+    const OWNER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const BONK_MINT: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
+
+    fn token_account(mint: &str, amount: u64) -> Option<SimulatedAccount> {
+        let mut data = Vec::new();
+        data.extend_from_slice(Pubkey::from_str(mint).unwrap().as_ref());
+        data.extend_from_slice(Pubkey::from_str(OWNER).unwrap().as_ref());
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend([0u8; 93]);
+
+        Some(SimulatedAccount {
+            lamports: 2_039_280,
+            owner: TOKEN_PROGRAM.to_string(),
+            data: vec![BASE64_STANDARD.encode(data), "base64".to_string()],
+        })
+    }
+
+    fn system_account(lamports: u64) -> Option<SimulatedAccount> {
+        Some(SimulatedAccount {
+            lamports,
+            owner: "11111111111111111111111111111111".to_string(),
+            data: vec![String::new(), "base64".to_string()],
+        })
+    }
+
+    #[test]
+    fn test_map_simulation_balance_changes() {
+        let pre = vec![
+            TokenAccountSnapshot {
+                address: "usdc-account".to_string(),
+                mint: USDC_MINT.to_string(),
+                amount: 5_000_000,
+            },
+            TokenAccountSnapshot {
+                address: "closed-account".to_string(),
+                mint: USDC_MINT.to_string(),
+                amount: 1_000_000,
+            },
+        ];
+        let watched = vec![
+            "usdc-account".to_string(),
+            "closed-account".to_string(),
+            "new-bonk-account".to_string(),
+        ];
+        let post = vec![
+            system_account(900_000_000),
+            token_account(USDC_MINT, 2_000_000),
+            None,
+            token_account(BONK_MINT, 42),
+        ];
+
+        let changes = map_simulation_balance_changes(1_000_000_000, &pre, &watched, &post).unwrap();
+
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].contract_address, None);
+        assert_eq!(changes[0].amount, BigInt::from(-100_000_000));
+
+        let usdc = changes
+            .iter()
+            .find(|x| x.contract_address.as_deref() == Some(USDC_MINT))
+            .unwrap();
+        assert_eq!(usdc.amount, BigInt::from(-4_000_000));
+
+        let bonk = changes
+            .iter()
+            .find(|x| x.contract_address.as_deref() == Some(BONK_MINT))
+            .unwrap();
+        assert_eq!(bonk.amount, BigInt::from(42));
+    }
+
+    #[test]
+    fn test_map_simulation_balance_changes_unchanged() {
+        let pre = vec![TokenAccountSnapshot {
+            address: "usdc-account".to_string(),
+            mint: USDC_MINT.to_string(),
+            amount: 5_000_000,
+        }];
+        let watched = vec!["usdc-account".to_string()];
+        let post = vec![system_account(1_000), token_account(USDC_MINT, 5_000_000)];
+
+        let changes = map_simulation_balance_changes(1_000, &pre, &watched, &post).unwrap();
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_map_simulation_balance_changes_length_mismatch() {
+        let watched = vec!["usdc-account".to_string()];
+        let post = vec![system_account(1_000)];
+
+        assert!(map_simulation_balance_changes(1_000, &[], &watched, &post).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_simulate_balance_changes_v0_lookup() {
+        use reef_client::MockClient;
+        use serde_json::json;
+
+        let signer = Pubkey::from_str(OWNER).unwrap();
+        let mint = Pubkey::from_str(BONK_MINT).unwrap();
+        let token_program = Pubkey::from_str(TOKEN_PROGRAM).unwrap();
+        let ata = get_associated_token_address(&signer, &mint, &token_program).unwrap();
+        let table = Pubkey::from([7u8; 32]);
+
+        // v0 message: the new ATA and its mint are only loaded through the lookup table
+        let mut transaction = vec![1u8];
+        transaction.extend([0u8; 64]);
+        transaction.extend([0x80, 1, 0, 1, 1]);
+        transaction.extend_from_slice(signer.as_ref());
+        transaction.extend([0u8; 32]);
+        transaction.push(0);
+        transaction.push(1);
+        transaction.extend_from_slice(table.as_ref());
+        transaction.extend([1, 0, 1, 1]);
+        let transaction = BASE64_STANDARD.encode(transaction);
+
+        let mut table_data = vec![0u8; 56];
+        table_data.extend_from_slice(ata.as_ref());
+        table_data.extend_from_slice(mint.as_ref());
+
+        let mock = MockClient::new()
+            .with_method_response("getSlot", json!(300))
+            .with_method_response(
+                "getMultipleAccounts",
+                json!({ "value": [{
+                    "data": [BASE64_STANDARD.encode(table_data), "base64"],
+                    "owner": ADDRESS_LOOKUP_TABLE_PROGRAM,
+                }] }),
+            )
+            .with_method_response("getBalance", json!({ "value": 1_000_000_000u64 }))
+            .with_method_response("getTokenAccountsByOwner", json!({ "value": [] }))
+            .with_method_response(
+                "simulateTransaction",
+                json!({ "value": {
+                    "err": null,
+                    "logs": [],
+                    "accounts": [system_account(999_000_000), token_account(BONK_MINT, 42)],
+                    "unitsConsumed": 5000,
+                } }),
+            );
+        let client = SolanaClient::new_with_client(mock.clone());
+
+        let result = client.simulate_balance_changes(&transaction).await.unwrap();
+        assert_eq!(result.changes.len(), 2);
+        assert_eq!(result.changes[0].amount, BigInt::from(-1_000_000));
+        assert_eq!(
+            result.changes[1].contract_address.as_deref(),
+            Some(BONK_MINT)
+        );
+        assert_eq!(result.changes[1].amount, BigInt::from(42));
+
+        // the snapshot and the simulation read the same confirmed slot, uncached
+        let requests = mock.requests();
+        let params = |method: &str| {
+            requests
+                .iter()
+                .filter(|(x, _)| x == method)
+                .map(|(_, params)| params.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(params("getSlot")[0][0]["commitment"], "confirmed");
+        assert_eq!(params("getBalance")[0][1]["minContextSlot"], 300);
+        for params in params("getTokenAccountsByOwner") {
+            assert_eq!(params[2]["commitment"], "confirmed");
+            assert_eq!(params[2]["minContextSlot"], 300);
+        }
+        let simulation = &params("simulateTransaction")[0][1];
+        assert_eq!(simulation["commitment"], "confirmed");
+        assert_eq!(simulation["minContextSlot"], 300);
+        assert_eq!(
+            simulation["accounts"]["addresses"],
+            json!([OWNER, ata.to_string()])
+        );
+    }
+
+    #[test]
+    fn test_map_new_associated_accounts() {
+        let signer = Pubkey::from_str(OWNER).unwrap();
+        let mint = Pubkey::from_str(BONK_MINT).unwrap();
+        let token_program = Pubkey::from_str(TOKEN_PROGRAM).unwrap();
+        let ata = get_associated_token_address(&signer, &mint, &token_program).unwrap();
+
+        let keys = vec![signer.clone(), ata.clone(), mint];
+        let accounts = map_new_associated_accounts(&signer, &keys, &[]);
+        assert_eq!(accounts, vec![ata.to_string()]);
+
+        let known = vec![TokenAccountSnapshot {
+            address: ata.to_string(),
+            mint: BONK_MINT.to_string(),
+            amount: 1,
+        }];
+        assert!(map_new_associated_accounts(&signer, &keys, &known).is_empty());
+    }
+}
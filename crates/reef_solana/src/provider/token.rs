@@ -1,15 +1,18 @@
 use async_trait::async_trait;
 use std::error::Error;
+use std::str::FromStr;
 
 use crate::{
     models::Extension,
     provider::token_mapper::{map_token_data_metaplex, map_token_data_spl_token_2022},
+    pubkey::Pubkey,
     rpc::client::SolanaClient,
 };
 use primitives::{Asset, ChainToken};
+use reef_client::Client;
 
 #[async_trait]
-impl ChainToken for SolanaClient {
+impl<C: Client + Clone> ChainToken for SolanaClient<C> {
     async fn get_token_data(
         &self,
         token_address: String,
@@ -34,9 +37,7 @@ impl ChainToken for SolanaClient {
     }
 
     fn get_is_token_address(&self, token_address: &str) -> bool {
-        token_address.len() >= 40
-            && token_address.len() <= 60
-            && bs58::decode(token_address).into_vec().is_ok()
+        Pubkey::from_str(token_address).is_ok()
     }
 
     async fn get_tokens_data(
@@ -68,8 +69,17 @@ impl ChainToken for SolanaClient {
 mod tests {
     use super::*;
     use primitives::ChainToken;
+    use reef_client::{FixtureClient, ReqwestClient};
     use tokio;
 
+    /// 回放 `fixtures/token_data.json`，设置 `REEF_RECORD_FIXTURES` 时从主网重新录制
+    fn fixture_client() -> SolanaClient<FixtureClient<ReqwestClient>> {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/token_data.json");
+        let upstream =
+            ReqwestClient::new_with_url("https://api.mainnet-beta.solana.com".to_string());
+        SolanaClient::new_with_client(FixtureClient::new(path, upstream).unwrap())
+    }
+
     #[test]
     fn test_get_is_token_address() {
         let client = SolanaClient::new("https://api.mainnet-beta.solana.com".to_string());
@@ -90,7 +100,7 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn test_get_token_data_single() {
-        let client = SolanaClient::new("https://api.mainnet-beta.solana.com".to_string());
+        let client = fixture_client();
         let token_address = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string();
 
         let result = client.get_token_data(token_address.clone()).await;
@@ -107,7 +117,7 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn test_get_tokens_data_multiple() {
-        let client = SolanaClient::new("https://api.mainnet-beta.solana.com".to_string());
+        let client = fixture_client();
         let token_addresses = vec![
             "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
             "So11111111111111111111111111111111111111112".to_string(),
@@ -127,6 +137,10 @@ mod tests {
         assert_eq!(asset1.decimals, 6);
         assert_eq!(asset1.symbol, "USDC");
 
+        let asset2 = &assets[1];
+        assert_eq!(asset2.decimals, 9);
+        assert_eq!(asset2.symbol, "SOL");
+
         dbg!(&assets);
     }
 }
@@ -1 +1,3 @@
-// 工具模块 - 目前为空
+// 工具模块
+pub mod token_account;
+pub mod transaction;
@@ -0,0 +1,91 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::pubkey::{Pubkey, PUBKEY_BYTES};
+use crate::ASSOCIATED_TOKEN_PROGRAM;
+
+/// SPL Token 账户的基础布局：mint(32) + owner(32) + amount(8)，Token-2022 账户前缀相同
+const TOKEN_ACCOUNT_MIN_LEN: usize = PUBKEY_BYTES * 2 + 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenAccountState {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+/// 解码原始 SPL Token 账户数据
+pub fn decode_token_account(
+    data: &[u8],
+) -> Result<TokenAccountState, Box<dyn Error + Send + Sync>> {
+    if data.len() < TOKEN_ACCOUNT_MIN_LEN {
+        return Err(format!("Token account data too short: {} bytes", data.len()).into());
+    }
+
+    let mint = Pubkey::try_from(data[0..32].to_vec()).map_err(|_| "Invalid mint")?;
+    let owner = Pubkey::try_from(data[32..64].to_vec()).map_err(|_| "Invalid owner")?;
+    let amount = u64::from_le_bytes(data[64..72].try_into()?);
+
+    Ok(TokenAccountState {
+        mint,
+        owner,
+        amount,
+    })
+}
+
+/// 解码 base64 编码的 SPL Token 账户数据
+pub fn decode_token_account_base64(
+    data: &str,
+) -> Result<TokenAccountState, Box<dyn Error + Send + Sync>> {
+    let bytes = BASE64_STANDARD.decode(data)?;
+    decode_token_account(&bytes)
+}
+
+/// 计算关联代币账户 (ATA) 地址
+pub fn get_associated_token_address(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Option<Pubkey> {
+    let program_id = Pubkey::from_str(ASSOCIATED_TOKEN_PROGRAM).ok()?;
+    let seeds = &[owner.as_ref(), token_program.as_ref(), mint.as_ref()];
+    Pubkey::try_find_program_address(seeds, &program_id).map(|(address, _)| address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TOKEN_PROGRAM, USDC_MINT};
+
+    #[test]
+    fn test_decode_token_account() {
+        let mint = Pubkey::from_str(USDC_MINT).unwrap();
+        let owner = Pubkey::from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap();
+        let mut data = Vec::new();
+        data.extend_from_slice(mint.as_ref());
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(&1_500_000u64.to_le_bytes());
+        data.extend([0u8; 93]);
+
+        let state = decode_token_account_base64(&BASE64_STANDARD.encode(&data)).unwrap();
+        assert_eq!(state.mint, mint);
+        assert_eq!(state.owner, owner);
+        assert_eq!(state.amount, 1_500_000);
+
+        assert!(decode_token_account(&data[..40]).is_err());
+    }
+
+    #[test]
+    fn test_get_associated_token_address() {
+        let owner = Pubkey::from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap();
+        let mint = Pubkey::from_str(USDC_MINT).unwrap();
+        let token_program = Pubkey::from_str(TOKEN_PROGRAM).unwrap();
+
+        let address = get_associated_token_address(&owner, &mint, &token_program).unwrap();
+        assert_eq!(
+            address.to_string(),
+            "FGETo8T8wMcN2wCjav8VK6eh3dLk63evNDPxzLSJra8B"
+        );
+    }
+}
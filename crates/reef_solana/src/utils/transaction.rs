@@ -0,0 +1,291 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use std::error::Error;
+
+use crate::pubkey::{Pubkey, PUBKEY_BYTES};
+
+const SIGNATURE_BYTES: usize = 64;
+const MESSAGE_HEADER_BYTES: usize = 3;
+const VERSION_PREFIX_MASK: u8 = 0x80;
+const BLOCKHASH_BYTES: usize = 32;
+/// 地址查找表账户中地址列表之前的元数据长度
+const LOOKUP_TABLE_META_BYTES: usize = 56;
+
+/// v0 消息通过地址查找表加载的账户
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressTableLookup {
+    pub account_key: Pubkey,
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// 交易消息中的账户信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageAccounts {
+    pub num_required_signatures: u8,
+    pub account_keys: Vec<Pubkey>,
+    /// 仅 v0 消息，legacy 消息为空
+    pub address_table_lookups: Vec<AddressTableLookup>,
+}
+
+impl MessageAccounts {
+    /// 手续费支付者，即第一个签名账户
+    pub fn fee_payer(&self) -> Option<&Pubkey> {
+        if self.num_required_signatures == 0 {
+            return None;
+        }
+        self.account_keys.first()
+    }
+}
+
+/// 读取 compact-u16 (shortvec) 编码的长度
+fn decode_short_u16(
+    data: &[u8],
+    offset: &mut usize,
+) -> Result<usize, Box<dyn Error + Send + Sync>> {
+    let mut value = 0usize;
+    for i in 0..3 {
+        let byte = *data
+            .get(*offset)
+            .ok_or("Unexpected end of transaction data")?;
+        *offset += 1;
+        value |= ((byte & 0x7f) as usize) << (i * 7);
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err("Invalid compact-u16 length".into())
+}
+
+/// 读取 compact-u16 长度前缀的字节数组
+fn decode_short_vec<'a>(
+    data: &'a [u8],
+    offset: &mut usize,
+) -> Result<&'a [u8], Box<dyn Error + Send + Sync>> {
+    let length = decode_short_u16(data, offset)?;
+    let bytes = data
+        .get(*offset..*offset + length)
+        .ok_or("Unexpected end of transaction data")?;
+    *offset += length;
+    Ok(bytes)
+}
+
+fn decode_pubkey(data: &[u8], offset: &mut usize) -> Result<Pubkey, Box<dyn Error + Send + Sync>> {
+    let bytes = data
+        .get(*offset..*offset + PUBKEY_BYTES)
+        .ok_or("Transaction account keys are truncated")?;
+    *offset += PUBKEY_BYTES;
+    Pubkey::try_from(bytes.to_vec()).map_err(|_| "Invalid account key".into())
+}
+
+/// 解析序列化交易（已签名或未签名，legacy 或 v0）中的静态账户与地址查找表
+pub fn decode_message_accounts(
+    transaction: &[u8],
+) -> Result<MessageAccounts, Box<dyn Error + Send + Sync>> {
+    let mut offset = 0;
+    let signatures = decode_short_u16(transaction, &mut offset)?;
+    offset += signatures * SIGNATURE_BYTES;
+
+    let first = *transaction
+        .get(offset)
+        .ok_or("Transaction message is empty")?;
+    let versioned = first & VERSION_PREFIX_MASK != 0;
+    if versioned {
+        offset += 1;
+    }
+
+    let header = transaction
+        .get(offset..offset + MESSAGE_HEADER_BYTES)
+        .ok_or("Transaction message header is truncated")?;
+    let num_required_signatures = header[0];
+    offset += MESSAGE_HEADER_BYTES;
+
+    let keys = decode_short_u16(transaction, &mut offset)?;
+    let account_keys = (0..keys)
+        .map(|_| decode_pubkey(transaction, &mut offset))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut address_table_lookups = Vec::new();
+    if versioned {
+        offset += BLOCKHASH_BYTES;
+        let instructions = decode_short_u16(transaction, &mut offset)?;
+        for _ in 0..instructions {
+            offset += 1; // program id index
+            decode_short_vec(transaction, &mut offset)?;
+            decode_short_vec(transaction, &mut offset)?;
+        }
+        let lookups = decode_short_u16(transaction, &mut offset)?;
+        for _ in 0..lookups {
+            address_table_lookups.push(AddressTableLookup {
+                account_key: decode_pubkey(transaction, &mut offset)?,
+                writable_indexes: decode_short_vec(transaction, &mut offset)?.to_vec(),
+                readonly_indexes: decode_short_vec(transaction, &mut offset)?.to_vec(),
+            });
+        }
+    }
+
+    Ok(MessageAccounts {
+        num_required_signatures,
+        account_keys,
+        address_table_lookups,
+    })
+}
+
+/// 地址查找表账户数据中的地址列表
+pub fn decode_lookup_table_addresses(
+    data: &[u8],
+) -> Result<Vec<Pubkey>, Box<dyn Error + Send + Sync>> {
+    let addresses = data
+        .get(LOOKUP_TABLE_META_BYTES..)
+        .filter(|x| x.len() % PUBKEY_BYTES == 0)
+        .ok_or("Invalid address lookup table data")?;
+    addresses
+        .chunks(PUBKEY_BYTES)
+        .map(|x| Pubkey::try_from(x.to_vec()).map_err(|_| "Invalid lookup table address".into()))
+        .collect()
+}
+
+impl AddressTableLookup {
+    /// 按索引从查找表地址中取出加载的账户，可写账户在前
+    pub fn resolve(&self, table: &[Pubkey]) -> Result<Vec<Pubkey>, Box<dyn Error + Send + Sync>> {
+        self.writable_indexes
+            .iter()
+            .chain(self.readonly_indexes.iter())
+            .map(|index| {
+                table.get(*index as usize).cloned().ok_or_else(|| {
+                    format!("Lookup table {} has no index {}", self.account_key, index).into()
+                })
+            })
+            .collect()
+    }
+}
+
+/// 解析 base64 编码的交易
+pub fn decode_message_accounts_base64(
+    transaction: &str,
+) -> Result<MessageAccounts, Box<dyn Error + Send + Sync>> {
+    let bytes = BASE64_STANDARD
+        .decode(transaction)
+        .map_err(|e| format!("Failed to decode base64 transaction: {}", e))?;
+    decode_message_accounts(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn build_transaction(version: Option<u8>, signatures: u8, keys: &[Pubkey]) -> Vec<u8> {
+        build_transaction_with_lookups(version, signatures, keys, &[])
+    }
+
+    fn build_transaction_with_lookups(
+        version: Option<u8>,
+        signatures: u8,
+        keys: &[Pubkey],
+        lookups: &[AddressTableLookup],
+    ) -> Vec<u8> {
+        let mut data = vec![signatures];
+        data.resize(data.len() + signatures as usize * SIGNATURE_BYTES, 0);
+        if let Some(version) = version {
+            data.push(VERSION_PREFIX_MASK | version);
+        }
+        data.extend([signatures, 0, 1]);
+        data.push(keys.len() as u8);
+        for key in keys {
+            data.extend_from_slice(key.as_ref());
+        }
+        data.extend([0u8; 32]); // recent blockhash
+        data.extend([1, 0, 2, 0, 1, 3, 9, 9, 9]); // one instruction
+        if version.is_some() {
+            data.push(lookups.len() as u8);
+            for lookup in lookups {
+                data.extend_from_slice(lookup.account_key.as_ref());
+                data.push(lookup.writable_indexes.len() as u8);
+                data.extend(&lookup.writable_indexes);
+                data.push(lookup.readonly_indexes.len() as u8);
+                data.extend(&lookup.readonly_indexes);
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_decode_short_u16() {
+        let mut offset = 0;
+        assert_eq!(decode_short_u16(&[0x05], &mut offset).unwrap(), 5);
+
+        let mut offset = 0;
+        assert_eq!(decode_short_u16(&[0x80, 0x01], &mut offset).unwrap(), 128);
+        assert_eq!(offset, 2);
+
+        let mut offset = 0;
+        assert!(decode_short_u16(&[0x80], &mut offset).is_err());
+    }
+
+    #[test]
+    fn test_decode_legacy_message_accounts() {
+        let payer = Pubkey::from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap();
+        let program = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+        let data = build_transaction(None, 1, &[payer.clone(), program.clone()]);
+
+        let accounts = decode_message_accounts(&data).unwrap();
+        assert_eq!(accounts.num_required_signatures, 1);
+        assert_eq!(accounts.account_keys, vec![payer.clone(), program]);
+        assert_eq!(accounts.fee_payer(), Some(&payer));
+    }
+
+    #[test]
+    fn test_decode_versioned_message_accounts() {
+        let payer = Pubkey::from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap();
+        let data = build_transaction(Some(0), 1, std::slice::from_ref(&payer));
+        let encoded = BASE64_STANDARD.encode(&data);
+
+        let accounts = decode_message_accounts_base64(&encoded).unwrap();
+        assert_eq!(accounts.fee_payer(), Some(&payer));
+    }
+
+    #[test]
+    fn test_decode_address_table_lookups() {
+        let payer = Pubkey::from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap();
+        let table = Pubkey::from_str("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263").unwrap();
+        let lookup = AddressTableLookup {
+            account_key: table,
+            writable_indexes: vec![1],
+            readonly_indexes: vec![0],
+        };
+        let data = build_transaction_with_lookups(
+            Some(0),
+            1,
+            std::slice::from_ref(&payer),
+            std::slice::from_ref(&lookup),
+        );
+
+        let accounts = decode_message_accounts(&data).unwrap();
+        assert_eq!(accounts.account_keys, vec![payer.clone()]);
+        assert_eq!(accounts.address_table_lookups, vec![lookup.clone()]);
+
+        let first = Pubkey::from([1u8; 32]);
+        let second = Pubkey::from([2u8; 32]);
+        let mut table_data = vec![0u8; LOOKUP_TABLE_META_BYTES];
+        table_data.extend_from_slice(first.as_ref());
+        table_data.extend_from_slice(second.as_ref());
+        let addresses = decode_lookup_table_addresses(&table_data).unwrap();
+        assert_eq!(lookup.resolve(&addresses).unwrap(), vec![second, first]);
+        assert!(lookup.resolve(&addresses[..1]).is_err());
+        assert!(decode_lookup_table_addresses(&table_data[..60]).is_err());
+
+        // legacy messages have no lookups
+        let legacy = build_transaction(None, 1, std::slice::from_ref(&payer));
+        assert!(decode_message_accounts(&legacy)
+            .unwrap()
+            .address_table_lookups
+            .is_empty());
+    }
+
+    #[test]
+    fn test_decode_truncated_transaction() {
+        assert!(decode_message_accounts(&[]).is_err());
+        assert!(decode_message_accounts(&[1, 0, 0]).is_err());
+        assert!(decode_message_accounts_base64("not base64!").is_err());
+    }
+}
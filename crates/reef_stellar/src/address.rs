@@ -0,0 +1,138 @@
+use data_encoding::BASE32_NOPAD;
+
+/// StrKey 版本字节，`G` 开头的 ed25519 公钥
+const VERSION_ACCOUNT_ID: u8 = 6 << 3;
+const ACCOUNT_ID_LENGTH: usize = 56;
+/// `M` 开头的多路复用账户，公钥后附加 8 字节大端序 ID
+const VERSION_MUXED_ACCOUNT: u8 = 12 << 3;
+const MUXED_ACCOUNT_LENGTH: usize = 69;
+const ED25519_KEY_LENGTH: usize = 32;
+
+/// 多路复用地址对应的基础账户与 memo ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MuxedAccount {
+    pub account_id: String,
+    pub id: u64,
+}
+
+/// CRC16-XMODEM，StrKey 以小端序附加在末尾
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+fn encode_strkey(version: u8, payload: &[u8]) -> String {
+    let mut data = vec![version];
+    data.extend_from_slice(payload);
+    let checksum = crc16(&data).to_le_bytes();
+    data.extend_from_slice(&checksum);
+    BASE32_NOPAD.encode(&data)
+}
+
+/// base32(版本字节 + 数据 + CRC16)，返回版本字节之后的数据
+fn decode_strkey(address: &str, version: u8, length: usize) -> Result<Vec<u8>, String> {
+    let invalid = |reason: &str| format!("Invalid Stellar address {}: {}", address, reason);
+    if address.len() != length {
+        return Err(invalid("bad length"));
+    }
+    let data = BASE32_NOPAD
+        .decode(address.as_bytes())
+        .map_err(|e| invalid(&e.to_string()))?;
+    if data[0] != version {
+        return Err(invalid("unexpected version byte"));
+    }
+    let (payload, checksum) = data.split_at(data.len() - 2);
+    if crc16(payload).to_le_bytes() != checksum {
+        return Err(invalid("bad checksum"));
+    }
+    Ok(payload[1..].to_vec())
+}
+
+/// `G` 开头的账户地址，代币发行方只能是这类地址
+pub fn validate_account_id(address: &str) -> Result<(), String> {
+    decode_strkey(address, VERSION_ACCOUNT_ID, ACCOUNT_ID_LENGTH).map(|_| ())
+}
+
+/// `M` 开头的多路复用地址
+pub fn parse_muxed_address(address: &str) -> Result<MuxedAccount, String> {
+    let payload = decode_strkey(address, VERSION_MUXED_ACCOUNT, MUXED_ACCOUNT_LENGTH)?;
+    let (key, id) = payload.split_at(ED25519_KEY_LENGTH);
+    Ok(MuxedAccount {
+        account_id: encode_strkey(VERSION_ACCOUNT_ID, key),
+        id: u64::from_be_bytes(id.try_into().map_err(|_| "Invalid muxed id")?),
+    })
+}
+
+/// 账户地址或多路复用地址
+pub fn validate_address(address: &str) -> Result<(), String> {
+    base_account_id(address).map(|_| ())
+}
+
+/// Horizon 只按基础账户查询，多路复用地址取其 `G` 地址
+pub fn base_account_id(address: &str) -> Result<String, String> {
+    if address.starts_with('M') {
+        Ok(parse_muxed_address(address)?.account_id)
+    } else {
+        validate_account_id(address)?;
+        Ok(address.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Circle USDC 发行方
+    const ADDRESS: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+    #[test]
+    fn test_validate_address() {
+        assert!(validate_address(ADDRESS).is_ok());
+        assert!(validate_address(&ADDRESS.replace("KZVN", "KZVM")).is_err());
+        assert!(validate_address(&ADDRESS[1..]).is_err());
+        // 私钥种子 S 开头
+        assert!(
+            validate_address("SBZVMB74Z76QZ3ZOY7UTDFYKMEGKW5XFJEB6PFKBF4UYSSWHG4EDH7PY").is_err()
+        );
+        assert!(validate_address(&ADDRESS.to_lowercase()).is_err());
+    }
+
+    #[test]
+    fn test_muxed_address() {
+        // SEP-23 测试向量
+        let muxed = "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVAAAAAAAAAAAAAJLK";
+        let base = "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+        assert!(validate_address(muxed).is_ok());
+        assert_eq!(
+            parse_muxed_address(muxed).unwrap(),
+            MuxedAccount {
+                account_id: base.to_string(),
+                id: 9223372036854775808,
+            }
+        );
+        assert_eq!(
+            parse_muxed_address(
+                "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAAAAAAAACJUQ"
+            )
+            .unwrap()
+            .id,
+            0
+        );
+        assert_eq!(base_account_id(muxed).unwrap(), base);
+        assert_eq!(base_account_id(base).unwrap(), base);
+
+        assert!(validate_account_id(muxed).is_err());
+        assert!(validate_address(&muxed.replace("AJLK", "AJLA")).is_err());
+        assert!(parse_muxed_address(base).is_err());
+    }
+}
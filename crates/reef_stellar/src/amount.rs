@@ -0,0 +1,35 @@
+use num_bigint::BigUint;
+
+use crate::STELLAR_DECIMALS;
+
+/// Horizon 金额固定 7 位小数，例如 `100.0000000`
+pub fn parse_amount(amount: &str) -> Option<BigUint> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if fraction.len() > STELLAR_DECIMALS as usize {
+        return None;
+    }
+    let digits = format!(
+        "{}{:0<width$}",
+        integer,
+        fraction,
+        width = STELLAR_DECIMALS as usize
+    );
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(
+            parse_amount("100.0000000"),
+            Some(BigUint::from(1_000_000_000u64))
+        );
+        assert_eq!(parse_amount("0.5"), Some(BigUint::from(5_000_000u64)));
+        assert_eq!(parse_amount("12"), Some(BigUint::from(120_000_000u64)));
+        assert_eq!(parse_amount("1.00000001"), None);
+        assert_eq!(parse_amount("-1.0"), None);
+    }
+}
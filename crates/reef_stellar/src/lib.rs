@@ -0,0 +1,12 @@
+pub mod address;
+pub mod amount;
+pub mod models;
+pub mod provider;
+pub mod rpc;
+
+/// XLM 与发行资产都是 7 位小数
+pub const STELLAR_DECIMALS: u8 = 7;
+
+// Re-export for convenience
+pub use models::*;
+pub use rpc::StellarClient;
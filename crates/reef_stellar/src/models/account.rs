@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+pub const ASSET_TYPE_NATIVE: &str = "native";
+
+/// `/accounts/{account_id}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonAccount {
+    pub account_id: String,
+    pub sequence: String,
+    /// 信任线、挂单等子条目数量
+    pub subentry_count: u64,
+    #[serde(default)]
+    pub num_sponsoring: u64,
+    #[serde(default)]
+    pub num_sponsored: u64,
+    pub balances: Vec<HorizonBalance>,
+}
+
+impl HorizonAccount {
+    /// 最低余额对应的基础储备数量
+    pub fn reserve_entries(&self) -> u64 {
+        (2 + self.subentry_count + self.num_sponsoring).saturating_sub(self.num_sponsored)
+    }
+
+    pub fn native_balance(&self) -> Option<&HorizonBalance> {
+        self.balances
+            .iter()
+            .find(|x| x.asset_type == ASSET_TYPE_NATIVE)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonBalance {
+    pub balance: String,
+    /// `native`、`credit_alphanum4`、`credit_alphanum12` 或 `liquidity_pool_shares`
+    pub asset_type: String,
+    pub asset_code: Option<String>,
+    pub asset_issuer: Option<String>,
+    /// 挂单锁定的金额
+    pub selling_liabilities: Option<String>,
+}
+
+impl HorizonBalance {
+    /// 发行资产标识 `{code}-{issuer}`，原生币与流动性份额为 None
+    pub fn asset_id(&self) -> Option<String> {
+        asset_id(self.asset_code.as_deref(), self.asset_issuer.as_deref())
+    }
+}
+
+pub fn asset_id(code: Option<&str>, issuer: Option<&str>) -> Option<String> {
+    Some(format!("{}-{}", code?, issuer?))
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// `/ledgers?order=desc&limit=1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonLedger {
+    pub sequence: u64,
+    pub base_fee_in_stroops: u64,
+    pub base_reserve_in_stroops: u64,
+}
@@ -0,0 +1,21 @@
+pub mod account;
+pub mod ledger;
+pub mod transaction;
+
+pub use account::*;
+pub use ledger::*;
+pub use transaction::*;
+
+use serde::{Deserialize, Serialize};
+
+/// Horizon 分页列表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    #[serde(rename = "_embedded")]
+    pub embedded: Embedded<T>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Embedded<T> {
+    pub records: Vec<T>,
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use super::account::asset_id;
+
+/// `/transactions/{hash}`，也是提交交易的返回
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonTransaction {
+    pub hash: String,
+    pub successful: bool,
+    pub ledger: u64,
+    /// `none`、`text`、`id`、`hash` 或 `return`
+    #[serde(default)]
+    pub memo_type: String,
+    pub memo: Option<String>,
+    pub fee_charged: String,
+}
+
+/// `/accounts/{account_id}/payments`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRecord {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub payment_type: String,
+    pub transaction_hash: String,
+    #[serde(default)]
+    pub transaction_successful: bool,
+    pub created_at: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub amount: Option<String>,
+    pub asset_type: Option<String>,
+    pub asset_code: Option<String>,
+    pub asset_issuer: Option<String>,
+    /// `create_account` 的字段
+    pub funder: Option<String>,
+    pub account: Option<String>,
+    pub starting_balance: Option<String>,
+    /// `join=transactions` 时附带，memo 在交易上
+    pub transaction: Option<HorizonTransaction>,
+}
+
+/// 入账需要的支付信息，交易所按 memo 区分充值用户
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StellarTransaction {
+    pub hash: String,
+    pub from: String,
+    pub to: String,
+    pub memo_type: String,
+    pub memo: Option<String>,
+    /// 发行资产标识，原生 XLM 为 None
+    pub asset_id: Option<String>,
+    /// 十进制字符串
+    pub amount: String,
+    pub ledger: Option<u64>,
+    pub created_at: String,
+    pub successful: bool,
+}
+
+impl StellarTransaction {
+    /// 只处理 payment 与 create_account，path payment 的到账资产同样在 `asset_*` 中
+    pub fn from_payment(record: PaymentRecord) -> Option<Self> {
+        let (from, to, amount) = match record.payment_type.as_str() {
+            "create_account" => (record.funder?, record.account?, record.starting_balance?),
+            "payment" | "path_payment_strict_receive" | "path_payment_strict_send" => {
+                (record.from?, record.to?, record.amount?)
+            }
+            _ => return None,
+        };
+        let transaction = record.transaction;
+        Some(Self {
+            hash: record.transaction_hash,
+            from,
+            to,
+            memo_type: transaction
+                .as_ref()
+                .map(|x| x.memo_type.clone())
+                .unwrap_or_default(),
+            memo: transaction.as_ref().and_then(|x| x.memo.clone()),
+            asset_id: asset_id(record.asset_code.as_deref(), record.asset_issuer.as_deref()),
+            amount,
+            ledger: transaction.as_ref().map(|x| x.ledger),
+            created_at: record.created_at,
+            successful: record.transaction_successful,
+        })
+    }
+}
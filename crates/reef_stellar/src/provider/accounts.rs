@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use primitives::{
+    Chain, ChainNft, ChainProvider, ChainState, ChainTraits, ChainTransactions, ChainUtxo,
+};
+use reef_client::Client;
+use std::error::Error;
+
+use crate::rpc::client::StellarClient;
+
+impl<C: Client + Clone> ChainTraits for StellarClient<C> {}
+
+impl<C: Client + Clone> ChainProvider for StellarClient<C> {
+    fn get_chain(&self) -> Chain {
+        self.get_chain()
+    }
+
+    fn verify_address(&self, address: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.validate_address(&address)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainState for StellarClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainTransactions for StellarClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainNft for StellarClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainUtxo for StellarClient<C> {}
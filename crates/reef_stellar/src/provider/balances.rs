@@ -0,0 +1,190 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{AssetBalance, Balance, Chain, ChainBalances};
+use reef_client::Client;
+
+use crate::amount::parse_amount;
+use crate::models::{HorizonAccount, HorizonLedger};
+use crate::rpc::client::StellarClient;
+use crate::STELLAR_DECIMALS;
+
+fn parse_optional(amount: Option<&String>) -> BigUint {
+    amount.and_then(|x| parse_amount(x)).unwrap_or_default()
+}
+
+/// 可用余额，扣除最低余额与挂单锁定的部分
+fn map_balance_coin(account: Option<&HorizonAccount>, ledger: &HorizonLedger) -> AssetBalance {
+    let available = account
+        .and_then(|account| {
+            let native = account.native_balance()?;
+            let locked = BigUint::from(account.reserve_entries() * ledger.base_reserve_in_stroops)
+                + parse_optional(native.selling_liabilities.as_ref());
+            let balance = parse_optional(Some(&native.balance));
+            Some(if balance > locked {
+                balance - locked
+            } else {
+                BigUint::ZERO
+            })
+        })
+        .unwrap_or_default();
+    AssetBalance {
+        chain: Chain::Stellar,
+        contract_address: None,
+        balance: Balance::coin_balance(available, STELLAR_DECIMALS),
+        is_active: Some(account.is_some()),
+    }
+}
+
+fn map_token_balances(account: Option<HorizonAccount>) -> Vec<(String, AssetBalance)> {
+    let Some(account) = account else {
+        return vec![];
+    };
+    account
+        .balances
+        .iter()
+        .filter_map(|x| {
+            let asset_id = x.asset_id()?;
+            let balance = AssetBalance::new_token(
+                Chain::Stellar,
+                Some(asset_id.clone()),
+                parse_optional(Some(&x.balance)),
+                STELLAR_DECIMALS,
+            );
+            Some((asset_id, balance))
+        })
+        .collect()
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for StellarClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let account = self.get_account(&address).await?;
+        let ledger = self.get_latest_ledger().await?;
+        Ok(map_balance_coin(account.as_ref(), &ledger))
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let account = self.get_account(&address).await?;
+        Ok(map_token_balances(account)
+            .into_iter()
+            .filter(|(asset_id, _)| token_addresses.contains(asset_id))
+            .map(|(_, balance)| balance)
+            .collect())
+    }
+
+    async fn get_assets_balances(
+        &self,
+        address: String,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        let account = self.get_account(&address).await?;
+        Ok(map_token_balances(account)
+            .into_iter()
+            .map(|(_, balance)| balance)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "GCEZWKCA5VLDNRLN3RPRJMRZOX3Z6G5CHCGSNFHEYVXM3XOJMDS674JZ";
+    const ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+    const MUXED_ADDRESS: &str =
+        "MCEZWKCA5VLDNRLN3RPRJMRZOX3Z6G5CHCGSNFHEYVXM3XOJMDS66AAAAAAAAAAAFLB46";
+
+    fn client() -> StellarClient<MockClient> {
+        let mock = MockClient::new()
+            .with_get(
+                &format!("/accounts/{}", ADDRESS),
+                json!({
+                    "account_id": ADDRESS,
+                    "sequence": "176093659137",
+                    "subentry_count": 2,
+                    "num_sponsoring": 0,
+                    "num_sponsored": 1,
+                    "balances": [
+                        {
+                            "balance": "42.5000000",
+                            "asset_type": "credit_alphanum4",
+                            "asset_code": "USDC",
+                            "asset_issuer": ISSUER,
+                            "selling_liabilities": "0.0000000"
+                        },
+                        {
+                            "balance": "0.0100000",
+                            "asset_type": "liquidity_pool_shares",
+                            "liquidity_pool_id": "abcd"
+                        },
+                        {
+                            "balance": "10.0000000",
+                            "asset_type": "native",
+                            "selling_liabilities": "1.0000000"
+                        }
+                    ]
+                }),
+            )
+            .with_get(
+                "/ledgers?order=desc&limit=1",
+                json!({
+                    "_embedded": {
+                        "records": [{ "sequence": 55756331, "base_fee_in_stroops": 100, "base_reserve_in_stroops": 5000000 }]
+                    }
+                }),
+            );
+        StellarClient::new_with_client(mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let client = client();
+        let balance = client.get_balance_coin(ADDRESS.to_string()).await.unwrap();
+        // 10 XLM - (2 + 2 - 1) * 0.5 XLM - 1 XLM 挂单
+        assert_eq!(balance.balance.amount, "75000000");
+        assert_eq!(balance.balance.decimals, 7);
+        assert_eq!(balance.is_active, Some(true));
+
+        // 多路复用地址读取基础账户
+        let balance = client
+            .get_balance_coin(MUXED_ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balance.balance.amount, "75000000");
+
+        let balance = client.get_balance_coin(ISSUER.to_string()).await.unwrap();
+        assert_eq!(balance.balance.amount, "0");
+        assert_eq!(balance.is_active, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_get_token_balances() {
+        let client = client();
+        let balances = client
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(
+            balances[0].contract_address,
+            Some(format!("USDC-{}", ISSUER))
+        );
+        assert_eq!(balances[0].balance.amount, "425000000");
+
+        let balances = client
+            .get_balance_tokens(ADDRESS.to_string(), vec![format!("EURC-{}", ISSUER)])
+            .await
+            .unwrap();
+        assert!(balances.is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+use crate::{address::validate_account_id, rpc::client::StellarClient, STELLAR_DECIMALS};
+use primitives::{Asset, AssetType, Chain, ChainToken};
+use reef_client::Client;
+
+/// 代币标识 `{code}-{issuer}`，代码为 1 到 12 位字母数字
+pub fn parse_asset_id(asset_id: &str) -> Result<(&str, &str), String> {
+    let (code, issuer) = asset_id
+        .split_once('-')
+        .ok_or_else(|| format!("Invalid Stellar asset: {}", asset_id))?;
+    if code.is_empty() || code.len() > 12 || !code.chars().all(|x| x.is_ascii_alphanumeric()) {
+        return Err(format!("Invalid Stellar asset code: {}", asset_id));
+    }
+    validate_account_id(issuer)?;
+    Ok((code, issuer))
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for StellarClient<C> {
+    /// 名称在发行方的 stellar.toml 中，这里使用资产代码
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let (code, _) = parse_asset_id(&token_address)?;
+        Ok(Asset::new(
+            code.to_string(),
+            code.to_string(),
+            STELLAR_DECIMALS as i32,
+            Chain::Stellar,
+            Some(token_address),
+            AssetType::TOKEN,
+        ))
+    }
+
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        parse_asset_id(token_address).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+
+    const USDC: &str = "USDC-GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+    #[tokio::test]
+    async fn test_get_token_data() {
+        let client = StellarClient::new_with_client(MockClient::new());
+        let asset = client.get_token_data(USDC.to_string()).await.unwrap();
+        assert_eq!(asset.symbol, "USDC");
+        assert_eq!(asset.decimals, 7);
+        assert_eq!(asset.asset_type, AssetType::TOKEN);
+        assert!(client.get_is_token_address(USDC));
+        assert!(!client.get_is_token_address("USDC"));
+        assert!(!client.get_is_token_address("USDC-GA5Z"));
+    }
+}
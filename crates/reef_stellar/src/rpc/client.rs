@@ -0,0 +1,255 @@
+use crate::address::{base_account_id, validate_address};
+use crate::models::{
+    HorizonAccount, HorizonLedger, HorizonTransaction, Page, PaymentRecord, StellarTransaction,
+};
+use primitives::{Chain, TransactionStatus, TransactionUpdate};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use std::error::Error;
+
+const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+
+/// 表单编码，base64 XDR 中的 `+/=` 需要转义
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|x| match x {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (x as char).to_string()
+            }
+            _ => format!("%{:02X}", x),
+        })
+        .collect()
+}
+
+/// Horizon REST 客户端
+#[derive(Debug, Clone)]
+pub struct StellarClient<C: Client + Clone = ReqwestClient> {
+    client: C,
+}
+
+impl StellarClient {
+    pub fn new(url: String) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url))
+    }
+
+    pub fn new_with_config(url: String, config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(ReqwestClient::new_with_config(
+            url, config,
+        )?))
+    }
+}
+
+impl<C: Client + Clone> StellarClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C) -> Self {
+        Self { client }
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        Chain::Stellar
+    }
+
+    pub fn validate_address(&self, address: &str) -> Result<(), String> {
+        validate_address(address)
+    }
+
+    /// 未激活的账户返回 None，多路复用地址返回其基础账户
+    pub async fn get_account(
+        &self,
+        address: &str,
+    ) -> Result<Option<HorizonAccount>, Box<dyn Error + Send + Sync>> {
+        let path = format!("/accounts/{}", base_account_id(address)?);
+        match self.client.get(&path).await {
+            Ok(account) => Ok(Some(account)),
+            Err(ClientError::HttpError { status: 404, .. }) => Ok(None),
+            Err(e) => Err(format!("Failed to get account: {}", e).into()),
+        }
+    }
+
+    /// 最新账本中的基础储备与手续费
+    pub async fn get_latest_ledger(&self) -> Result<HorizonLedger, Box<dyn Error + Send + Sync>> {
+        let page: Page<HorizonLedger> = self
+            .client
+            .get("/ledgers?order=desc&limit=1")
+            .await
+            .map_err(|e| format!("Failed to get ledger: {}", e))?;
+        Ok(page
+            .embedded
+            .records
+            .into_iter()
+            .next()
+            .ok_or("Empty ledger response")?)
+    }
+
+    /// 最近的入账与出账支付，附带交易 memo，多路复用地址返回基础账户的全部支付
+    pub async fn get_transactions(
+        &self,
+        address: &str,
+        limit: usize,
+    ) -> Result<Vec<StellarTransaction>, Box<dyn Error + Send + Sync>> {
+        let path = format!(
+            "/accounts/{}/payments?order=desc&limit={}&join=transactions",
+            base_account_id(address)?,
+            limit
+        );
+        let page: Page<PaymentRecord> = match self.client.get(&path).await {
+            Ok(page) => page,
+            Err(ClientError::HttpError { status: 404, .. }) => return Ok(vec![]),
+            Err(e) => return Err(format!("Failed to get transactions: {}", e).into()),
+        };
+        Ok(page
+            .embedded
+            .records
+            .into_iter()
+            .filter_map(StellarTransaction::from_payment)
+            .collect())
+    }
+
+    /// 提交 base64 编码的 `TransactionEnvelope` XDR，返回交易 hash
+    pub async fn broadcast_transaction(
+        &self,
+        envelope: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let body = format!("tx={}", form_encode(envelope));
+        let response: HorizonTransaction = self
+            .client
+            .post_bytes("/transactions", body.into_bytes(), FORM_CONTENT_TYPE)
+            .await
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        Ok(response.hash)
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        hash: &str,
+    ) -> Result<TransactionUpdate, Box<dyn Error + Send + Sync>> {
+        let path = format!("/transactions/{}", hash);
+        let transaction: HorizonTransaction = match self.client.get(&path).await {
+            Ok(transaction) => transaction,
+            Err(ClientError::HttpError { status: 404, .. }) => {
+                return Ok(TransactionUpdate {
+                    hash: hash.to_string(),
+                    status: TransactionStatus::Pending,
+                    block_number: None,
+                    confirmations: 0,
+                })
+            }
+            Err(e) => return Err(format!("Failed to get transaction: {}", e).into()),
+        };
+        Ok(TransactionUpdate {
+            hash: transaction.hash,
+            status: if transaction.successful {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Failed
+            },
+            block_number: Some(transaction.ledger),
+            confirmations: 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+    const SENDER: &str = "GCEZWKCA5VLDNRLN3RPRJMRZOX3Z6G5CHCGSNFHEYVXM3XOJMDS674JZ";
+    const HASH: &str = "3389e9f0f1a65f19736cacf544c2e825313e8447f569233bb8db39aa607c8889";
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("AAAA+b/c=="), "AAAA%2Bb%2Fc%3D%3D");
+    }
+
+    #[tokio::test]
+    async fn test_get_transactions() {
+        let mock = MockClient::new().with_get(
+            &format!(
+                "/accounts/{}/payments?order=desc&limit=10&join=transactions",
+                ADDRESS
+            ),
+            json!({
+                "_embedded": {
+                    "records": [
+                        {
+                            "id": "239471208741056513",
+                            "type": "payment",
+                            "transaction_hash": HASH,
+                            "transaction_successful": true,
+                            "created_at": "2025-06-01T10:00:00Z",
+                            "from": SENDER,
+                            "to": ADDRESS,
+                            "amount": "150.0000000",
+                            "asset_type": "credit_alphanum4",
+                            "asset_code": "USDC",
+                            "asset_issuer": ADDRESS,
+                            "transaction": {
+                                "hash": HASH,
+                                "successful": true,
+                                "ledger": 55756331,
+                                "memo_type": "id",
+                                "memo": "3847592",
+                                "fee_charged": "100"
+                            }
+                        },
+                        {
+                            "id": "239471208741056514",
+                            "type": "create_account",
+                            "transaction_hash": HASH,
+                            "transaction_successful": true,
+                            "created_at": "2025-05-01T10:00:00Z",
+                            "funder": SENDER,
+                            "account": ADDRESS,
+                            "starting_balance": "5.0000000"
+                        },
+                        {
+                            "id": "239471208741056515",
+                            "type": "change_trust",
+                            "transaction_hash": HASH,
+                            "created_at": "2025-05-01T10:00:00Z"
+                        }
+                    ]
+                }
+            }),
+        );
+        let client = StellarClient::new_with_client(mock);
+
+        let transactions = client.get_transactions(ADDRESS, 10).await.unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].memo_type, "id");
+        assert_eq!(transactions[0].memo.as_deref(), Some("3847592"));
+        assert_eq!(transactions[0].asset_id, Some(format!("USDC-{}", ADDRESS)));
+        assert_eq!(transactions[0].ledger, Some(55756331));
+        assert_eq!(transactions[1].from, SENDER);
+        assert_eq!(transactions[1].amount, "5.0000000");
+        assert_eq!(transactions[1].asset_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_and_status() {
+        let envelope = "AAAAAgAAAAB+Yn/c==";
+        let mock = MockClient::new()
+            .with_post_bytes(
+                "/transactions",
+                format!("tx={}", form_encode(envelope)).as_bytes(),
+                json!({ "hash": HASH, "successful": true, "ledger": 55756331, "fee_charged": "100" }),
+            )
+            .with_get(
+                &format!("/transactions/{}", HASH),
+                json!({ "hash": HASH, "successful": true, "ledger": 55756331, "memo_type": "none", "fee_charged": "100" }),
+            );
+        let client = StellarClient::new_with_client(mock);
+
+        assert_eq!(client.broadcast_transaction(envelope).await.unwrap(), HASH);
+
+        let update = client.get_transaction_status(HASH).await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Confirmed));
+        assert_eq!(update.block_number, Some(55756331));
+
+        let update = client.get_transaction_status("00").await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Pending));
+    }
+}
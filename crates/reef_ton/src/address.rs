@@ -0,0 +1,189 @@
+use base64::engine::general_purpose::{STANDARD, URL_SAFE};
+use base64::Engine;
+use std::fmt;
+use std::str::FromStr;
+
+const FLAG_BOUNCEABLE: u8 = 0x11;
+const FLAG_NON_BOUNCEABLE: u8 = 0x51;
+const FLAG_TESTNET: u8 = 0x80;
+const FRIENDLY_LENGTH: usize = 48;
+/// 标志位 1 + workchain 1 + hash 32 + crc16 2
+const FRIENDLY_BYTES: usize = 36;
+
+/// CRC16-XMODEM，用户友好地址的校验和
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// TON 地址，解析 raw (`0:hex`) 与用户友好 (base64) 两种格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TonAddress {
+    pub workchain: i8,
+    pub hash: [u8; 32],
+    /// 用户友好格式中的标志位，raw 格式默认可回弹
+    pub bounceable: bool,
+    pub testnet: bool,
+}
+
+impl TonAddress {
+    pub fn new(workchain: i8, hash: [u8; 32]) -> Self {
+        Self {
+            workchain,
+            hash,
+            bounceable: true,
+            testnet: false,
+        }
+    }
+
+    fn from_raw(address: &str) -> Result<Self, String> {
+        let (workchain, hash) = address
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid TON address: {}", address))?;
+        let workchain = workchain
+            .parse::<i8>()
+            .map_err(|_| format!("Invalid workchain: {}", address))?;
+        let hash = hex::decode(hash).map_err(|_| format!("Invalid TON address: {}", address))?;
+        let hash: [u8; 32] = hash
+            .try_into()
+            .map_err(|_| format!("Invalid TON address length: {}", address))?;
+        Ok(Self::new(workchain, hash))
+    }
+
+    fn from_friendly(address: &str) -> Result<Self, String> {
+        if address.len() != FRIENDLY_LENGTH {
+            return Err(format!("Invalid TON address length: {}", address));
+        }
+        let data = if address.contains(['-', '_']) {
+            URL_SAFE.decode(address)
+        } else {
+            STANDARD.decode(address)
+        }
+        .map_err(|_| format!("Invalid TON address: {}", address))?;
+        // 48 位中带 `=` 填充时解码不足 36 字节
+        if data.len() != FRIENDLY_BYTES {
+            return Err(format!("Invalid TON address length: {}", address));
+        }
+
+        let checksum = u16::from_be_bytes([data[34], data[35]]);
+        if crc16(&data[..34]) != checksum {
+            return Err(format!("Invalid TON address checksum: {}", address));
+        }
+        let testnet = data[0] & FLAG_TESTNET != 0;
+        let bounceable = match data[0] & !FLAG_TESTNET {
+            FLAG_BOUNCEABLE => true,
+            FLAG_NON_BOUNCEABLE => false,
+            _ => return Err(format!("Invalid TON address flags: {}", address)),
+        };
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&data[2..34]);
+        Ok(Self {
+            workchain: data[1] as i8,
+            hash,
+            bounceable,
+            testnet,
+        })
+    }
+
+    /// `0:83df...`，toncenter 返回的地址为大写 raw 格式
+    pub fn to_raw(&self) -> String {
+        format!("{}:{}", self.workchain, hex::encode(self.hash))
+    }
+
+    /// URL 安全的 base64 格式
+    pub fn to_friendly(&self, bounceable: bool) -> String {
+        let mut flag = if bounceable {
+            FLAG_BOUNCEABLE
+        } else {
+            FLAG_NON_BOUNCEABLE
+        };
+        if self.testnet {
+            flag |= FLAG_TESTNET;
+        }
+        let mut data = Vec::with_capacity(36);
+        data.push(flag);
+        data.push(self.workchain as u8);
+        data.extend_from_slice(&self.hash);
+        data.extend_from_slice(&crc16(&data).to_be_bytes());
+        URL_SAFE.encode(data)
+    }
+
+    /// 忽略格式标志，比较是否为同一账户
+    pub fn same_account(&self, other: &TonAddress) -> bool {
+        self.workchain == other.workchain && self.hash == other.hash
+    }
+}
+
+impl FromStr for TonAddress {
+    type Err = String;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if address.contains(':') {
+            Self::from_raw(address)
+        } else {
+            Self::from_friendly(address)
+        }
+    }
+}
+
+/// 默认输出可回弹的用户友好格式
+impl fmt::Display for TonAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_friendly(self.bounceable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // USDT Jetton master
+    const USDT_RAW: &str = "0:b113a994b5024a16719f69139328eb759596c38a25f59028b146fecdc3621dfe";
+    const USDT_FRIENDLY: &str = "EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs";
+
+    #[test]
+    fn test_crc16() {
+        assert_eq!(crc16(b"123456789"), 0x31c3);
+    }
+
+    #[test]
+    fn test_parse_raw_and_friendly() {
+        let raw = TonAddress::from_str(USDT_RAW).unwrap();
+        let friendly = TonAddress::from_str(USDT_FRIENDLY).unwrap();
+        assert_eq!(raw, friendly);
+        assert_eq!(friendly.to_raw(), USDT_RAW);
+        assert_eq!(raw.to_string(), USDT_FRIENDLY);
+        assert!(TonAddress::from_str(&USDT_RAW.to_uppercase()).is_ok());
+
+        let non_bounceable = raw.to_friendly(false);
+        assert!(non_bounceable.starts_with("UQ"));
+        let parsed = TonAddress::from_str(&non_bounceable).unwrap();
+        assert!(!parsed.bounceable);
+        assert!(parsed.same_account(&raw));
+
+        let standard = non_bounceable.replace('-', "+").replace('_', "/");
+        assert!(TonAddress::from_str(&standard).unwrap().same_account(&raw));
+    }
+
+    #[test]
+    fn test_invalid_address() {
+        let mut corrupted = USDT_FRIENDLY.to_string();
+        corrupted.replace_range(10..11, "A");
+        assert!(TonAddress::from_str(&corrupted).is_err());
+        assert!(TonAddress::from_str("0:1234").is_err());
+        assert!(TonAddress::from_str("x:00").is_err());
+        assert!(TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id").is_err());
+        assert!(TonAddress::from_str(&format!("{}==", "A".repeat(46))).is_err());
+        assert!(TonAddress::from_str(&format!("{}=", "A".repeat(47))).is_err());
+    }
+}
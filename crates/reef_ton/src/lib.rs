@@ -0,0 +1,13 @@
+pub mod address;
+pub mod models;
+pub mod provider;
+pub mod rpc;
+
+pub const TON_DECIMALS: u8 = 9;
+/// TEP-64 未指定精度时的默认值
+pub const JETTON_DEFAULT_DECIMALS: u8 = 9;
+
+// Re-export for convenience
+pub use address::TonAddress;
+pub use models::*;
+pub use rpc::TonClient;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// `/api/v3/account`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TonAccount {
+    pub balance: String,
+    /// `active`、`uninit`、`frozen` 或 `nonexist`
+    pub status: String,
+}
+
+impl TonAccount {
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// `/api/v3/jetton/wallets`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JettonWalletsResponse {
+    pub jetton_wallets: Vec<JettonWallet>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JettonWallet {
+    pub address: String,
+    pub balance: String,
+    pub owner: String,
+    /// Jetton master 地址
+    pub jetton: String,
+}
+
+/// `/api/v3/jetton/masters`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JettonMastersResponse {
+    pub jetton_masters: Vec<JettonMaster>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JettonMaster {
+    pub address: String,
+    pub total_supply: String,
+    #[serde(default)]
+    pub jetton_content: JettonContent,
+}
+
+/// TEP-64 元数据，链下内容只有 `uri`，半链上内容两者都有
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JettonContent {
+    pub uri: Option<String>,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    /// 字符串或数字
+    pub decimals: Option<Value>,
+    pub image: Option<String>,
+    pub description: Option<String>,
+}
+
+impl JettonContent {
+    pub fn is_complete(&self) -> bool {
+        self.name.is_some() && self.symbol.is_some()
+    }
+
+    pub fn decimals(&self) -> Option<u8> {
+        match self.decimals.as_ref()? {
+            Value::String(x) => x.parse().ok(),
+            Value::Number(x) => x.as_u64().and_then(|x| u8::try_from(x).ok()),
+            _ => None,
+        }
+    }
+
+    /// 链上字段优先，缺失的由链下内容补齐
+    pub fn merge(self, offchain: JettonContent) -> JettonContent {
+        JettonContent {
+            uri: self.uri,
+            name: self.name.or(offchain.name),
+            symbol: self.symbol.or(offchain.symbol),
+            decimals: self.decimals.or(offchain.decimals),
+            image: self.image.or(offchain.image),
+            description: self.description.or(offchain.description),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_content() {
+        let onchain: JettonContent = serde_json::from_value(json!({
+            "uri": "https://example.com/jetton.json",
+            "decimals": "6"
+        }))
+        .unwrap();
+        let offchain: JettonContent = serde_json::from_value(json!({
+            "name": "Example",
+            "symbol": "EXM",
+            "decimals": 9,
+            "image": "https://example.com/logo.png"
+        }))
+        .unwrap();
+        assert!(!onchain.is_complete());
+
+        let content = onchain.merge(offchain);
+        assert!(content.is_complete());
+        assert_eq!(content.symbol.as_deref(), Some("EXM"));
+        assert_eq!(content.decimals(), Some(6));
+    }
+}
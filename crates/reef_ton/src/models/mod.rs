@@ -0,0 +1,7 @@
+pub mod account;
+pub mod jetton;
+pub mod transaction;
+
+pub use account::*;
+pub use jetton::*;
+pub use transaction::*;
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// `POST /api/v3/message`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendMessageRequest {
+    /// base64 编码的外部消息 BOC
+    pub boc: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendMessageResponse {
+    pub message_hash: String,
+}
+
+/// `/api/v3/transactionsByMessage`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionsResponse {
+    pub transactions: Vec<TonTransaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TonTransaction {
+    pub hash: String,
+    pub lt: String,
+    pub mc_block_seqno: Option<u64>,
+    pub description: TransactionDescription,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionDescription {
+    #[serde(default)]
+    pub aborted: bool,
+    pub compute_ph: Option<ComputePhase>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputePhase {
+    pub success: Option<bool>,
+    pub exit_code: Option<i32>,
+}
+
+impl TonTransaction {
+    pub fn is_success(&self) -> bool {
+        !self.description.aborted
+            && self
+                .description
+                .compute_ph
+                .as_ref()
+                .and_then(|x| x.success)
+                .unwrap_or(true)
+    }
+}
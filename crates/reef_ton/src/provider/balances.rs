@@ -0,0 +1,177 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{AssetBalance, Balance, Chain, ChainBalances};
+use reef_client::Client;
+
+use crate::address::TonAddress;
+use crate::models::JettonWallet;
+use crate::rpc::client::TonClient;
+use crate::{JETTON_DEFAULT_DECIMALS, TON_DECIMALS};
+
+impl<C: Client + Clone> TonClient<C> {
+    /// 优先使用链上精度，缺失时读取链下元数据
+    pub async fn get_jetton_decimals(
+        &self,
+        master: &str,
+    ) -> Result<u8, Box<dyn Error + Send + Sync>> {
+        let master = self.get_jetton_master(master).await?;
+        if let Some(decimals) = master.jetton_content.decimals() {
+            return Ok(decimals);
+        }
+        let content = self.get_jetton_content(&master).await?;
+        Ok(content.decimals().unwrap_or(JETTON_DEFAULT_DECIMALS))
+    }
+
+    /// 非零 Jetton 余额，合约地址为 master 的用户友好格式
+    async fn map_jetton_balances(&self, wallets: Vec<JettonWallet>) -> Vec<AssetBalance> {
+        let mut balances = Vec::new();
+        for wallet in wallets {
+            let amount = wallet.balance.parse::<BigUint>().unwrap_or_default();
+            if amount == BigUint::ZERO {
+                continue;
+            }
+            let Ok(master) = TonAddress::from_str(&wallet.jetton) else {
+                continue;
+            };
+            // 跳过获取失败的 Jetton
+            if let Ok(decimals) = self.get_jetton_decimals(&wallet.jetton).await {
+                balances.push(AssetBalance::new_token(
+                    Chain::Ton,
+                    Some(master.to_string()),
+                    amount,
+                    decimals,
+                ));
+            }
+        }
+        balances
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for TonClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let account = self.get_account(&address).await?;
+        let balance = account
+            .balance
+            .parse::<BigUint>()
+            .map_err(|e| format!("Invalid balance {}: {}", account.balance, e))?;
+        Ok(AssetBalance {
+            chain: Chain::Ton,
+            contract_address: None,
+            balance: Balance::coin_balance(balance, TON_DECIMALS),
+            is_active: Some(account.is_active()),
+        })
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let masters = token_addresses
+            .iter()
+            .map(|x| TonAddress::from_str(x))
+            .collect::<Result<Vec<_>, _>>()?;
+        let wallets = self
+            .get_jetton_wallets(&address, None)
+            .await?
+            .into_iter()
+            .filter(|wallet| {
+                TonAddress::from_str(&wallet.jetton)
+                    .map(|jetton| masters.iter().any(|x| x.same_account(&jetton)))
+                    .unwrap_or(false)
+            })
+            .collect();
+        Ok(self.map_jetton_balances(wallets).await)
+    }
+
+    async fn get_assets_balances(
+        &self,
+        address: String,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        let wallets = self.get_jetton_wallets(&address, None).await?;
+        Ok(self.map_jetton_balances(wallets).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const OWNER: &str = "UQBWAs9c3KHBYwqVZT1GBnSPfj9X1dEJwgYgNTVVRlSZ2jqU";
+    const USDT_RAW: &str = "0:B113A994B5024A16719F69139328EB759596C38A25F59028B146FECDC3621DFE";
+    const USDT: &str = "EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs";
+    const NOT_RAW: &str = "0:2F956143C461769579BAEF2E32CC2D7BC18283F40D20BB03E432CD603AC33FFC";
+
+    fn client() -> TonClient<MockClient> {
+        let owner = TonAddress::from_str(OWNER).unwrap().to_raw();
+        let usdt = TonAddress::from_str(USDT).unwrap().to_raw();
+        let mock = MockClient::new()
+            .with_get(
+                &format!("/api/v3/account?address={}", owner),
+                json!({ "balance": "1250000000", "status": "active", "last_transaction_lt": "58134839000001" }),
+            )
+            .with_get(
+                &format!("/api/v3/jetton/wallets?owner_address={}&limit=100&offset=0", owner),
+                json!({
+                    "jetton_wallets": [
+                        { "address": "0:7A1B", "balance": "15000000", "owner": owner, "jetton": USDT_RAW },
+                        { "address": "0:9C2D", "balance": "0", "owner": owner, "jetton": NOT_RAW }
+                    ]
+                }),
+            )
+            .with_get(
+                &format!("/api/v3/jetton/masters?address={}&limit=1", usdt),
+                json!({
+                    "jetton_masters": [{
+                        "address": USDT_RAW,
+                        "total_supply": "1229976002510000",
+                        "jetton_content": {
+                            "uri": "https://tether.to/usdt-ton.json",
+                            "name": "Tether USD",
+                            "symbol": "USD₮",
+                            "decimals": "6"
+                        }
+                    }]
+                }),
+            );
+        TonClient::new_with_client(mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let balance = client().get_balance_coin(OWNER.to_string()).await.unwrap();
+        assert_eq!(balance.chain, Chain::Ton);
+        assert_eq!(balance.balance.amount, "1250000000");
+        assert_eq!(balance.balance.decimals, 9);
+        assert_eq!(balance.is_active, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_jetton_balances() {
+        let client = client();
+        let balances = client.get_assets_balances(OWNER.to_string()).await.unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].contract_address.as_deref(), Some(USDT));
+        assert_eq!(balances[0].balance.amount, "15000000");
+        assert_eq!(balances[0].balance.decimals, 6);
+
+        let balances = client
+            .get_balance_tokens(OWNER.to_string(), vec![USDT_RAW.to_lowercase()])
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert!(client
+            .get_balance_tokens(OWNER.to_string(), vec!["invalid".to_string()])
+            .await
+            .is_err());
+    }
+}
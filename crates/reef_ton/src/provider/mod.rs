@@ -0,0 +1,3 @@
+pub mod accounts;
+pub mod balances;
+pub mod token;
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::{address::TonAddress, rpc::client::TonClient, JETTON_DEFAULT_DECIMALS};
+use primitives::{Asset, AssetType, Chain, ChainToken};
+use reef_client::Client;
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for TonClient<C> {
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let master = self.get_jetton_master(&token_address).await?;
+        let content = self.get_jetton_content(&master).await?;
+        let address = TonAddress::from_str(&master.address)?;
+        Ok(Asset::new(
+            content.name.clone().ok_or("Missing jetton name")?,
+            content.symbol.clone().ok_or("Missing jetton symbol")?,
+            content.decimals().unwrap_or(JETTON_DEFAULT_DECIMALS) as i32,
+            Chain::Ton,
+            Some(address.to_string()),
+            AssetType::JETTON,
+        ))
+    }
+
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        TonAddress::from_str(token_address).is_ok()
+    }
+
+    async fn get_tokens_data(
+        &self,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<Asset>, Box<dyn Error + Sync + Send>> {
+        let mut assets = Vec::new();
+        for token_address in token_addresses {
+            // 跳过获取失败的代币
+            if let Ok(asset) = self.get_token_data(token_address).await {
+                assets.push(asset);
+            }
+        }
+        Ok(assets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const NOT: &str = "EQAvlWFDxGF2lXm67y4yzC17wYKD9A0guwPkMs1gOsM__NOT";
+
+    #[tokio::test]
+    async fn test_get_token_data() {
+        let raw = TonAddress::from_str(NOT).unwrap().to_raw();
+        let mock = MockClient::new().with_get(
+            &format!("/api/v3/jetton/masters?address={}&limit=1", raw),
+            json!({
+                "jetton_masters": [{
+                    "address": raw.to_uppercase(),
+                    "total_supply": "102455896904753170000",
+                    "jetton_content": {
+                        "name": "Notcoin",
+                        "symbol": "NOT",
+                        "decimals": "9",
+                        "image": "https://cdn.joincommunity.xyz/clicker/not_logo.png"
+                    }
+                }]
+            }),
+        );
+        let client = TonClient::new_with_client(mock);
+
+        let asset = client.get_token_data(NOT.to_string()).await.unwrap();
+        assert_eq!(asset.name, "Notcoin");
+        assert_eq!(asset.symbol, "NOT");
+        assert_eq!(asset.decimals, 9);
+        assert_eq!(asset.contract_address.as_deref(), Some(NOT));
+        assert_eq!(asset.asset_type, AssetType::JETTON);
+        assert!(client.get_is_token_address(NOT));
+        assert!(!client.get_is_token_address("NOT"));
+    }
+}
@@ -0,0 +1,303 @@
+use crate::address::TonAddress;
+use crate::models::{
+    JettonContent, JettonMaster, JettonMastersResponse, JettonWallet, JettonWalletsResponse,
+    SendMessageRequest, SendMessageResponse, TonAccount, TonTransaction, TransactionsResponse,
+};
+use primitives::{Chain, TransactionStatus, TransactionUpdate};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient, UntrustedClient};
+use std::error::Error;
+use std::str::FromStr;
+
+const IPFS_PREFIX: &str = "ipfs://";
+const IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+const JETTON_WALLETS_LIMIT: usize = 100;
+
+/// 链下元数据地址，ipfs 通过公共网关访问
+pub fn content_url(uri: &str) -> String {
+    match uri.strip_prefix(IPFS_PREFIX) {
+        Some(path) => format!("{}{}", IPFS_GATEWAY, path),
+        None => uri.to_string(),
+    }
+}
+
+/// base64 消息 hash 作为查询参数时需要转义
+fn encode_query_value(value: &str) -> String {
+    value
+        .replace('+', "%2B")
+        .replace('/', "%2F")
+        .replace('=', "%3D")
+}
+
+fn parse_address(address: &str) -> Result<TonAddress, Box<dyn Error + Send + Sync>> {
+    Ok(TonAddress::from_str(address)?)
+}
+
+/// toncenter v3 客户端
+#[derive(Debug, Clone)]
+pub struct TonClient<C: Client + Clone = ReqwestClient> {
+    client: C,
+}
+
+impl TonClient {
+    pub fn new(url: String) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url))
+    }
+
+    pub fn new_with_config(url: String, config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(ReqwestClient::new_with_config(
+            url, config,
+        )?))
+    }
+}
+
+impl<C: Client + Clone> TonClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C) -> Self {
+        Self { client }
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        Chain::Ton
+    }
+
+    pub fn validate_address(&self, address: &str) -> Result<TonAddress, String> {
+        TonAddress::from_str(address)
+    }
+
+    /// 未部署的账户余额为 0，状态为 `nonexist`
+    pub async fn get_account(
+        &self,
+        address: &str,
+    ) -> Result<TonAccount, Box<dyn Error + Send + Sync>> {
+        let address = parse_address(address)?;
+        let path = format!("/api/v3/account?address={}", address.to_raw());
+        match self.client.get(&path).await {
+            Ok(account) => Ok(account),
+            Err(ClientError::HttpError { status: 404, .. }) => Ok(TonAccount {
+                balance: "0".to_string(),
+                status: "nonexist".to_string(),
+            }),
+            Err(e) => Err(format!("Failed to get account: {}", e).into()),
+        }
+    }
+
+    /// 账户持有的 Jetton 钱包，`jetton` 为 master 地址时只查询该 Jetton
+    pub async fn get_jetton_wallets(
+        &self,
+        owner: &str,
+        jetton: Option<&str>,
+    ) -> Result<Vec<JettonWallet>, Box<dyn Error + Send + Sync>> {
+        let owner = parse_address(owner)?;
+        let mut path = format!(
+            "/api/v3/jetton/wallets?owner_address={}&limit={}&offset=0",
+            owner.to_raw(),
+            JETTON_WALLETS_LIMIT
+        );
+        if let Some(jetton) = jetton {
+            path.push_str(&format!(
+                "&jetton_address={}",
+                parse_address(jetton)?.to_raw()
+            ));
+        }
+        let response: JettonWalletsResponse = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get jetton wallets: {}", e))?;
+        Ok(response.jetton_wallets)
+    }
+
+    pub async fn get_jetton_master(
+        &self,
+        address: &str,
+    ) -> Result<JettonMaster, Box<dyn Error + Send + Sync>> {
+        let address = parse_address(address)?;
+        let path = format!(
+            "/api/v3/jetton/masters?address={}&limit=1",
+            address.to_raw()
+        );
+        let response: JettonMastersResponse = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get jetton master: {}", e))?;
+        response
+            .jetton_masters
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("Jetton master not found: {}", address).into())
+    }
+
+    /// 链上元数据，不完整时合并 `uri` 指向的链下内容
+    pub async fn get_jetton_content(
+        &self,
+        master: &JettonMaster,
+    ) -> Result<JettonContent, Box<dyn Error + Send + Sync>> {
+        let content = master.jetton_content.clone();
+        if content.is_complete() {
+            return Ok(content);
+        }
+        let Some(uri) = content.uri.clone() else {
+            return Ok(content);
+        };
+        // uri 由代币发行方填写：独立的客户端，不带 toncenter 的 API key，且只访问公网 https
+        let offchain: JettonContent = UntrustedClient::new()
+            .get(&content_url(&uri))
+            .await
+            .map_err(|e| format!("Failed to get jetton content {}: {}", uri, e))?;
+        Ok(content.merge(offchain))
+    }
+
+    /// 广播外部消息 BOC，返回消息 hash
+    pub async fn broadcast_transaction(
+        &self,
+        boc: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let request = SendMessageRequest {
+            boc: boc.to_string(),
+        };
+        let response: SendMessageResponse = self
+            .client
+            .post("/api/v3/message", &request, None)
+            .await
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        Ok(response.message_hash)
+    }
+
+    /// 由外部消息触发的交易，未处理时为 None
+    pub async fn get_transaction_by_message(
+        &self,
+        message_hash: &str,
+    ) -> Result<Option<TonTransaction>, Box<dyn Error + Send + Sync>> {
+        let path = format!(
+            "/api/v3/transactionsByMessage?msg_hash={}&direction=in",
+            encode_query_value(message_hash)
+        );
+        let response: TransactionsResponse = self
+            .client
+            .get(&path)
+            .await
+            .map_err(|e| format!("Failed to get transaction: {}", e))?;
+        Ok(response.transactions.into_iter().next())
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        message_hash: &str,
+    ) -> Result<TransactionUpdate, Box<dyn Error + Send + Sync>> {
+        let Some(transaction) = self.get_transaction_by_message(message_hash).await? else {
+            return Ok(TransactionUpdate {
+                hash: message_hash.to_string(),
+                status: TransactionStatus::Pending,
+                block_number: None,
+                confirmations: 0,
+            });
+        };
+        Ok(TransactionUpdate {
+            hash: transaction.hash.clone(),
+            status: if transaction.is_success() {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Failed
+            },
+            block_number: transaction.mc_block_seqno,
+            confirmations: 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{JettonContent, JettonMaster};
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const MESSAGE_HASH: &str = "b9cD7+Zs2eLq/0a4nFz1kWQ8tXh3R5pVuYmJ6oKcE2A=";
+
+    #[test]
+    fn test_content_url() {
+        assert_eq!(
+            content_url("ipfs://bafkreiast4fqlkp4upyu2cvo7fn7aabjusx765yzvqitsr4rpwfvhjguhy"),
+            "https://ipfs.io/ipfs/bafkreiast4fqlkp4upyu2cvo7fn7aabjusx765yzvqitsr4rpwfvhjguhy"
+        );
+        assert_eq!(
+            content_url("https://tether.to/usdt-ton.json"),
+            "https://tether.to/usdt-ton.json"
+        );
+        assert_eq!(
+            encode_query_value(MESSAGE_HASH),
+            "b9cD7%2BZs2eLq%2F0a4nFz1kWQ8tXh3R5pVuYmJ6oKcE2A%3D"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_jetton_content_rejects_internal_uri() {
+        let client = TonClient::new_with_client(MockClient::new());
+        for uri in [
+            "http://metadata.example.com/jetton.json",
+            "https://169.254.169.254/latest/meta-data/",
+            "https://127.0.0.1:8080/jetton.json",
+        ] {
+            let master = JettonMaster {
+                address: "0:b113a994b5024a16719f69139328eb759596c38a25f59028b146fecdc3621dfe"
+                    .to_string(),
+                total_supply: "0".to_string(),
+                jetton_content: JettonContent {
+                    uri: Some(uri.to_string()),
+                    ..Default::default()
+                },
+            };
+            let error = client.get_jetton_content(&master).await.unwrap_err();
+            assert!(error.to_string().contains("Refusing to fetch"), "{}", error);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_and_status() {
+        let status_path = format!(
+            "/api/v3/transactionsByMessage?msg_hash={}&direction=in",
+            encode_query_value(MESSAGE_HASH)
+        );
+        let mock = MockClient::new()
+            .with_post(
+                "/api/v3/message",
+                json!({ "boc": "te6cckEBAgEAqgAB" }),
+                json!({ "message_hash": MESSAGE_HASH, "message_hash_norm": MESSAGE_HASH }),
+            )
+            .with_get(
+                &status_path,
+                json!({
+                    "transactions": [{
+                        "hash": "Fq3xRj0mZ8s1vV2yB6tN9pL4wK7cH5eD0aG3uI8oQ1E=",
+                        "lt": "58134839000001",
+                        "mc_block_seqno": 47123456,
+                        "description": {
+                            "aborted": false,
+                            "compute_ph": { "success": true, "exit_code": 0 }
+                        }
+                    }]
+                }),
+            )
+            .with_get(
+                "/api/v3/transactionsByMessage?msg_hash=pending&direction=in",
+                json!({ "transactions": [] }),
+            );
+        let client = TonClient::new_with_client(mock);
+
+        assert_eq!(
+            client
+                .broadcast_transaction("te6cckEBAgEAqgAB")
+                .await
+                .unwrap(),
+            MESSAGE_HASH
+        );
+
+        let update = client.get_transaction_status(MESSAGE_HASH).await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Confirmed));
+        assert_eq!(update.block_number, Some(47123456));
+
+        let update = client.get_transaction_status("pending").await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Pending));
+    }
+}
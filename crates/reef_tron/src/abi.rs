@@ -0,0 +1,76 @@
+use num_bigint::BigUint;
+
+const WORD_HEX_LEN: usize = 64;
+
+fn words(hex_result: &str) -> &str {
+    hex_result.strip_prefix("0x").unwrap_or(hex_result)
+}
+
+fn word_at(hex_result: &str, index: usize) -> Option<&str> {
+    let start = index.checked_mul(WORD_HEX_LEN)?;
+    words(hex_result).get(start..start.checked_add(WORD_HEX_LEN)?)
+}
+
+fn word_to_usize(word: &str) -> Option<usize> {
+    usize::try_from(BigUint::parse_bytes(word.as_bytes(), 16)?).ok()
+}
+
+pub fn decode_uint256(hex_result: &str) -> Option<BigUint> {
+    BigUint::parse_bytes(word_at(hex_result, 0)?.as_bytes(), 16)
+}
+
+/// ABI `string` 返回值，部分老合约返回 `bytes32`。
+/// 偏移量与长度来自合约返回值，溢出时返回 `None`
+pub fn decode_string(hex_result: &str) -> Option<String> {
+    let data = words(hex_result);
+    let bytes = if data.len() == WORD_HEX_LEN {
+        let bytes = hex::decode(data).ok()?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        bytes[..end].to_vec()
+    } else {
+        let offset = word_to_usize(word_at(data, 0)?)?.checked_mul(2)?;
+        let start = offset.checked_add(WORD_HEX_LEN)?;
+        let length = word_to_usize(data.get(offset..start)?)?;
+        let end = start.checked_add(length.checked_mul(2)?)?;
+        hex::decode(data.get(start..end)?).ok()?
+    };
+    String::from_utf8(bytes).ok().filter(|x| !x.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let string = concat!(
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "000000000000000000000000000000000000000000000000000000000000000a",
+            "5465746865722055534400000000000000000000000000000000000000000000"
+        );
+        assert_eq!(decode_string(string), Some("Tether USD".to_string()));
+        assert_eq!(
+            decode_string("5553445400000000000000000000000000000000000000000000000000000000"),
+            Some("USDT".to_string())
+        );
+        assert_eq!(
+            decode_uint256("0000000000000000000000000000000000000000000000000000000000000006"),
+            Some(BigUint::from(6u32))
+        );
+        assert_eq!(decode_string(""), None);
+    }
+
+    #[test]
+    fn test_decode_string_overflow() {
+        let offset = concat!(
+            "0000000000000000000000000000000000000000000000007fffffffffffffff",
+            "000000000000000000000000000000000000000000000000000000000000000a"
+        );
+        assert_eq!(decode_string(offset), None);
+        let length = concat!(
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "000000000000000000000000000000000000000000000000ffffffffffffffff"
+        );
+        assert_eq!(decode_string(length), None);
+    }
+}
@@ -0,0 +1,115 @@
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+
+/// 主网地址前缀，hex 地址以 `41` 开头
+pub const ADDRESS_PREFIX: u8 = 0x41;
+pub const ADDRESS_LEN: usize = 21;
+const CHECKSUM_LEN: usize = 4;
+
+fn checksum(payload: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = Sha256::digest(Sha256::digest(payload));
+    let mut out = [0u8; CHECKSUM_LEN];
+    out.copy_from_slice(&hash[..CHECKSUM_LEN]);
+    out
+}
+
+/// Tron 地址：`0x41` 前缀加 20 字节账户
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TronAddress([u8; ADDRESS_LEN]);
+
+impl TronAddress {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let bytes: [u8; ADDRESS_LEN] = bytes
+            .try_into()
+            .map_err(|_| format!("Invalid address length: {}", bytes.len()))?;
+        if bytes[0] != ADDRESS_PREFIX {
+            return Err(format!("Invalid address prefix: {:#04x}", bytes[0]));
+        }
+        Ok(Self(bytes))
+    }
+
+    /// `41` 开头的 hex，也接受 EVM 风格的 `0x` 20 字节地址
+    pub fn from_hex(value: &str) -> Result<Self, String> {
+        let value = value.strip_prefix("0x").unwrap_or(value);
+        let bytes = hex::decode(value).map_err(|e| format!("Invalid hex address: {}", e))?;
+        if bytes.len() == ADDRESS_LEN - 1 {
+            return Self::from_bytes(&[&[ADDRESS_PREFIX], bytes.as_slice()].concat());
+        }
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn to_base58(&self) -> String {
+        let mut data = self.0.to_vec();
+        data.extend_from_slice(&checksum(&self.0));
+        bs58::encode(data).into_string()
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    /// ABI 参数中的地址：去掉前缀的 20 字节，左侧补零到 32 字节
+    pub fn to_abi_word(&self) -> String {
+        format!("{:0>64}", hex::encode(&self.0[1..]))
+    }
+}
+
+impl FromStr for TronAddress {
+    type Err = String;
+
+    /// base58check 地址，例如 `TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t`
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let data = bs58::decode(value)
+            .into_vec()
+            .map_err(|e| format!("Invalid base58 address: {}", e))?;
+        if data.len() != ADDRESS_LEN + CHECKSUM_LEN {
+            return Err(format!("Invalid address length: {}", data.len()));
+        }
+        let (payload, sum) = data.split_at(ADDRESS_LEN);
+        if checksum(payload) != sum {
+            return Err("Invalid address checksum".to_string());
+        }
+        Self::from_bytes(payload)
+    }
+}
+
+impl fmt::Display for TronAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_base58())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDT: &str = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+    const USDT_HEX: &str = "41a614f803b6fd780986a42c78ec9c7f77e6ded13c";
+
+    #[test]
+    fn test_base58_hex_round_trip() {
+        let address = TronAddress::from_str(USDT).unwrap();
+        assert_eq!(address.to_hex(), USDT_HEX);
+        assert_eq!(TronAddress::from_hex(USDT_HEX).unwrap().to_base58(), USDT);
+        assert_eq!(
+            TronAddress::from_hex("0xa614f803b6fd780986a42c78ec9c7f77e6ded13c").unwrap(),
+            address
+        );
+        assert_eq!(
+            address.to_abi_word(),
+            "000000000000000000000000a614f803b6fd780986a42c78ec9c7f77e6ded13c"
+        );
+    }
+
+    #[test]
+    fn test_invalid_address() {
+        // 校验和错误
+        assert!(TronAddress::from_str("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6u").is_err());
+        // 以太坊地址
+        assert!(TronAddress::from_str("0xa614f803b6fd780986a42c78ec9c7f77e6ded13c").is_err());
+        assert!(TronAddress::from_str("").is_err());
+        // 前缀不是 0x41
+        assert!(TronAddress::from_hex("a0a614f803b6fd780986a42c78ec9c7f77e6ded13c").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+pub mod abi;
+pub mod address;
+pub mod models;
+pub mod provider;
+pub mod rpc;
+
+// Tron 常量
+pub const TRX_DECIMALS: u8 = 6;
+pub const SUN_PER_TRX: u64 = 1_000_000;
+
+// 常用 TRC-20 合约
+pub const USDT_CONTRACT: &str = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+
+// Re-export for convenience
+pub use address::TronAddress;
+pub use models::*;
+pub use rpc::TronClient;
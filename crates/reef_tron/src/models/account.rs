@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// `/wallet/getaccount`，未激活的账户返回空对象
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TronAccount {
+    pub address: Option<String>,
+    /// 单位 sun
+    #[serde(default)]
+    pub balance: u64,
+    pub create_time: Option<u64>,
+}
+
+impl TronAccount {
+    pub fn is_active(&self) -> bool {
+        self.address.is_some()
+    }
+}
+
+/// `/wallet/getaccountresource`，字段为 0 时接口会省略
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountResource {
+    #[serde(rename = "freeNetUsed")]
+    pub free_net_used: u64,
+    #[serde(rename = "freeNetLimit")]
+    pub free_net_limit: u64,
+    #[serde(rename = "NetUsed")]
+    pub net_used: u64,
+    #[serde(rename = "NetLimit")]
+    pub net_limit: u64,
+    #[serde(rename = "EnergyUsed")]
+    pub energy_used: u64,
+    #[serde(rename = "EnergyLimit")]
+    pub energy_limit: u64,
+}
+
+/// 账户剩余的带宽与能量
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TronResources {
+    /// 免费带宽加质押获得的带宽
+    pub bandwidth: u64,
+    pub energy: u64,
+}
+
+impl From<&AccountResource> for TronResources {
+    fn from(resource: &AccountResource) -> Self {
+        Self {
+            bandwidth: resource
+                .free_net_limit
+                .saturating_sub(resource.free_net_used)
+                + resource.net_limit.saturating_sub(resource.net_used),
+            energy: resource.energy_limit.saturating_sub(resource.energy_used),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// `/wallet/triggerconstantcontract` 请求，地址使用 base58
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerConstantContract {
+    pub owner_address: String,
+    pub contract_address: String,
+    pub function_selector: String,
+    pub parameter: String,
+    pub visible: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReturnResult {
+    #[serde(default)]
+    pub result: bool,
+    pub code: Option<String>,
+    /// hex 编码的错误信息
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TriggerConstantResult {
+    #[serde(default)]
+    pub result: ReturnResult,
+    #[serde(default)]
+    pub constant_result: Vec<String>,
+    pub energy_used: Option<u64>,
+}
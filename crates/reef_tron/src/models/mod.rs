@@ -0,0 +1,7 @@
+pub mod account;
+pub mod contract;
+pub mod transaction;
+
+pub use account::*;
+pub use contract::*;
+pub use transaction::*;
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// `/wallet/broadcasthex` 的返回
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BroadcastResult {
+    #[serde(default)]
+    pub result: bool,
+    pub txid: Option<String>,
+    pub code: Option<String>,
+    /// hex 编码的错误信息
+    pub message: Option<String>,
+}
+
+/// 节点返回的 hex 错误信息解码为文本
+pub fn decode_message(message: &str) -> String {
+    hex::decode(message)
+        .ok()
+        .and_then(|x| String::from_utf8(x).ok())
+        .unwrap_or_else(|| message.to_string())
+}
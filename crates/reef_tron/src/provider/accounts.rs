@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use primitives::{
+    Chain, ChainNft, ChainProvider, ChainState, ChainTraits, ChainTransactions, ChainUtxo,
+};
+use reef_client::Client;
+use std::error::Error;
+
+use crate::rpc::client::TronClient;
+
+impl<C: Client + Clone> ChainTraits for TronClient<C> {}
+
+impl<C: Client + Clone> ChainProvider for TronClient<C> {
+    fn get_chain(&self) -> Chain {
+        self.get_chain()
+    }
+
+    fn verify_address(&self, address: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.verify_address(address)
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainState for TronClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainTransactions for TronClient<C> {}
+
+// TRC-721 暂不支持
+#[async_trait]
+impl<C: Client + Clone> ChainNft for TronClient<C> {}
+
+#[async_trait]
+impl<C: Client + Clone> ChainUtxo for TronClient<C> {}
@@ -0,0 +1,144 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{AssetBalance, Balance, Chain, ChainBalances};
+use reef_client::Client;
+
+use crate::abi;
+use crate::models::TronAccount;
+use crate::rpc::client::TronClient;
+use crate::TRX_DECIMALS;
+
+fn map_trx_balance(account: &TronAccount) -> AssetBalance {
+    AssetBalance {
+        chain: Chain::Tron,
+        contract_address: None,
+        balance: Balance::coin_balance(BigUint::from(account.balance), TRX_DECIMALS),
+        is_active: Some(account.is_active()),
+    }
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for TronClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let account = self.get_account(&address).await?;
+        Ok(map_trx_balance(&account))
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let mut balances = Vec::new();
+
+        for token_address in token_addresses {
+            let Ok(balance) = self.get_token_balance(&token_address, &address).await else {
+                // 合约不存在或调用失败时跳过
+                continue;
+            };
+            if balance == BigUint::ZERO {
+                continue;
+            }
+            let decimals = self.call_token(&token_address, "decimals()").await?;
+            let decimals = abi::decode_uint256(&decimals)
+                .and_then(|x| u8::try_from(x).ok())
+                .ok_or("Invalid token decimals")?;
+            balances.push(AssetBalance::new_token(
+                Chain::Tron,
+                Some(token_address),
+                balance,
+                decimals,
+            ));
+        }
+
+        Ok(balances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::TronAddress;
+    use crate::USDT_CONTRACT;
+    use reef_client::MockClient;
+    use serde_json::json;
+    use std::str::FromStr;
+
+    const ADDRESS: &str = "TLa2f6VPqDgRE67v1736s7bJ8Ray5wYjU7";
+
+    fn trigger(owner: &str, selector: &str, parameter: &str) -> serde_json::Value {
+        json!({
+            "owner_address": owner,
+            "contract_address": USDT_CONTRACT,
+            "function_selector": selector,
+            "parameter": parameter,
+            "visible": true
+        })
+    }
+
+    fn constant_result(value: u64) -> serde_json::Value {
+        json!({
+            "result": { "result": true },
+            "energy_used": 935,
+            "constant_result": [format!("{:064x}", value)]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let mock = MockClient::new()
+            .with_post(
+                "/wallet/getaccount",
+                json!({ "address": ADDRESS, "visible": true }),
+                json!({ "address": ADDRESS, "balance": 12_500_000, "create_time": 1_600_000_000_000u64 }),
+            )
+            .with_post("/wallet/getaccount", serde_json::Value::Null, json!({}));
+        let client = TronClient::new_with_client(mock);
+
+        let balance = client.get_balance_coin(ADDRESS.to_string()).await.unwrap();
+        assert_eq!(balance.chain, Chain::Tron);
+        assert_eq!(balance.balance.amount, "12500000");
+        assert_eq!(balance.balance.decimals, 6);
+        assert_eq!(balance.balance.ui_amount, Some(12.5));
+        assert_eq!(balance.is_active, Some(true));
+
+        // 未激活账户
+        let balance = client
+            .get_balance_coin("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t".to_string())
+            .await
+            .unwrap();
+        assert_eq!(balance.balance.amount, "0");
+        assert_eq!(balance.is_active, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_tokens() {
+        let owner = TronAddress::from_str(ADDRESS).unwrap();
+        let mock = MockClient::new()
+            .with_post(
+                "/wallet/triggerconstantcontract",
+                trigger(ADDRESS, "balanceOf(address)", &owner.to_abi_word()),
+                constant_result(1_234_567),
+            )
+            .with_post(
+                "/wallet/triggerconstantcontract",
+                trigger(USDT_CONTRACT, "decimals()", ""),
+                constant_result(6),
+            );
+        let client = TronClient::new_with_client(mock);
+
+        let balances = client
+            .get_balance_tokens(ADDRESS.to_string(), vec![USDT_CONTRACT.to_string()])
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].contract_address.as_deref(), Some(USDT_CONTRACT));
+        assert_eq!(balances[0].balance.amount, "1234567");
+        assert_eq!(balances[0].balance.decimals, 6);
+    }
+}
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::{
+    address::TronAddress, provider::token_mapper::map_token_data, rpc::client::TronClient,
+};
+use primitives::{Asset, ChainToken};
+use reef_client::Client;
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for TronClient<C> {
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let name = self.call_token(&token_address, "name()").await?;
+        let symbol = self.call_token(&token_address, "symbol()").await?;
+        let decimals = self.call_token(&token_address, "decimals()").await?;
+        map_token_data(token_address, &name, &symbol, &decimals)
+    }
+
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        TronAddress::from_str(token_address).is_ok()
+    }
+
+    async fn get_tokens_data(
+        &self,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<Asset>, Box<dyn Error + Sync + Send>> {
+        let mut assets = Vec::new();
+        for token_address in token_addresses {
+            // 跳过获取失败的代币
+            if let Ok(asset) = self.get_token_data(token_address).await {
+                assets.push(asset);
+            }
+        }
+        Ok(assets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::USDT_CONTRACT;
+    use primitives::{AssetType, Chain};
+    use reef_client::MockClient;
+    use serde_json::{json, Value};
+
+    fn trigger(selector: &str) -> Value {
+        json!({
+            "owner_address": USDT_CONTRACT,
+            "contract_address": USDT_CONTRACT,
+            "function_selector": selector,
+            "parameter": "",
+            "visible": true
+        })
+    }
+
+    fn constant_result(value: &str) -> Value {
+        json!({ "result": { "result": true }, "constant_result": [value] })
+    }
+
+    #[tokio::test]
+    async fn test_get_token_data() {
+        let mock = MockClient::new()
+            .with_post(
+                "/wallet/triggerconstantcontract",
+                trigger("name()"),
+                constant_result(concat!(
+                    "0000000000000000000000000000000000000000000000000000000000000020",
+                    "000000000000000000000000000000000000000000000000000000000000000a",
+                    "5465746865722055534400000000000000000000000000000000000000000000"
+                )),
+            )
+            .with_post(
+                "/wallet/triggerconstantcontract",
+                trigger("symbol()"),
+                constant_result(concat!(
+                    "0000000000000000000000000000000000000000000000000000000000000020",
+                    "0000000000000000000000000000000000000000000000000000000000000004",
+                    "5553445400000000000000000000000000000000000000000000000000000000"
+                )),
+            )
+            .with_post(
+                "/wallet/triggerconstantcontract",
+                trigger("decimals()"),
+                constant_result("0000000000000000000000000000000000000000000000000000000000000006"),
+            );
+        let client = TronClient::new_with_client(mock);
+
+        let asset = client
+            .get_token_data(USDT_CONTRACT.to_string())
+            .await
+            .unwrap();
+        assert_eq!(asset.name, "Tether USD");
+        assert_eq!(asset.symbol, "USDT");
+        assert_eq!(asset.decimals, 6);
+        assert_eq!(asset.chain, Chain::Tron);
+        assert_eq!(asset.asset_type, AssetType::TRC20);
+        assert!(client.get_is_token_address(USDT_CONTRACT));
+        assert!(!client.get_is_token_address("0xdAC17F958D2ee523a2206206994597C13D831ec7"));
+    }
+
+    #[tokio::test]
+    async fn test_get_token_data_revert() {
+        let mock = MockClient::new().with_post(
+            "/wallet/triggerconstantcontract",
+            Value::Null,
+            // "REVERT opcode executed"
+            json!({
+                "result": {
+                    "code": "CONTRACT_EXE_ERROR",
+                    "message": "524556455254206f70636f6465206578656375746564"
+                }
+            }),
+        );
+        let client = TronClient::new_with_client(mock);
+
+        let error = client
+            .get_token_data(USDT_CONTRACT.to_string())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Contract call name() failed: REVERT opcode executed"
+        );
+    }
+}
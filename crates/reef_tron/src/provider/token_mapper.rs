@@ -0,0 +1,25 @@
+use primitives::{Asset, AssetType, Chain};
+
+use crate::abi;
+
+pub fn map_token_data(
+    token_address: String,
+    name_hex: &str,
+    symbol_hex: &str,
+    decimals_hex: &str,
+) -> Result<Asset, Box<dyn std::error::Error + Send + Sync>> {
+    let name = abi::decode_string(name_hex).ok_or("Invalid token name")?;
+    let symbol = abi::decode_string(symbol_hex).ok_or("Invalid token symbol")?;
+    let decimals = abi::decode_uint256(decimals_hex)
+        .and_then(|x| i32::try_from(x).ok())
+        .ok_or("Invalid token decimals")?;
+
+    Ok(Asset::new(
+        name,
+        symbol,
+        decimals,
+        Chain::Tron,
+        Some(token_address),
+        AssetType::TRC20,
+    ))
+}
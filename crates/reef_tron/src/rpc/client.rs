@@ -0,0 +1,243 @@
+use crate::abi;
+use crate::address::TronAddress;
+use crate::models::{
+    decode_message, AccountResource, BroadcastResult, TriggerConstantContract,
+    TriggerConstantResult, TronAccount, TronResources,
+};
+use num_bigint::BigUint;
+use primitives::Chain;
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::json;
+use std::error::Error;
+use std::str::FromStr;
+
+/// Trongrid HTTP API 客户端
+#[derive(Debug, Clone)]
+pub struct TronClient<C: Client + Clone = ReqwestClient> {
+    client: C,
+}
+
+impl TronClient {
+    pub fn new(url: String) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url))
+    }
+
+    /// API key 通过 `config.auth` 以 `TRON-PRO-API-KEY` 请求头发送
+    pub fn new_with_config(url: String, config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(ReqwestClient::new_with_config(
+            url, config,
+        )?))
+    }
+}
+
+impl<C: Client + Clone> TronClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C) -> Self {
+        Self { client }
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        Chain::Tron
+    }
+
+    pub fn verify_address(&self, address: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        TronAddress::from_str(&address).map_err(|e| format!("Invalid Tron address: {}", e))?;
+        Ok(())
+    }
+
+    async fn post<T, R>(&self, path: &str, body: &T) -> Result<R, Box<dyn Error + Send + Sync>>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        Ok(self.client.post(path, body, None).await?)
+    }
+
+    pub async fn get_account(
+        &self,
+        address: &str,
+    ) -> Result<TronAccount, Box<dyn Error + Send + Sync>> {
+        let body = json!({ "address": address, "visible": true });
+        self.post("/wallet/getaccount", &body)
+            .await
+            .map_err(|e| format!("Failed to get account: {}", e).into())
+    }
+
+    pub async fn get_account_resource(
+        &self,
+        address: &str,
+    ) -> Result<AccountResource, Box<dyn Error + Send + Sync>> {
+        let body = json!({ "address": address, "visible": true });
+        self.post("/wallet/getaccountresource", &body)
+            .await
+            .map_err(|e| format!("Failed to get account resource: {}", e).into())
+    }
+
+    /// 剩余带宽与能量
+    pub async fn get_resources(
+        &self,
+        address: &str,
+    ) -> Result<TronResources, Box<dyn Error + Send + Sync>> {
+        Ok(TronResources::from(
+            &self.get_account_resource(address).await?,
+        ))
+    }
+
+    /// 只读合约调用，返回 `constant_result` 的第一项
+    pub async fn trigger_constant_contract(
+        &self,
+        owner_address: &str,
+        contract_address: &str,
+        function_selector: &str,
+        parameter: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let request = TriggerConstantContract {
+            owner_address: owner_address.to_string(),
+            contract_address: contract_address.to_string(),
+            function_selector: function_selector.to_string(),
+            parameter: parameter.to_string(),
+            visible: true,
+        };
+        let response: TriggerConstantResult = self
+            .post("/wallet/triggerconstantcontract", &request)
+            .await
+            .map_err(|e| format!("Failed to trigger constant contract: {}", e))?;
+        if !response.result.result {
+            let message = response.result.message.as_deref().map(decode_message);
+            return Err(format!(
+                "Contract call {} failed: {}",
+                function_selector,
+                message.or(response.result.code).unwrap_or_default()
+            )
+            .into());
+        }
+        response
+            .constant_result
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("Empty result for {}", function_selector).into())
+    }
+
+    /// TRC-20 余额（最小单位）
+    pub async fn get_token_balance(
+        &self,
+        contract_address: &str,
+        owner_address: &str,
+    ) -> Result<BigUint, Box<dyn Error + Send + Sync>> {
+        let owner = TronAddress::from_str(owner_address)?;
+        let result = self
+            .trigger_constant_contract(
+                owner_address,
+                contract_address,
+                "balanceOf(address)",
+                &owner.to_abi_word(),
+            )
+            .await?;
+        abi::decode_uint256(&result).ok_or_else(|| "Invalid balanceOf result".into())
+    }
+
+    /// TRC-20 只读方法，调用方使用合约地址本身
+    pub async fn call_token(
+        &self,
+        contract_address: &str,
+        function_selector: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        self.trigger_constant_contract(contract_address, contract_address, function_selector, "")
+            .await
+    }
+
+    /// 广播已签名交易的 protobuf hex，返回交易 ID
+    pub async fn broadcast_hex(
+        &self,
+        transaction: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let body = json!({ "transaction": transaction });
+        let response: BroadcastResult = self
+            .post("/wallet/broadcasthex", &body)
+            .await
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        if !response.result {
+            let message = response.message.as_deref().map(decode_message);
+            return Err(format!(
+                "Broadcast failed: {}",
+                message.or(response.code).unwrap_or_default()
+            )
+            .into());
+        }
+        response
+            .txid
+            .ok_or_else(|| "Broadcast returned no txid".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+
+    const ADDRESS: &str = "TLa2f6VPqDgRE67v1736s7bJ8Ray5wYjU7";
+
+    #[test]
+    fn test_verify_address() {
+        let client = TronClient::new("https://api.trongrid.io".to_string());
+        assert!(client.verify_address(ADDRESS.to_string()).is_ok());
+        assert!(client
+            .verify_address("0xa614f803b6fd780986a42c78ec9c7f77e6ded13c".to_string())
+            .is_err());
+        assert_eq!(client.get_chain(), Chain::Tron);
+    }
+
+    #[tokio::test]
+    async fn test_get_resources() {
+        let mock = MockClient::new().with_post(
+            "/wallet/getaccountresource",
+            json!({ "address": ADDRESS, "visible": true }),
+            json!({
+                "freeNetUsed": 100,
+                "freeNetLimit": 600,
+                "NetLimit": 50,
+                "EnergyLimit": 65000,
+                "EnergyUsed": 15000
+            }),
+        );
+        let client = TronClient::new_with_client(mock);
+
+        let resources = client.get_resources(ADDRESS).await.unwrap();
+        assert_eq!(
+            resources,
+            TronResources {
+                bandwidth: 550,
+                energy: 50000
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_hex() {
+        let mock = MockClient::new()
+            .with_post(
+                "/wallet/broadcasthex",
+                json!({ "transaction": "0a02" }),
+                json!({ "result": true, "txid": "abc" }),
+            )
+            .with_post(
+                "/wallet/broadcasthex",
+                json!({ "transaction": "0a03" }),
+                // "Validate signature error"
+                json!({
+                    "result": false,
+                    "code": "SIGERROR",
+                    "message": "56616c6964617465207369676e6174757265206572726f72"
+                }),
+            );
+        let client = TronClient::new_with_client(mock);
+
+        assert_eq!(client.broadcast_hex("0a02").await.unwrap(), "abc");
+        let error = client.broadcast_hex("0a03").await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Broadcast failed: Validate signature error"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use sha2::{Digest, Sha256};
+
+const ACCOUNT_ID_VERSION: u8 = 0x00;
+
+/// 经典地址：XRPL 字母表的 base58check，版本号 0 加 20 字节账户 ID
+pub fn validate_address(address: &str) -> Result<(), String> {
+    let invalid = |reason: &str| format!("Invalid XRP address {}: {}", address, reason);
+    if !address.starts_with('r') {
+        return Err(invalid("must start with r"));
+    }
+    let data = bs58::decode(address)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .into_vec()
+        .map_err(|e| invalid(&e.to_string()))?;
+    if data.len() != 25 || data[0] != ACCOUNT_ID_VERSION {
+        return Err(invalid("bad length or version"));
+    }
+    let (payload, checksum) = data.split_at(21);
+    let hash = Sha256::digest(Sha256::digest(payload));
+    if &hash[..4] != checksum {
+        return Err(invalid("bad checksum"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_address() {
+        // 创世账户与 Bitstamp 发行地址
+        assert!(validate_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").is_ok());
+        assert!(validate_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").is_ok());
+        assert!(validate_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTj").is_err());
+        assert!(validate_address("1Hb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").is_err());
+        assert!(validate_address("r0b9CJAWyB4rj91VRWn96DkukG4bwdtyTh").is_err());
+        assert!(validate_address("rHb9CJAWyB4rj91VRWn96Dkuk").is_err());
+    }
+}
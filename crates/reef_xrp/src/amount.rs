@@ -0,0 +1,84 @@
+use num_bigint::BigUint;
+
+/// IOU 金额约在 1e-96 到 1e80 之间，留出尾数位数后超出该范围的指数视为无效
+const MAX_EXPONENT: i32 = 96;
+
+/// 十进制字符串换算为整数，支持科学计数法，超出精度的部分截断，负数返回 None
+pub fn parse_decimal(value: &str, decimals: u8) -> Option<BigUint> {
+    let value = value.trim();
+    if value.starts_with('-') {
+        return None;
+    }
+    let (mantissa, exponent) = match value.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+        None => (value, 0),
+    };
+    if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+        return None;
+    }
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", integer, fraction);
+    if digits.is_empty() || !digits.chars().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    // 小数点右移的位数
+    let shift = (decimals as i32)
+        .checked_add(exponent)?
+        .checked_sub(i32::try_from(fraction.len()).ok()?)?;
+    if shift >= 0 {
+        format!("{}{}", digits, "0".repeat(shift as usize))
+            .parse()
+            .ok()
+    } else {
+        let keep = digits.len().saturating_sub(shift.unsigned_abs() as usize);
+        Some(digits[..keep].parse().unwrap_or_default())
+    }
+}
+
+/// 160 位十六进制货币代码解码为 ASCII，例如 RLUSD
+pub fn currency_code(currency: &str) -> String {
+    if currency.len() != 40 {
+        return currency.to_string();
+    }
+    match hex::decode(currency) {
+        Ok(bytes) if bytes[0] != 0 => String::from_utf8_lossy(&bytes)
+            .trim_end_matches('\0')
+            .to_string(),
+        _ => currency.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("12.5", 6), Some(BigUint::from(12_500_000u64)));
+        assert_eq!(parse_decimal("0", 15), Some(BigUint::ZERO));
+        assert_eq!(parse_decimal("1.5e-5", 6), Some(BigUint::from(15u32)));
+        assert_eq!(parse_decimal("2e3", 0), Some(BigUint::from(2000u32)));
+        assert_eq!(parse_decimal("0.1234567", 3), Some(BigUint::from(123u32)));
+        assert_eq!(parse_decimal("-3.2", 6), None);
+        assert_eq!(parse_decimal("abc", 6), None);
+    }
+
+    #[test]
+    fn test_parse_decimal_exponent_bounds() {
+        assert_eq!(parse_decimal("1e80", 0), Some(BigUint::from(10u32).pow(80)));
+        assert_eq!(parse_decimal("1e-96", 15), Some(BigUint::ZERO));
+        assert_eq!(parse_decimal("1e97", 0), None);
+        assert_eq!(parse_decimal("1e-97", 0), None);
+        assert_eq!(parse_decimal("1e2147483647", 255), None);
+        assert_eq!(parse_decimal("1e-2147483648", 0), None);
+    }
+
+    #[test]
+    fn test_currency_code() {
+        assert_eq!(currency_code("USD"), "USD");
+        assert_eq!(
+            currency_code("524C555344000000000000000000000000000000"),
+            "RLUSD"
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod address;
+pub mod amount;
+pub mod models;
+pub mod provider;
+pub mod rpc;
+
+pub const XRP_DECIMALS: u8 = 6;
+/// 发行资产最多 15 位有效数字，统一按 15 位小数换算
+pub const XRP_TOKEN_DECIMALS: u8 = 15;
+
+// Re-export for convenience
+pub use models::*;
+pub use rpc::XrpClient;
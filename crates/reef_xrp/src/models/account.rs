@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// `account_info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountInfoResult {
+    pub account_data: AccountRoot,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountRoot {
+    pub account: String,
+    /// drops
+    pub balance: String,
+    /// 信任线、挂单等占用储备金的对象数量
+    pub owner_count: u64,
+    pub sequence: u64,
+}
+
+/// `account_lines`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountLinesResult {
+    pub lines: Vec<TrustLine>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustLine {
+    /// 发行方地址
+    pub account: String,
+    /// 十进制字符串，为负时是对方欠款
+    pub balance: String,
+    pub currency: String,
+    pub limit: String,
+}
+
+impl TrustLine {
+    /// 代币标识 `{currency}-{issuer}`
+    pub fn asset_id(&self) -> String {
+        format!("{}-{}", self.currency, self.account)
+    }
+}
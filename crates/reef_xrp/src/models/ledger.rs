@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// `server_state`，金额单位为 drops
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerStateResult {
+    pub state: ServerState,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerState {
+    pub validated_ledger: Option<ValidatedLedger>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatedLedger {
+    pub reserve_base: u64,
+    pub reserve_inc: u64,
+    pub seq: u64,
+}
+
+impl ValidatedLedger {
+    /// 账户基础储备加上每个对象的增量储备
+    pub fn account_reserve(&self, owner_count: u64) -> u64 {
+        self.reserve_base + self.reserve_inc * owner_count
+    }
+}
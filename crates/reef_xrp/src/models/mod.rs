@@ -0,0 +1,25 @@
+pub mod account;
+pub mod ledger;
+pub mod transaction;
+
+pub use account::*;
+pub use ledger::*;
+pub use transaction::*;
+
+use serde::{Deserialize, Serialize};
+
+/// rippled 错误在 `result` 中返回，例如 `actNotFound`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub error: String,
+    pub error_message: Option<String>,
+}
+
+impl std::fmt::Display for RpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.error_message {
+            Some(message) => write!(f, "{}: {}", self.error, message),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+/// Ripple 纪元 2000-01-01 相对 Unix 纪元的秒数
+pub const RIPPLE_EPOCH_OFFSET: u64 = 946_684_800;
+
+/// XRP 为 drops 字符串，发行资产为对象
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum XrpAmount {
+    Drops(String),
+    Issued {
+        currency: String,
+        issuer: String,
+        value: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoWrapper {
+    #[serde(rename = "Memo")]
+    pub memo: Memo,
+}
+
+/// 字段均为十六进制
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Memo {
+    pub memo_data: Option<String>,
+    pub memo_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxJson {
+    #[serde(rename = "TransactionType")]
+    pub transaction_type: String,
+    #[serde(rename = "Account")]
+    pub account: String,
+    #[serde(rename = "Destination")]
+    pub destination: Option<String>,
+    #[serde(rename = "DestinationTag")]
+    pub destination_tag: Option<u32>,
+    #[serde(rename = "Amount")]
+    pub amount: Option<XrpAmount>,
+    #[serde(rename = "Fee")]
+    pub fee: String,
+    #[serde(rename = "Memos")]
+    pub memos: Option<Vec<MemoWrapper>>,
+    pub hash: String,
+    pub ledger_index: Option<u64>,
+    /// Ripple 纪元秒数
+    pub date: Option<u64>,
+}
+
+impl TxJson {
+    /// 第一条可按 UTF-8 解码的 memo
+    pub fn memo(&self) -> Option<String> {
+        self.memos.as_ref()?.iter().find_map(|x| {
+            let data = hex::decode(x.memo.memo_data.as_ref()?).ok()?;
+            String::from_utf8(data).ok()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxMeta {
+    #[serde(rename = "TransactionResult")]
+    pub transaction_result: String,
+    /// 实际到账金额，部分支付时小于 `Amount`，旧交易为 `unavailable`
+    pub delivered_amount: Option<XrpAmount>,
+}
+
+impl TxMeta {
+    pub fn is_success(&self) -> bool {
+        self.transaction_result == "tesSUCCESS"
+    }
+}
+
+/// `account_tx`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTxResult {
+    pub transactions: Vec<AccountTransaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTransaction {
+    pub tx: TxJson,
+    pub meta: TxMeta,
+    #[serde(default)]
+    pub validated: bool,
+}
+
+/// `tx`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxResult {
+    pub hash: String,
+    pub ledger_index: Option<u64>,
+    pub meta: Option<TxMeta>,
+    #[serde(default)]
+    pub validated: bool,
+}
+
+/// `submit`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitResult {
+    pub engine_result: String,
+    pub engine_result_message: String,
+    pub tx_json: SubmitTxJson,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitTxJson {
+    pub hash: String,
+}
+
+/// 入账需要的支付信息，交易所按目标标签区分充值用户
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XrpTransaction {
+    pub hash: String,
+    pub from: String,
+    pub to: String,
+    pub destination_tag: Option<u32>,
+    pub memo: Option<String>,
+    pub amount: XrpAmount,
+    /// drops
+    pub fee: String,
+    pub ledger_index: Option<u64>,
+    pub timestamp: Option<u64>,
+    pub successful: bool,
+}
+
+impl XrpTransaction {
+    /// 只处理已验证的 Payment，金额取 `delivered_amount` 防止部分支付攻击
+    pub fn from_account_transaction(transaction: AccountTransaction) -> Option<Self> {
+        let AccountTransaction {
+            tx,
+            meta,
+            validated,
+        } = transaction;
+        if !validated || tx.transaction_type != "Payment" {
+            return None;
+        }
+        let amount = match meta.delivered_amount.clone() {
+            Some(XrpAmount::Drops(x)) if x == "unavailable" => tx.amount.clone()?,
+            Some(amount) => amount,
+            None => tx.amount.clone()?,
+        };
+        Some(Self {
+            memo: tx.memo(),
+            hash: tx.hash,
+            from: tx.account,
+            to: tx.destination?,
+            destination_tag: tx.destination_tag,
+            amount,
+            fee: tx.fee,
+            ledger_index: tx.ledger_index,
+            timestamp: tx.date.map(|x| x + RIPPLE_EPOCH_OFFSET),
+            successful: meta.is_success(),
+        })
+    }
+}
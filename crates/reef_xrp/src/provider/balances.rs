@@ -0,0 +1,174 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use primitives::{AssetBalance, Balance, Chain, ChainBalances};
+use reef_client::Client;
+
+use crate::amount::parse_decimal;
+use crate::models::{AccountRoot, TrustLine, ValidatedLedger};
+use crate::rpc::client::XrpClient;
+use crate::{XRP_DECIMALS, XRP_TOKEN_DECIMALS};
+
+/// 可用余额，扣除基础储备与对象储备
+fn map_balance_coin(account: Option<&AccountRoot>, ledger: &ValidatedLedger) -> AssetBalance {
+    let available = account
+        .map(|x| {
+            let balance = x.balance.parse::<u64>().unwrap_or_default();
+            balance.saturating_sub(ledger.account_reserve(x.owner_count))
+        })
+        .unwrap_or_default();
+    AssetBalance {
+        chain: Chain::Xrp,
+        contract_address: None,
+        balance: Balance::coin_balance(BigUint::from(available), XRP_DECIMALS),
+        is_active: Some(account.is_some()),
+    }
+}
+
+/// 负余额表示该地址是发行方，不计入
+fn map_token_balance(line: &TrustLine) -> Option<AssetBalance> {
+    if line.balance.trim_start().starts_with('-') {
+        return None;
+    }
+    Some(AssetBalance::new_token(
+        Chain::Xrp,
+        Some(line.asset_id()),
+        parse_decimal(&line.balance, XRP_TOKEN_DECIMALS).unwrap_or_default(),
+        XRP_TOKEN_DECIMALS,
+    ))
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainBalances for XrpClient<C> {
+    async fn get_balance_coin(
+        &self,
+        address: String,
+    ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        let account = self.get_account_info(&address).await?;
+        let ledger = self.get_validated_ledger().await?;
+        Ok(map_balance_coin(account.as_ref(), &ledger))
+    }
+
+    async fn get_balance_tokens(
+        &self,
+        address: String,
+        token_addresses: Vec<String>,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        let lines = self.get_account_lines(&address).await?;
+        Ok(lines
+            .iter()
+            .filter(|x| token_addresses.contains(&x.asset_id()))
+            .filter_map(map_token_balance)
+            .collect())
+    }
+
+    async fn get_assets_balances(
+        &self,
+        address: String,
+    ) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        let lines = self.get_account_lines(&address).await?;
+        Ok(lines.iter().filter_map(map_token_balance).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+    use serde_json::json;
+
+    const ADDRESS: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+    const ISSUER: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+
+    fn request(method: &str, params: serde_json::Value) -> serde_json::Value {
+        json!({ "method": method, "params": [params] })
+    }
+
+    fn client() -> XrpClient<MockClient> {
+        let mock = MockClient::new()
+            .with_post(
+                "",
+                request("account_info", json!({ "account": ADDRESS, "ledger_index": "validated" })),
+                json!({
+                    "result": {
+                        "account_data": { "Account": ADDRESS, "Balance": "25000000", "OwnerCount": 2, "Sequence": 7 },
+                        "validated": true,
+                        "status": "success"
+                    }
+                }),
+            )
+            .with_post(
+                "",
+                request("account_info", json!({ "account": ISSUER, "ledger_index": "validated" })),
+                json!({ "result": { "error": "actNotFound", "error_message": "Account not found.", "status": "error" } }),
+            )
+            .with_post(
+                "",
+                request("server_state", json!({})),
+                json!({
+                    "result": {
+                        "state": { "validated_ledger": { "reserve_base": 1000000, "reserve_inc": 200000, "seq": 94567123 } },
+                        "status": "success"
+                    }
+                }),
+            )
+            .with_post(
+                "",
+                request("account_lines", json!({ "account": ADDRESS, "ledger_index": "validated" })),
+                json!({
+                    "result": {
+                        "account": ADDRESS,
+                        "lines": [
+                            { "account": ISSUER, "balance": "12.5", "currency": "USD", "limit": "1000" },
+                            { "account": ISSUER, "balance": "-3", "currency": "EUR", "limit": "0" }
+                        ],
+                        "status": "success"
+                    }
+                }),
+            );
+        XrpClient::new_with_client(mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_coin() {
+        let client = client();
+        let balance = client.get_balance_coin(ADDRESS.to_string()).await.unwrap();
+        // 25 XRP - (1 + 2 * 0.2) XRP 储备
+        assert_eq!(balance.balance.amount, "23600000");
+        assert_eq!(balance.balance.decimals, 6);
+        assert_eq!(balance.is_active, Some(true));
+
+        let balance = client.get_balance_coin(ISSUER.to_string()).await.unwrap();
+        assert_eq!(balance.balance.amount, "0");
+        assert_eq!(balance.is_active, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_get_token_balances() {
+        let client = client();
+        let balances = client
+            .get_balance_tokens(ADDRESS.to_string(), vec![format!("USD-{}", ISSUER)])
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].balance.amount, "12500000000000000");
+        assert_eq!(balances[0].balance.decimals, 15);
+
+        let balances = client
+            .get_assets_balances(ADDRESS.to_string())
+            .await
+            .unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(
+            balances[0].contract_address,
+            Some(format!("USD-{}", ISSUER))
+        );
+
+        let balances = client
+            .get_balance_tokens(ADDRESS.to_string(), vec![format!("EUR-{}", ISSUER)])
+            .await
+            .unwrap();
+        assert!(balances.is_empty());
+    }
+}
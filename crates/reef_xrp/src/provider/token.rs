@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+use crate::XRP_TOKEN_DECIMALS;
+use crate::{address::validate_address, amount::currency_code, rpc::client::XrpClient};
+use primitives::{Asset, AssetType, Chain, ChainToken};
+use reef_client::Client;
+
+/// 代币标识 `{currency}-{issuer}` 拆分为货币代码与发行方
+pub fn parse_asset_id(asset_id: &str) -> Result<(&str, &str), String> {
+    let (currency, issuer) = asset_id
+        .split_once('-')
+        .ok_or_else(|| format!("Invalid XRP asset: {}", asset_id))?;
+    let is_code = currency.len() == 3 || (currency.len() == 40 && hex::decode(currency).is_ok());
+    if !is_code || currency == "XRP" {
+        return Err(format!("Invalid XRP currency: {}", asset_id));
+    }
+    validate_address(issuer)?;
+    Ok((currency, issuer))
+}
+
+#[async_trait]
+impl<C: Client + Clone> ChainToken for XrpClient<C> {
+    /// 发行资产没有链上名称，使用货币代码
+    async fn get_token_data(
+        &self,
+        token_address: String,
+    ) -> Result<Asset, Box<dyn Error + Sync + Send>> {
+        let (currency, _) = parse_asset_id(&token_address)?;
+        let code = currency_code(currency);
+        Ok(Asset::new(
+            code.clone(),
+            code,
+            XRP_TOKEN_DECIMALS as i32,
+            Chain::Xrp,
+            Some(token_address),
+            AssetType::TOKEN,
+        ))
+    }
+
+    fn get_is_token_address(&self, token_address: &str) -> bool {
+        parse_asset_id(token_address).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reef_client::MockClient;
+
+    const RLUSD: &str =
+        "524C555344000000000000000000000000000000-rMxCKbEDwqr76QuheSUMdEGf4B9xJ8m5De";
+
+    #[tokio::test]
+    async fn test_get_token_data() {
+        let client = XrpClient::new_with_client(MockClient::new());
+        let asset = client.get_token_data(RLUSD.to_string()).await.unwrap();
+        assert_eq!(asset.symbol, "RLUSD");
+        assert_eq!(asset.decimals, 15);
+        assert_eq!(asset.asset_type, AssetType::TOKEN);
+        assert!(client.get_is_token_address("USD-rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"));
+        assert!(!client.get_is_token_address("XRP-rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"));
+        assert!(!client.get_is_token_address("USD"));
+    }
+}
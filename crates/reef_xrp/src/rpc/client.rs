@@ -0,0 +1,328 @@
+use crate::address::validate_address;
+use crate::models::{
+    AccountInfoResult, AccountLinesResult, AccountRoot, AccountTxResult, RpcError,
+    ServerStateResult, SubmitResult, TrustLine, TxResult, ValidatedLedger, XrpTransaction,
+};
+use primitives::{Chain, TransactionStatus, TransactionUpdate};
+use reef_client::{Client, ClientConfig, ClientError, ReqwestClient};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::error::Error;
+
+const LEDGER_VALIDATED: &str = "validated";
+const ERROR_ACCOUNT_NOT_FOUND: &str = "actNotFound";
+const ERROR_TRANSACTION_NOT_FOUND: &str = "txnNotFound";
+
+/// rippled JSON-RPC 客户端，请求与响应不是标准 JSON-RPC 2.0
+#[derive(Debug, Clone)]
+pub struct XrpClient<C: Client + Clone = ReqwestClient> {
+    client: C,
+}
+
+impl XrpClient {
+    pub fn new(url: String) -> Self {
+        Self::new_with_client(ReqwestClient::new_with_url(url))
+    }
+
+    pub fn new_with_config(url: String, config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self::new_with_client(ReqwestClient::new_with_config(
+            url, config,
+        )?))
+    }
+}
+
+impl<C: Client + Clone> XrpClient<C> {
+    /// 使用任意传输层，例如测试中的 `MockClient`
+    pub fn new_with_client(client: C) -> Self {
+        Self { client }
+    }
+
+    pub fn get_chain(&self) -> Chain {
+        Chain::Xrp
+    }
+
+    pub fn validate_address(&self, address: &str) -> Result<(), String> {
+        validate_address(address)
+    }
+
+    /// 外层为传输错误，内层为 rippled 返回的错误
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> Result<Result<T, RpcError>, Box<dyn Error + Send + Sync>> {
+        let request = json!({ "method": method, "params": [params] });
+        let response: Value = self
+            .client
+            .post("", &request, None)
+            .await
+            .map_err(|e| format!("Failed to call {}: {}", method, e))?;
+        let result = response.get("result").cloned().unwrap_or(Value::Null);
+        if result["status"] == "error" {
+            return Ok(Err(serde_json::from_value(result)?));
+        }
+        Ok(Ok(serde_json::from_value(result)?))
+    }
+
+    /// 未激活的账户返回 None
+    pub async fn get_account_info(
+        &self,
+        address: &str,
+    ) -> Result<Option<AccountRoot>, Box<dyn Error + Send + Sync>> {
+        let params = json!({ "account": address, "ledger_index": LEDGER_VALIDATED });
+        match self
+            .call::<AccountInfoResult>("account_info", params)
+            .await?
+        {
+            Ok(result) => Ok(Some(result.account_data)),
+            Err(e) if e.error == ERROR_ACCOUNT_NOT_FOUND => Ok(None),
+            Err(e) => Err(format!("Failed to get account info: {}", e).into()),
+        }
+    }
+
+    pub async fn get_account_lines(
+        &self,
+        address: &str,
+    ) -> Result<Vec<TrustLine>, Box<dyn Error + Send + Sync>> {
+        let params = json!({ "account": address, "ledger_index": LEDGER_VALIDATED });
+        match self
+            .call::<AccountLinesResult>("account_lines", params)
+            .await?
+        {
+            Ok(result) => Ok(result.lines),
+            Err(e) if e.error == ERROR_ACCOUNT_NOT_FOUND => Ok(vec![]),
+            Err(e) => Err(format!("Failed to get account lines: {}", e).into()),
+        }
+    }
+
+    /// 最新验证账本中的储备金要求
+    pub async fn get_validated_ledger(
+        &self,
+    ) -> Result<ValidatedLedger, Box<dyn Error + Send + Sync>> {
+        let result = self
+            .call::<ServerStateResult>("server_state", json!({}))
+            .await?
+            .map_err(|e| format!("Failed to get server state: {}", e))?;
+        Ok(result
+            .state
+            .validated_ledger
+            .ok_or("Server has no validated ledger")?)
+    }
+
+    /// 最近的入账与出账支付，包含目标标签与 memo
+    pub async fn get_transactions(
+        &self,
+        address: &str,
+        limit: usize,
+    ) -> Result<Vec<XrpTransaction>, Box<dyn Error + Send + Sync>> {
+        let params = json!({
+            "account": address,
+            "ledger_index_min": -1,
+            "ledger_index_max": -1,
+            "limit": limit,
+        });
+        let result = self
+            .call::<AccountTxResult>("account_tx", params)
+            .await?
+            .map_err(|e| format!("Failed to get transactions: {}", e))?;
+        Ok(result
+            .transactions
+            .into_iter()
+            .filter_map(XrpTransaction::from_account_transaction)
+            .collect())
+    }
+
+    /// 广播十六进制的已签名交易，返回交易 hash
+    pub async fn broadcast_transaction(
+        &self,
+        tx_blob: &str,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let result = self
+            .call::<SubmitResult>("submit", json!({ "tx_blob": tx_blob }))
+            .await?
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+        // tes 已应用，terQUEUED 进入队列等待下一个账本
+        if result.engine_result.starts_with("tes") || result.engine_result == "terQUEUED" {
+            return Ok(result.tx_json.hash);
+        }
+        Err(format!(
+            "Broadcast failed with {}: {}",
+            result.engine_result, result.engine_result_message
+        )
+        .into())
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        hash: &str,
+    ) -> Result<TransactionUpdate, Box<dyn Error + Send + Sync>> {
+        let pending = TransactionUpdate {
+            hash: hash.to_string(),
+            status: TransactionStatus::Pending,
+            block_number: None,
+            confirmations: 0,
+        };
+        let result = match self
+            .call::<TxResult>("tx", json!({ "transaction": hash }))
+            .await?
+        {
+            Ok(result) => result,
+            Err(e) if e.error == ERROR_TRANSACTION_NOT_FOUND => return Ok(pending),
+            Err(e) => return Err(format!("Failed to get transaction: {}", e).into()),
+        };
+        let Some(meta) = result.meta.filter(|_| result.validated) else {
+            return Ok(pending);
+        };
+        Ok(TransactionUpdate {
+            hash: result.hash,
+            status: if meta.is_success() {
+                TransactionStatus::Confirmed
+            } else {
+                TransactionStatus::Failed
+            },
+            block_number: result.ledger_index,
+            confirmations: 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::XrpAmount;
+    use reef_client::MockClient;
+
+    const ADDRESS: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+    const SENDER: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+    const HASH: &str = "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9";
+
+    fn request(method: &str, params: Value) -> Value {
+        json!({ "method": method, "params": [params] })
+    }
+
+    #[tokio::test]
+    async fn test_get_transactions() {
+        let mock = MockClient::new().with_post(
+            "",
+            request(
+                "account_tx",
+                json!({ "account": ADDRESS, "ledger_index_min": -1, "ledger_index_max": -1, "limit": 10 }),
+            ),
+            json!({
+                "result": {
+                    "account": ADDRESS,
+                    "status": "success",
+                    "transactions": [
+                        {
+                            "tx": {
+                                "TransactionType": "Payment",
+                                "Account": SENDER,
+                                "Destination": ADDRESS,
+                                "DestinationTag": 104729,
+                                "Amount": "5000000000",
+                                "Fee": "12",
+                                "Flags": 131072,
+                                "Memos": [{ "Memo": { "MemoData": "6465706f736974", "MemoType": "74657874" } }],
+                                "hash": HASH,
+                                "ledger_index": 94567123,
+                                "date": 789012345
+                            },
+                            "meta": { "TransactionResult": "tesSUCCESS", "delivered_amount": "1000" },
+                            "validated": true
+                        },
+                        {
+                            "tx": {
+                                "TransactionType": "TrustSet",
+                                "Account": ADDRESS,
+                                "Fee": "12",
+                                "hash": "00",
+                                "ledger_index": 94567000
+                            },
+                            "meta": { "TransactionResult": "tesSUCCESS" },
+                            "validated": true
+                        }
+                    ]
+                }
+            }),
+        );
+        let client = XrpClient::new_with_client(mock);
+
+        let transactions = client.get_transactions(ADDRESS, 10).await.unwrap();
+        assert_eq!(transactions.len(), 1);
+        let transaction = &transactions[0];
+        assert_eq!(transaction.to, ADDRESS);
+        assert_eq!(transaction.destination_tag, Some(104729));
+        assert_eq!(transaction.memo.as_deref(), Some("deposit"));
+        // 部分支付只到账 delivered_amount
+        assert_eq!(transaction.amount, XrpAmount::Drops("1000".to_string()));
+        assert_eq!(transaction.timestamp, Some(789012345 + 946684800));
+        assert!(transaction.successful);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_and_status() {
+        let mock = MockClient::new()
+            .with_post(
+                "",
+                request("submit", json!({ "tx_blob": "1200002280000000" })),
+                json!({
+                    "result": {
+                        "engine_result": "tesSUCCESS",
+                        "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+                        "tx_json": { "hash": HASH },
+                        "status": "success"
+                    }
+                }),
+            )
+            .with_post(
+                "",
+                request("submit", json!({ "tx_blob": "00" })),
+                json!({
+                    "result": {
+                        "engine_result": "tefPAST_SEQ",
+                        "engine_result_message": "This sequence number has already passed.",
+                        "tx_json": { "hash": "00" },
+                        "status": "success"
+                    }
+                }),
+            )
+            .with_post(
+                "",
+                request("tx", json!({ "transaction": HASH })),
+                json!({
+                    "result": {
+                        "hash": HASH,
+                        "ledger_index": 94567123,
+                        "meta": { "TransactionResult": "tecUNFUNDED_PAYMENT" },
+                        "validated": true,
+                        "status": "success"
+                    }
+                }),
+            )
+            .with_post(
+                "",
+                request("tx", json!({ "transaction": "00" })),
+                json!({ "result": { "error": "txnNotFound", "status": "error" } }),
+            );
+        let client = XrpClient::new_with_client(mock);
+
+        assert_eq!(
+            client
+                .broadcast_transaction("1200002280000000")
+                .await
+                .unwrap(),
+            HASH
+        );
+        let error = client.broadcast_transaction("00").await.unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Broadcast failed with tefPAST_SEQ"));
+
+        let update = client.get_transaction_status(HASH).await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Failed));
+        assert_eq!(update.block_number, Some(94567123));
+
+        let update = client.get_transaction_status("00").await.unwrap();
+        assert!(matches!(update.status, TransactionStatus::Pending));
+    }
+}
@@ -1,7 +1,11 @@
 mod chain_providers;
+mod name_resolvers;
+mod node_provider;
 mod provider_config;
 mod provider_factory;
 
 pub use chain_providers::ChainProviders;
+pub use name_resolvers::NameResolvers;
+pub use node_provider::{node_endpoint, NodeEndpoint};
 pub use provider_config::ProviderConfig;
 pub use provider_factory::ProviderFactory;
@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use primitives::NameResolver;
+use reef_evm::ens::EnsResolver;
+use reef_solana::name::{AllDomainsResolver, SnsResolver};
+use settings::Settings;
+
+pub struct NameResolvers {
+    resolvers: Vec<Box<dyn NameResolver>>,
+}
+
+impl NameResolvers {
+    pub fn new(resolvers: Vec<Box<dyn NameResolver>>) -> Self {
+        Self { resolvers }
+    }
+
+    pub fn from_settings(settings: &Settings) -> Self {
+        // SNS 账户直接从 Solana 节点读取，settings.name.sns 为 Bonfida 代理地址
+        let solana_url = settings.chains.solana.get_type().get_url();
+        Self::new(vec![
+            Box::new(EnsResolver::new(settings.name.ens.url.clone())),
+            Box::new(SnsResolver::new(solana_url)),
+            Box::new(AllDomainsResolver::new(
+                settings.name.alldomains.url.clone(),
+            )),
+        ])
+    }
+
+    /// 按域名后缀选择解析器
+    pub fn get_resolver(
+        &self,
+        name: &str,
+    ) -> Result<&dyn NameResolver, Box<dyn Error + Send + Sync>> {
+        self.resolvers
+            .iter()
+            .find(|x| x.is_supported_name(name))
+            .map(|x| x.as_ref())
+            .ok_or_else(|| format!("No name resolver found for: {}", name).into())
+    }
+}
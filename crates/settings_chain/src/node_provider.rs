@@ -0,0 +1,90 @@
+use primitives::Chain;
+use reef_client::ClientAuth;
+use settings::NodeProvider;
+
+pub const TRONGRID_URL: &str = "https://api.trongrid.io";
+pub const TRONGRID_API_KEY_HEADER: &str = "TRON-PRO-API-KEY";
+
+/// 付费节点地址，key 在路径中或通过 auth 发送
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeEndpoint {
+    pub url: String,
+    pub auth: Option<ClientAuth>,
+}
+
+/// Alchemy 子域名，例如 `eth-mainnet.g.alchemy.com`
+pub fn alchemy_network(chain: Chain) -> Option<&'static str> {
+    match chain.as_ref() {
+        "ethereum" => Some("eth-mainnet"),
+        "smartchain" => Some("bnb-mainnet"),
+        "polygon" => Some("polygon-mainnet"),
+        "solana" => Some("solana-mainnet"),
+        "optimism" => Some("opt-mainnet"),
+        "arbitrum" => Some("arb-mainnet"),
+        "base" => Some("base-mainnet"),
+        "opbnb" => Some("opbnb-mainnet"),
+        "avalanchec" => Some("avax-mainnet"),
+        "fantom" => Some("fantom-mainnet"),
+        "gnosis" => Some("gnosis-mainnet"),
+        "zksync" => Some("zksync-mainnet"),
+        "linea" => Some("linea-mainnet"),
+        "mantle" => Some("mantle-mainnet"),
+        "celo" => Some("celo-mainnet"),
+        "blast" => Some("blast-mainnet"),
+        "sonic" => Some("sonic-mainnet"),
+        "berachain" => Some("berachain-mainnet"),
+        "ink" => Some("ink-mainnet"),
+        "unichain" => Some("unichain-mainnet"),
+        "abstract" => Some("abstract-mainnet"),
+        _ => None,
+    }
+}
+
+/// Ankr 路径中的链名，例如 `rpc.ankr.com/eth`
+pub fn ankr_network(chain: Chain) -> Option<&'static str> {
+    match chain.as_ref() {
+        "ethereum" => Some("eth"),
+        "smartchain" => Some("bsc"),
+        "polygon" => Some("polygon"),
+        "solana" => Some("solana"),
+        "optimism" => Some("optimism"),
+        "arbitrum" => Some("arbitrum"),
+        "base" => Some("base"),
+        "opbnb" => Some("opbnb"),
+        "avalanchec" => Some("avalanche"),
+        "fantom" => Some("fantom"),
+        "gnosis" => Some("gnosis"),
+        "zksync" => Some("zksync_era"),
+        "linea" => Some("linea"),
+        "mantle" => Some("mantle"),
+        "celo" => Some("celo"),
+        "blast" => Some("blast"),
+        // Trongrid 兼容的 HTTP API
+        "tron" => Some("premium-http/tron"),
+        _ => None,
+    }
+}
+
+/// 服务商不支持该链或 key 为空时返回 None
+pub fn node_endpoint(provider: NodeProvider, chain: Chain, key: &str) -> Option<NodeEndpoint> {
+    if key.is_empty() {
+        return None;
+    }
+    match provider {
+        NodeProvider::Alchemy => alchemy_network(chain).map(|network| NodeEndpoint {
+            url: format!("https://{}.g.alchemy.com/v2/{}", network, key),
+            auth: None,
+        }),
+        NodeProvider::Ankr => ankr_network(chain).map(|network| NodeEndpoint {
+            url: format!("https://rpc.ankr.com/{}/{}", network, key),
+            auth: None,
+        }),
+        NodeProvider::Trongrid => (chain.as_ref() == "tron").then(|| NodeEndpoint {
+            url: TRONGRID_URL.to_string(),
+            auth: Some(ClientAuth::ApiKeyHeader {
+                name: TRONGRID_API_KEY_HEADER.to_string(),
+                value: key.to_string(),
+            }),
+        }),
+    }
+}
@@ -1,11 +1,23 @@
 use primitives::Chain;
+use reef_client::ClientConfig;
+use reef_jsonrpc::{EndpointConfig, RateLimitConfig, RoutingStrategy};
 use serde::{Deserialize, Serialize};
+use settings::NodeProvider;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProviderConfig {
     pub chain: Chain,
     pub url: String,
+    pub endpoints: Vec<EndpointConfig>,
+    pub routing: RoutingStrategy,
+    pub rate_limit: Option<RateLimitConfig>,
+    pub client: ClientConfig,
     pub node_type: NodeType,
+    // 实际使用的付费节点，未配置 key 时为 None
+    #[serde(skip)]
+    pub node_provider: Option<NodeProvider>,
+    // 仅 EVM 链使用，未配置时使用默认区块数
+    pub nft_lookback_blocks: Option<u64>,
     pub alchemy_key: String,
     pub ankr_key: String,
     pub trongrid_key: String,
@@ -29,10 +41,56 @@ impl ProviderConfig {
         Self {
             chain,
             url: url.to_string(),
+            endpoints: vec![EndpointConfig::new(url, 1)],
+            routing: RoutingStrategy::default(),
+            rate_limit: None,
+            client: ClientConfig::default(),
             node_type,
+            node_provider: None,
+            nft_lookback_blocks: None,
             alchemy_key: alchemy_key.to_string(),
             ankr_key: ankr_key.to_string(),
             trongrid_key: trongrid_key.to_string(),
         }
     }
+
+    /// REST 接口不做故障转移，只使用首个节点
+    pub fn get_url(&self) -> String {
+        self.endpoints
+            .first()
+            .map(|x| x.url.clone())
+            .unwrap_or_else(|| self.url.clone())
+    }
+
+    pub fn with_endpoints(
+        mut self,
+        endpoints: Vec<EndpointConfig>,
+        routing: RoutingStrategy,
+    ) -> Self {
+        if !endpoints.is_empty() {
+            self.endpoints = endpoints;
+        }
+        self.routing = routing;
+        self
+    }
+
+    pub fn with_rate_limit(mut self, rate_limit: Option<RateLimitConfig>) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    pub fn with_client_config(mut self, client: ClientConfig) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn with_node_provider(mut self, node_provider: Option<NodeProvider>) -> Self {
+        self.node_provider = node_provider;
+        self
+    }
+
+    pub fn with_nft_lookback_blocks(mut self, nft_lookback_blocks: Option<u64>) -> Self {
+        self.nft_lookback_blocks = nft_lookback_blocks;
+        self
+    }
 }
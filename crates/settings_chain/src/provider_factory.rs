@@ -1,18 +1,36 @@
-use crate::{provider_config::NodeType, ProviderConfig};
+use crate::{node_provider::node_endpoint, provider_config::NodeType, ProviderConfig};
 use primitives::ChainTraits;
 use primitives::{Chain, EVMChain};
+use reef_bitcoin::{BitcoinChain, BitcoinClient};
+use reef_client::{ClientAuth, ClientConfig, ClientError};
+use reef_cosmos::{CosmosChain, CosmosClient};
 use reef_evm::rpc::EthereumClient;
+use reef_hypercore::HyperCoreClient;
+use reef_jsonrpc::{
+    CacheStore, EndpointConfig, FailoverClient, JsonRpcCache, RateLimitConfig, RedisCache,
+    RoutingStrategy,
+};
+use reef_move::{AptosClient, SuiClient};
 use reef_solana::rpc::SolanaClient;
-use settings::{ChainURLType, Settings};
+use reef_stellar::StellarClient;
+use reef_ton::TonClient;
+use reef_tron::rpc::TronClient;
+use reef_xrp::XrpClient;
+use settings::{
+    ChainAuth, ChainHttp, ChainRateLimit, ChainRouting, ChainURLType, NodeProvider, Settings,
+};
+use std::sync::Arc;
+use std::time::Duration;
 
 pub struct ProviderFactory;
 
 impl ProviderFactory {
     pub async fn new_providers(settings: &Settings) -> Vec<Box<dyn ChainTraits>> {
         let mut providers = Vec::new();
+        let store = Self::cache_store(settings).await;
         for chain in Chain::all() {
             // 尝试创建provider，如果失败则跳过
-            match Self::new_from_settings(chain, settings).await {
+            match Self::new_from_settings(chain, settings, store.clone()).await {
                 Ok(provider) => providers.push(provider),
                 Err(e) => {
                     println!(
@@ -25,48 +43,291 @@ impl ProviderFactory {
         providers
     }
 
+    // redis 不可用时只使用内存缓存
+    async fn cache_store(settings: &Settings) -> Option<Arc<dyn CacheStore>> {
+        if !settings.rpc_cache.redis {
+            return None;
+        }
+        match RedisCache::new(&settings.redis.url).await {
+            Ok(store) => Some(Arc::new(store)),
+            Err(e) => {
+                println!("Warning: Failed to connect rpc cache redis, error: {}", e);
+                None
+            }
+        }
+    }
+
     // 不对外暴露
     async fn new_from_settings(
         chain: Chain,
         settings: &Settings,
+        store: Option<Arc<dyn CacheStore>>,
     ) -> Result<Box<dyn ChainTraits>, Box<dyn std::error::Error + Send + Sync>> {
-        let url_type = Self::url(chain, settings);
+        let chain_settings = Self::chain_settings(chain, settings);
+        let url_type = chain_settings.get_type();
         let url = url_type.get_url();
         let node_type = ProviderFactory::get_node_type(url_type.clone());
+        let mut client_config = Self::get_client_config(&chain_settings.http, settings);
+        // http.auth 只发给配置的节点，付费节点使用自己的认证
+        let chain_auth = client_config.auth.take();
+        let (endpoints, node_provider) = Self::get_endpoints(chain, settings, chain_auth);
+        let cache =
+            JsonRpcCache::new(chain.as_ref(), settings.rpc_cache.capacity).with_store(store);
         // 调用new_provider 创建
-        Self::new_provider(ProviderConfig::new(
-            chain,
-            &url,
-            node_type,
-            settings.alchemy.key.secret.as_str(),
-            settings.ankr.key.secret.as_str(),
-            settings.trongrid.key.secret.as_str(),
-        ))
+        Self::new_provider(
+            ProviderConfig::new(
+                chain,
+                &url,
+                node_type,
+                settings.alchemy.key.secret.as_str(),
+                settings.ankr.key.secret.as_str(),
+                settings.trongrid.key.secret.as_str(),
+            )
+            .with_endpoints(endpoints, Self::get_routing(chain_settings.routing))
+            .with_rate_limit(chain_settings.rate_limit.as_ref().map(Self::get_rate_limit))
+            .with_client_config(client_config)
+            .with_node_provider(node_provider)
+            .with_nft_lookback_blocks(chain_settings.nft_lookback_blocks),
+            cache,
+        )
         .await
     }
 
     async fn new_provider(
         config: ProviderConfig,
+        cache: JsonRpcCache,
     ) -> Result<Box<dyn ChainTraits>, Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(evm_chain) = EVMChain::from_chain(config.chain) {
+            let mut client = EthereumClient::new_with_endpoints(
+                config.endpoints,
+                config.routing,
+                config.rate_limit,
+                &config.client,
+                evm_chain,
+            )?
+            .with_cache(cache);
+            if let Some(blocks) = config.nft_lookback_blocks {
+                client = client.with_nft_logs_lookback(blocks);
+            }
+            return Ok(Box::new(client));
+        }
+        if let Some(bitcoin_chain) = BitcoinChain::from_chain(config.chain) {
+            let client =
+                BitcoinClient::new_with_client(Self::failover_client(&config)?, bitcoin_chain);
+            return Ok(Box::new(client));
+        }
+        if let Some(cosmos_chain) = CosmosChain::from_chain(config.chain) {
+            let client =
+                CosmosClient::new_with_client(Self::failover_client(&config)?, cosmos_chain);
+            return Ok(Box::new(client));
+        }
         match config.chain {
-            Chain::Ethereum | Chain::SmartChain | Chain::Polygon => {
-                let evm_chain = EVMChain::from_chain(config.chain).unwrap();
-                let client = EthereumClient::new(config.url, evm_chain);
+            Chain::Solana => {
+                let client = SolanaClient::new_with_endpoints(
+                    config.endpoints,
+                    config.routing,
+                    config.rate_limit,
+                    &config.client,
+                )?
+                .with_cache(cache);
                 Ok(Box::new(client))
             }
-            Chain::Solana => {
-                let client = SolanaClient::new(config.url);
+            Chain::Sui => {
+                let client = SuiClient::new_with_endpoints(
+                    config.endpoints,
+                    config.routing,
+                    config.rate_limit,
+                    &config.client,
+                )?
+                .with_cache(cache);
                 Ok(Box::new(client))
             }
+            Chain::Aptos => Ok(Box::new(AptosClient::new_with_client(
+                Self::failover_client(&config)?,
+            ))),
+            Chain::Ton => Ok(Box::new(TonClient::new_with_client(Self::failover_client(
+                &config,
+            )?))),
+            Chain::Xrp => Ok(Box::new(XrpClient::new_with_client(Self::failover_client(
+                &config,
+            )?))),
+            Chain::Stellar => Ok(Box::new(StellarClient::new_with_client(
+                Self::failover_client(&config)?,
+            ))),
+            Chain::HyperCore => Ok(Box::new(HyperCoreClient::new_with_client(
+                Self::failover_client(&config)?,
+            ))),
+            Chain::Tron => Ok(Box::new(TronClient::new_with_client(
+                Self::failover_client(&config)?,
+            ))),
+            chain => Err(format!("Unsupported chain: {}", chain).into()),
         }
     }
 
+    // REST 节点与 JSON-RPC 节点使用同样的备用节点列表
+    fn failover_client(config: &ProviderConfig) -> Result<FailoverClient, ClientError> {
+        FailoverClient::new_reqwest_endpoints(
+            config.endpoints.clone(),
+            config.routing,
+            config.rate_limit,
+            &config.client,
+        )
+    }
+
     pub fn url(chain: Chain, settings: &Settings) -> ChainURLType {
+        Self::chain_settings(chain, settings).get_type()
+    }
+
+    pub fn chain_settings(chain: Chain, settings: &Settings) -> &settings::Chain {
         match chain {
-            Chain::Ethereum => settings.chains.ethereum.get_type(),
-            Chain::SmartChain => settings.chains.smartchain.get_type(),
-            Chain::Polygon => settings.chains.polygon.get_type(),
-            Chain::Solana => settings.chains.solana.get_type(),
+            Chain::Ethereum => &settings.chains.ethereum,
+            Chain::SmartChain => &settings.chains.smartchain,
+            Chain::Polygon => &settings.chains.polygon,
+            Chain::Solana => &settings.chains.solana,
+            Chain::Optimism => &settings.chains.optimism,
+            Chain::Arbitrum => &settings.chains.arbitrum,
+            Chain::Base => &settings.chains.base,
+            Chain::OpBNB => &settings.chains.opbnb,
+            Chain::AvalancheC => &settings.chains.avalanchec,
+            Chain::Fantom => &settings.chains.fantom,
+            Chain::Gnosis => &settings.chains.gnosis,
+            Chain::ZkSync => &settings.chains.zksync,
+            Chain::Linea => &settings.chains.linea,
+            Chain::Mantle => &settings.chains.mantle,
+            Chain::Celo => &settings.chains.celo,
+            Chain::Blast => &settings.chains.blast,
+            Chain::Manta => &settings.chains.manta,
+            Chain::Sonic => &settings.chains.sonic,
+            Chain::Berachain => &settings.chains.berachain,
+            Chain::Ink => &settings.chains.ink,
+            Chain::Unichain => &settings.chains.unichain,
+            Chain::Abstract => &settings.chains.abstract_chain,
+            Chain::Monad => &settings.chains.monad,
+            Chain::Hyperliquid => &settings.chains.hyperliquid,
+            Chain::Tron => &settings.chains.tron,
+            Chain::Bitcoin => &settings.chains.bitcoin,
+            Chain::BitcoinCash => &settings.chains.bitcoincash,
+            Chain::Litecoin => &settings.chains.litecoin,
+            Chain::Doge => &settings.chains.doge,
+            Chain::Cosmos => &settings.chains.cosmos,
+            Chain::Osmosis => &settings.chains.osmosis,
+            Chain::Injective => &settings.chains.injective,
+            Chain::Celestia => &settings.chains.celestia,
+            Chain::Sei => &settings.chains.sei,
+            Chain::Noble => &settings.chains.noble,
+            Chain::Sui => &settings.chains.sui,
+            Chain::Aptos => &settings.chains.aptos,
+            Chain::Ton => &settings.chains.ton,
+            Chain::Xrp => &settings.chains.xrp,
+            Chain::Stellar => &settings.chains.stellar,
+            Chain::HyperCore => &settings.chains.hypercore,
+        }
+    }
+
+    /// 付费节点放在首位，配置的节点降为备用
+    pub fn get_endpoints(
+        chain: Chain,
+        settings: &Settings,
+        auth: Option<ClientAuth>,
+    ) -> (Vec<EndpointConfig>, Option<NodeProvider>) {
+        let chain_settings = Self::chain_settings(chain, settings);
+        let endpoints: Vec<EndpointConfig> = chain_settings
+            .get_endpoints()
+            .into_iter()
+            .map(|x| EndpointConfig::new(&x.url, x.weight).with_auth(auth.clone()))
+            .collect();
+        let node = chain_settings.provider.and_then(|provider| {
+            let node = node_endpoint(provider, chain, &Self::get_api_key(provider, settings))?;
+            Some((provider, node))
+        });
+        match node {
+            Some((provider, node)) => {
+                let endpoints =
+                    std::iter::once(EndpointConfig::new(&node.url, 1).with_auth(node.auth))
+                        .chain(
+                            endpoints
+                                .into_iter()
+                                .map(|x| EndpointConfig { weight: 0, ..x }),
+                        )
+                        .collect();
+                (endpoints, Some(provider))
+            }
+            None => (endpoints, None),
+        }
+    }
+
+    pub fn get_routing(routing: ChainRouting) -> RoutingStrategy {
+        match routing {
+            ChainRouting::RoundRobin => RoutingStrategy::RoundRobin,
+            ChainRouting::LowestLatency => RoutingStrategy::LowestLatency,
+        }
+    }
+
+    pub fn get_rate_limit(rate_limit: &ChainRateLimit) -> RateLimitConfig {
+        let config = RateLimitConfig::new(rate_limit.rps);
+        RateLimitConfig {
+            burst: rate_limit.burst.unwrap_or(config.burst),
+            max_in_flight: rate_limit.max_in_flight,
+            ..config
+        }
+    }
+
+    pub fn get_client_config(http: &ChainHttp, settings: &Settings) -> ClientConfig {
+        let default = ClientConfig::default();
+        let auth = http.auth.as_ref().and_then(|auth| match auth {
+            ChainAuth::Bearer { token } => Some(ClientAuth::Bearer(token.clone())),
+            ChainAuth::Basic { username, password } => Some(ClientAuth::Basic {
+                username: username.clone(),
+                password: password.clone(),
+            }),
+            ChainAuth::ApiKey {
+                provider,
+                header,
+                query,
+            } => {
+                let value = Self::get_api_key(*provider, settings);
+                // 未配置 key 时不发送认证信息
+                if value.is_empty() {
+                    return None;
+                }
+                Some(match (header, query) {
+                    (_, Some(name)) => ClientAuth::ApiKeyQuery {
+                        name: name.clone(),
+                        value,
+                    },
+                    (Some(name), None) => ClientAuth::ApiKeyHeader {
+                        name: name.clone(),
+                        value,
+                    },
+                    // 未指定时使用 Bearer
+                    (None, None) => ClientAuth::Bearer(value),
+                })
+            }
+        });
+        ClientConfig {
+            connect_timeout: http
+                .connect_timeout_ms
+                .map(Duration::from_millis)
+                .unwrap_or(default.connect_timeout),
+            timeout: http
+                .timeout_ms
+                .map(Duration::from_millis)
+                .unwrap_or(default.timeout),
+            headers: http.headers.clone(),
+            auth,
+            gzip: http.gzip.unwrap_or(default.gzip),
+            brotli: http.brotli.unwrap_or(default.brotli),
+            http2_prior_knowledge: http.http2.unwrap_or(default.http2_prior_knowledge),
+            proxy: http.proxy.clone(),
+            user_agent: http.user_agent.clone(),
+        }
+    }
+
+    pub fn get_api_key(provider: NodeProvider, settings: &Settings) -> String {
+        match provider {
+            NodeProvider::Alchemy => settings.alchemy.key.secret.clone(),
+            NodeProvider::Ankr => settings.ankr.key.secret.clone(),
+            NodeProvider::Trongrid => settings.trongrid.key.secret.clone(),
         }
     }
 
@@ -77,3 +338,64 @@ impl ProviderFactory {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn settings() -> Settings {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../Settings.yaml");
+        Settings::new_setting_path(path).unwrap()
+    }
+
+    #[test]
+    fn test_get_client_config_api_key() {
+        let mut settings = settings();
+        settings.trongrid.key.secret = String::new();
+        let http = &settings.chains.tron.http;
+        assert_eq!(
+            ProviderFactory::get_client_config(http, &settings).auth,
+            None
+        );
+
+        settings.trongrid.key.secret = "key".to_string();
+        let http = &settings.chains.tron.http;
+        assert_eq!(
+            ProviderFactory::get_client_config(http, &settings).auth,
+            Some(ClientAuth::ApiKeyHeader {
+                name: "TRON-PRO-API-KEY".to_string(),
+                value: "key".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_endpoints_auth() {
+        let mut settings = settings();
+        settings.chains.tron.provider = Some(NodeProvider::Ankr);
+        settings.ankr.key.secret = "ankr_key".to_string();
+        settings.trongrid.key.secret = "trongrid_key".to_string();
+        let trongrid =
+            ProviderFactory::get_client_config(&settings.chains.tron.http, &settings).auth;
+
+        let (endpoints, provider) =
+            ProviderFactory::get_endpoints(Chain::Tron, &settings, trongrid.clone());
+        assert_eq!(provider, Some(NodeProvider::Ankr));
+        assert_eq!(
+            endpoints[0].url,
+            "https://rpc.ankr.com/premium-http/tron/ankr_key"
+        );
+        assert_eq!(endpoints[0].auth, None);
+        assert!(endpoints[1..]
+            .iter()
+            .all(|x| x.weight == 0 && x.auth == trongrid));
+
+        settings.ankr.key.secret = String::new();
+        let (endpoints, provider) =
+            ProviderFactory::get_endpoints(Chain::Tron, &settings, trongrid.clone());
+        assert_eq!(provider, None);
+        assert_eq!(endpoints[0].url, "https://api.trongrid.io");
+        assert_eq!(endpoints[0].auth, trongrid);
+    }
+}